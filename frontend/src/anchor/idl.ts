@@ -9,57 +9,114 @@ export type Truchain = {
     name: 'truchain';
     version: '0.1.0';
     spec: '0.1.0';
-    description: 'Created with Anchor';
+    description: 'TruChain - Video Authenticity Verification System';
   };
   instructions: [
     {
-      name: 'endorseVideo';
-      discriminator: [173, 7, 10, 6, 190, 162, 178, 18];
+      name: 'activateStandby';
+      discriminator: [149, 163, 2, 156, 182, 47, 129, 59];
       accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
         {
           name: 'official';
         },
         {
           name: 'video';
           writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'addMirror';
+      discriminator: [138, 133, 17, 162, 180, 6, 119, 247];
+      accounts: [
+        {
+          name: 'official';
         },
         {
-          name: 'endorser';
+          name: 'video';
+        },
+        {
+          name: 'mirrors';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [109, 105, 114, 114, 111, 114, 115];
+              },
+              {
+                kind: 'account';
+                path: 'video';
+              }
+            ];
+          };
+        },
+        {
+          name: 'authority';
           writable: true;
           signer: true;
+          relations: ['official'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
         }
       ];
       args: [
         {
-          name: 'isAuthentic';
-          type: 'bool';
+          name: 'pointerKind';
+          type: 'u8';
+        },
+        {
+          name: 'uri';
+          type: 'string';
         }
       ];
     },
     {
-      name: 'registerOfficial';
-      discriminator: [236, 127, 211, 47, 78, 152, 17, 216];
+      name: 'appendCorrectionNotice';
+      discriminator: [131, 126, 231, 5, 12, 109, 27, 249];
       accounts: [
         {
           name: 'official';
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'notices';
           writable: true;
           pda: {
             seeds: [
               {
                 kind: 'const';
-                value: [111, 102, 102, 105, 99, 105, 97, 108];
+                value: [110, 111, 116, 105, 99, 101, 115];
               },
               {
-                kind: 'arg';
-                path: 'officialId';
+                kind: 'account';
+                path: 'video';
               }
             ];
           };
         },
         {
-          name: 'admin';
+          name: 'authority';
           writable: true;
           signer: true;
+          relations: ['official'];
         },
         {
           name: 'systemProgram';
@@ -68,58 +125,119 @@ export type Truchain = {
       ];
       args: [
         {
-          name: 'officialId';
-          type: 'u64';
+          name: 'noticeHash';
+          type: {
+            array: ['u8', 32];
+          };
         },
         {
-          name: 'name';
+          name: 'noticeCid';
           type: 'string';
-        },
+        }
+      ];
+    },
+    {
+      name: 'applyScheduledRotation';
+      discriminator: [184, 146, 231, 191, 209, 166, 250, 107];
+      accounts: [
         {
-          name: 'authority';
-          type: 'pubkey';
+          name: 'official';
+          writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'approveRecovery';
+      discriminator: [148, 96, 41, 38, 108, 189, 129, 214];
+      accounts: [
+        {
+          name: 'official';
+          relations: ['proposal'];
         },
         {
-          name: 'endorsers';
-          type: {
-            array: ['pubkey', 3];
+          name: 'proposal';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [114, 101, 99, 111, 118, 101, 114, 121];
+              },
+              {
+                kind: 'account';
+                path: 'official';
+              }
+            ];
           };
+        },
+        {
+          name: 'endorser';
+          signer: true;
         }
       ];
+      args: [];
     },
     {
-      name: 'registerVideo';
-      discriminator: [213, 160, 14, 162, 203, 241, 158, 196];
+      name: 'approveThresholdChange';
+      discriminator: [167, 84, 53, 175, 15, 9, 163, 163];
       accounts: [
         {
           name: 'official';
           writable: true;
         },
         {
-          name: 'video';
-          writable: true;
+          name: 'endorser';
+          signer: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'attestBroadcast';
+      discriminator: [76, 242, 194, 124, 71, 206, 117, 250];
+      accounts: [
+        {
+          name: 'broadcastAttestor';
           pda: {
             seeds: [
               {
                 kind: 'const';
-                value: [118, 105, 100, 101, 111];
+                value: [
+                  98, 114, 111, 97, 100, 99, 97, 115, 116, 95, 97, 116, 116,
+                  101, 115, 116, 111, 114
+                ];
               },
               {
                 kind: 'account';
-                path: 'official';
+                path: 'attestor';
+              }
+            ];
+          };
+        },
+        {
+          name: 'video';
+        },
+        {
+          name: 'broadcastAttestations';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [98, 114, 111, 97, 100, 99, 97, 115, 116];
               },
               {
-                kind: 'arg';
-                path: 'videoHash';
+                kind: 'account';
+                path: 'video';
               }
             ];
           };
         },
         {
-          name: 'authority';
+          name: 'attestor';
           writable: true;
           signer: true;
-          relations: ['official'];
         },
         {
           name: 'systemProgram';
@@ -128,104 +246,5615 @@ export type Truchain = {
       ];
       args: [
         {
-          name: 'videoHash';
+          name: 'broadcastTime';
+          type: 'i64';
+        },
+        {
+          name: 'channelHash';
           type: {
             array: ['u8', 32];
           };
+        }
+      ];
+    },
+    {
+      name: 'attestDomain';
+      discriminator: [48, 164, 102, 50, 169, 128, 242, 133];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
         },
         {
-          name: 'ipfsCid';
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'domainIndex';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'domain';
           type: 'string';
         }
       ];
-    }
-  ];
-  accounts: [
-    {
-      name: 'Official';
-      discriminator: [111, 189, 248, 110, 255, 197, 65, 87];
-    },
-    {
-      name: 'Video';
-      discriminator: [57, 77, 160, 7, 234, 235, 39, 112];
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: 'UnauthorizedOfficial';
-      msg: "Only the official's authority can register videos";
-    },
-    {
-      code: 6001;
-      name: 'UnauthorizedEndorser';
-      msg: 'Only approved endorsers can vote on videos';
-    },
-    {
-      code: 6002;
-      name: 'AlreadyVoted';
-      msg: 'This endorser has already voted on this video';
-    },
-    {
-      code: 6003;
-      name: 'TooManyVotes';
-      msg: 'Maximum number of votes reached for this video';
     },
     {
-      code: 6004;
-      name: 'VideoAlreadyExists';
-      msg: 'Video already registered for this official';
-    },
-    {
-      code: 6005;
-      name: 'InvalidOfficialName';
-      msg: 'Invalid official name (must be <= 32 bytes UTF-8)';
+      name: 'attestPin';
+      discriminator: [110, 161, 200, 234, 241, 253, 169, 234];
+      accounts: [
+        {
+          name: 'provider';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 114, 111, 118, 105, 100, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'providerKey';
+              }
+            ];
+          };
+        },
+        {
+          name: 'video';
+        },
+        {
+          name: 'pinReceipt';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 105, 110];
+              },
+              {
+                kind: 'account';
+                path: 'video';
+              },
+              {
+                kind: 'account';
+                path: 'provider';
+              }
+            ];
+          };
+        },
+        {
+          name: 'providerKey';
+          writable: true;
+          signer: true;
+          relations: ['provider'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'expiresAt';
+          type: 'i64';
+        }
+      ];
     },
     {
-      code: 6006;
-      name: 'InvalidIpfsCid';
+      name: 'attestSocial';
+      discriminator: [52, 211, 188, 102, 168, 58, 117, 188];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'socialIndex';
+          writable: true;
+        },
+        {
+          name: 'previousSocialIndex';
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'platform';
+          type: 'u8';
+        },
+        {
+          name: 'handle';
+          type: 'string';
+        }
+      ];
+    },
+    {
+      name: 'authorizeHashes';
+      discriminator: [147, 160, 88, 144, 95, 230, 136, 234];
+      accounts: [
+        {
+          name: 'official';
+        },
+        {
+          name: 'authority';
+          writable: true;
+          signer: true;
+          relations: ['official'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'hashes';
+          type: {
+            vec: {
+              array: ['u8', 32];
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: 'bindExternalKey';
+      discriminator: [189, 168, 195, 38, 222, 72, 5, 171];
+      accounts: [
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['official'];
+        },
+        {
+          name: 'instructions';
+          optional: true;
+          address: 'Sysvar1nstructions1111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'keyKind';
+          type: 'u8';
+        },
+        {
+          name: 'fingerprint';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'withProof';
+          type: 'bool';
+        }
+      ];
+    },
+    {
+      name: 'bumpCursor';
+      discriminator: [129, 192, 190, 189, 156, 101, 192, 114];
+      accounts: [
+        {
+          name: 'cursor';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 117, 114, 115, 111, 114];
+              },
+              {
+                kind: 'account';
+                path: 'cursor.labelHash';
+                account: 'IndexerCursor';
+              }
+            ];
+          };
+        },
+        {
+          name: 'owner';
+          signer: true;
+          relations: ['cursor'];
+        }
+      ];
+      args: [
+        {
+          name: 'newSequence';
+          type: 'u64';
+        }
+      ];
+    },
+    {
+      name: 'cancelRecovery';
+      discriminator: [176, 23, 203, 37, 121, 251, 227, 83];
+      accounts: [
+        {
+          name: 'official';
+          relations: ['proposal'];
+        },
+        {
+          name: 'proposal';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [114, 101, 99, 111, 118, 101, 114, 121];
+              },
+              {
+                kind: 'account';
+                path: 'official';
+              }
+            ];
+          };
+        },
+        {
+          name: 'rentPayer';
+          writable: true;
+          relations: ['proposal'];
+        },
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['official'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'changeVote';
+      discriminator: [184, 39, 97, 137, 83, 108, 185, 75];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'receipt';
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [114, 101, 99, 101, 105, 112, 116];
+              },
+              {
+                kind: 'account';
+                path: 'video.videoHash';
+                account: 'Video';
+              },
+              {
+                kind: 'account';
+                path: 'endorser';
+              }
+            ];
+          };
+        },
+        {
+          name: 'endorser';
+          signer: true;
+        },
+        {
+          name: 'cosigner';
+          signer: true;
+          optional: true;
+        },
+        {
+          name: 'statusIndex';
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'oldStatusIndex';
+          writable: true;
+          optional: true;
+        }
+      ];
+      args: [
+        {
+          name: 'isAuthentic';
+          type: 'bool';
+        }
+      ];
+    },
+    {
+      name: 'claimHandle';
+      discriminator: [93, 142, 47, 111, 164, 134, 99, 181];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'handleAccount';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [104, 97, 110, 100, 108, 101];
+              },
+              {
+                kind: 'arg';
+                path: 'handle';
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'handle';
+          type: 'string';
+        }
+      ];
+    },
+    {
+      name: 'closeVoteReceipt';
+      discriminator: [245, 52, 25, 255, 206, 109, 60, 162];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'receipt';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [114, 101, 99, 101, 105, 112, 116];
+              },
+              {
+                kind: 'account';
+                path: 'receipt.videoHash';
+                account: 'VoteReceipt';
+              },
+              {
+                kind: 'account';
+                path: 'receipt.endorser';
+                account: 'VoteReceipt';
+              }
+            ];
+          };
+        },
+        {
+          name: 'endorser';
+          writable: true;
+          relations: ['receipt'];
+        },
+        {
+          name: 'closer';
+          signer: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'coAttest';
+      discriminator: [33, 59, 72, 122, 177, 44, 197, 47];
+      accounts: [
+        {
+          name: 'attestingOfficial';
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'coAttestation';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 95, 97, 116, 116, 101, 115, 116];
+              },
+              {
+                kind: 'account';
+                path: 'video';
+              },
+              {
+                kind: 'account';
+                path: 'attestingOfficial';
+              }
+            ];
+          };
+        },
+        {
+          name: 'authority';
+          writable: true;
+          signer: true;
+          relations: ['attestingOfficial'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'collectPinReceipt';
+      discriminator: [217, 72, 221, 228, 160, 113, 48, 192];
+      accounts: [
+        {
+          name: 'provider';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 114, 111, 118, 105, 100, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'provider.providerKey';
+                account: 'Provider';
+              }
+            ];
+          };
+        },
+        {
+          name: 'pinReceipt';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 105, 110];
+              },
+              {
+                kind: 'account';
+                path: 'pinReceipt.video';
+                account: 'PinReceipt';
+              },
+              {
+                kind: 'account';
+                path: 'provider';
+              }
+            ];
+          };
+        },
+        {
+          name: 'providerKey';
+          writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'createCursor';
+      discriminator: [10, 196, 78, 101, 250, 36, 240, 6];
+      accounts: [
+        {
+          name: 'cursor';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 117, 114, 115, 111, 114];
+              },
+              {
+                kind: 'arg';
+                path: 'labelHash';
+              }
+            ];
+          };
+        },
+        {
+          name: 'owner';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'labelHash';
+          type: {
+            array: ['u8', 32];
+          };
+        }
+      ];
+    },
+    {
+      name: 'emitCurrentState';
+      discriminator: [236, 35, 182, 176, 163, 60, 53, 247];
+      accounts: [
+        {
+          name: 'official';
+          optional: true;
+        },
+        {
+          name: 'video';
+          optional: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'endorseVideo';
+      discriminator: [173, 7, 10, 6, 190, 162, 178, 18];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'receipt';
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [114, 101, 99, 101, 105, 112, 116];
+              },
+              {
+                kind: 'account';
+                path: 'video.videoHash';
+                account: 'Video';
+              },
+              {
+                kind: 'account';
+                path: 'endorser';
+              }
+            ];
+          };
+        },
+        {
+          name: 'endorser';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'cosigner';
+          signer: true;
+          optional: true;
+        },
+        {
+          name: 'statusIndex';
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'isAuthentic';
+          type: 'bool';
+        },
+        {
+          name: 'issueReceipt';
+          type: 'bool';
+        },
+        {
+          name: 'classification';
+          type: {
+            option: 'u8';
+          };
+        }
+      ];
+    },
+    {
+      name: 'executeRecovery';
+      discriminator: [203, 133, 133, 228, 153, 121, 182, 237];
+      accounts: [
+        {
+          name: 'official';
+          writable: true;
+          relations: ['proposal'];
+        },
+        {
+          name: 'proposal';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [114, 101, 99, 111, 118, 101, 114, 121];
+              },
+              {
+                kind: 'account';
+                path: 'official';
+              }
+            ];
+          };
+        },
+        {
+          name: 'rentPayer';
+          writable: true;
+          relations: ['proposal'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'fileCounterClaim';
+      discriminator: [54, 104, 37, 111, 218, 73, 250, 38];
+      accounts: [
+        {
+          name: 'video';
+        },
+        {
+          name: 'counterClaim';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  99, 111, 117, 110, 116, 101, 114, 95, 99, 108, 97, 105, 109
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'video';
+              },
+              {
+                kind: 'account';
+                path: 'claimant';
+              }
+            ];
+          };
+        },
+        {
+          name: 'claimant';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'claimedHash';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'evidenceCid';
+          type: 'string';
+        }
+      ];
+    },
+    {
+      name: 'fileTakedown';
+      discriminator: [141, 44, 67, 80, 178, 13, 239, 199];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'requesterHash';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'reasonCode';
+          type: 'u16';
+        }
+      ];
+    },
+    {
+      name: 'finalizeVoteReceipt';
+      discriminator: [122, 247, 230, 200, 150, 213, 26, 240];
+      accounts: [
+        {
+          name: 'video';
+        },
+        {
+          name: 'receipt';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [114, 101, 99, 101, 105, 112, 116];
+              },
+              {
+                kind: 'account';
+                path: 'receipt.videoHash';
+                account: 'VoteReceipt';
+              },
+              {
+                kind: 'account';
+                path: 'receipt.endorser';
+                account: 'VoteReceipt';
+              }
+            ];
+          };
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'heartbeat';
+      discriminator: [202, 104, 56, 6, 240, 170, 63, 134];
+      accounts: [
+        {
+          name: 'profile';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [101, 110, 100, 111, 114, 115, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'endorser';
+              }
+            ];
+          };
+        },
+        {
+          name: 'endorser';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'initializeConfig';
+      discriminator: [208, 127, 21, 1, 194, 190, 196, 70];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'programData';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  212, 12, 221, 223, 214, 25, 3, 234, 250, 62, 3, 138, 159, 5,
+                  242, 47, 219, 190, 29, 13, 182, 68, 84, 109, 114, 70, 9, 204,
+                  196, 139, 214, 200
+                ];
+              }
+            ];
+            program: {
+              kind: 'const';
+              value: [
+                2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99,
+                174, 43, 0, 194, 185, 61, 22, 193, 36, 210, 192, 83, 122, 16, 4,
+                128, 0, 0
+              ];
+            };
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'maxVotes';
+          type: 'u8';
+        }
+      ];
+    },
+    {
+      name: 'invalidateSnsLink';
+      discriminator: [158, 237, 11, 176, 134, 31, 70, 25];
+      accounts: [
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'nameAccountInfo';
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'liftTakedown';
+      discriminator: [222, 158, 253, 16, 136, 222, 17, 133];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'linkSameEvent';
+      discriminator: [219, 117, 81, 67, 80, 0, 130, 115];
+      accounts: [
+        {
+          name: 'official';
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'otherOfficial';
+        },
+        {
+          name: 'linkedVideo';
+          writable: true;
+        },
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['official'];
+        },
+        {
+          name: 'otherAuthority';
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: 'otherVideo';
+          type: 'pubkey';
+        }
+      ];
+    },
+    {
+      name: 'linkSnsDomain';
+      discriminator: [125, 192, 19, 125, 39, 94, 63, 144];
+      accounts: [
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['official'];
+        },
+        {
+          name: 'nameAccountInfo';
+        }
+      ];
+      args: [
+        {
+          name: 'nameAccount';
+          type: 'pubkey';
+        }
+      ];
+    },
+    {
+      name: 'listVideo';
+      discriminator: [141, 209, 35, 122, 229, 178, 200, 153];
+      accounts: [
+        {
+          name: 'official';
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['official'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'markEndorserActive';
+      discriminator: [121, 254, 11, 175, 215, 92, 142, 251];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'profile';
+        }
+      ];
+      args: [
+        {
+          name: 'slotIndex';
+          type: 'u8';
+        }
+      ];
+    },
+    {
+      name: 'markEndorserInactive';
+      discriminator: [113, 111, 248, 232, 15, 183, 104, 66];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'profile';
+        }
+      ];
+      args: [
+        {
+          name: 'slotIndex';
+          type: 'u8';
+        }
+      ];
+    },
+    {
+      name: 'openStatusIndex';
+      discriminator: [225, 13, 83, 24, 137, 250, 134, 202];
+      accounts: [
+        {
+          name: 'official';
+        },
+        {
+          name: 'statusIndex';
+          writable: true;
+        },
+        {
+          name: 'previous';
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'payer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'status';
+          type: 'u8';
+        },
+        {
+          name: 'page';
+          type: 'u32';
+        }
+      ];
+    },
+    {
+      name: 'proposeAuthorityRecovery';
+      discriminator: [177, 84, 227, 248, 122, 237, 125, 40];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+        },
+        {
+          name: 'proposal';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [114, 101, 99, 111, 118, 101, 114, 121];
+              },
+              {
+                kind: 'account';
+                path: 'official';
+              }
+            ];
+          };
+        },
+        {
+          name: 'endorser';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'newAuthority';
+          type: 'pubkey';
+        }
+      ];
+    },
+    {
+      name: 'proposeThresholdChange';
+      discriminator: [181, 148, 51, 200, 229, 192, 251, 36];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'proposer';
+          signer: true;
+        }
+      ];
+      args: [
+        {
+          name: 'newThreshold';
+          type: 'u8';
+        }
+      ];
+    },
+    {
+      name: 'publishSnapshot';
+      discriminator: [120, 47, 50, 157, 45, 40, 236, 199];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'snapshot';
+          writable: true;
+        },
+        {
+          name: 'snapshotter';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'merkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'videoCount';
+          type: 'u64';
+        }
+      ];
+    },
+    {
+      name: 'reassignHandle';
+      discriminator: [46, 198, 24, 251, 153, 171, 238, 242];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'handleAccount';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [104, 97, 110, 100, 108, 101];
+              },
+              {
+                kind: 'account';
+                path: 'handleAccount';
+              }
+            ];
+          };
+        },
+        {
+          name: 'fromOfficial';
+          writable: true;
+        },
+        {
+          name: 'toOfficial';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'reclaimBond';
+      discriminator: [18, 133, 105, 58, 246, 52, 103, 31];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'rentPayer';
+          docs: ['signer that posted the bond along with the rent).'];
+          writable: true;
+          relations: ['video'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'registerAuthorizedVideo';
+      discriminator: [0, 178, 212, 124, 140, 39, 183, 27];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+          relations: ['authorization'];
+        },
+        {
+          name: 'authorization';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [104, 97, 115, 104, 95, 97, 117, 116, 104];
+              },
+              {
+                kind: 'account';
+                path: 'official';
+              },
+              {
+                kind: 'arg';
+                path: 'videoHash';
+              }
+            ];
+          };
+        },
+        {
+          name: 'authority';
+          writable: true;
+          relations: ['authorization'];
+        },
+        {
+          name: 'video';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [118, 105, 100, 101, 111];
+              },
+              {
+                kind: 'account';
+                path: 'official';
+              },
+              {
+                kind: 'arg';
+                path: 'videoHash';
+              }
+            ];
+          };
+        },
+        {
+          name: 'cidIndex';
+          writable: true;
+        },
+        {
+          name: 'payer';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'videoHash';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'ipfsCid';
+          type: 'string';
+        }
+      ];
+    },
+    {
+      name: 'registerBroadcastAttestor';
+      discriminator: [213, 101, 186, 250, 168, 50, 129, 212];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'broadcastAttestor';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  98, 114, 111, 97, 100, 99, 97, 115, 116, 95, 97, 116, 116,
+                  101, 115, 116, 111, 114
+                ];
+              },
+              {
+                kind: 'arg';
+                path: 'attestor';
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'attestor';
+          type: 'pubkey';
+        }
+      ];
+    },
+    {
+      name: 'registerOfficial';
+      discriminator: [236, 127, 211, 47, 78, 152, 17, 216];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          docs: [
+            'collision returns OfficialAlreadyExists instead of a system error.'
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [111, 102, 102, 105, 99, 105, 97, 108];
+              },
+              {
+                kind: 'arg';
+                path: 'officialId';
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'officialId';
+          type: 'u64';
+        },
+        {
+          name: 'name';
+          type: 'string';
+        },
+        {
+          name: 'authority';
+          type: 'pubkey';
+        },
+        {
+          name: 'endorsers';
+          type: {
+            vec: 'pubkey';
+          };
+        },
+        {
+          name: 'threshold';
+          type: 'u8';
+        },
+        {
+          name: 'rationaleHash';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'rationaleCid';
+          type: 'string';
+        }
+      ];
+    },
+    {
+      name: 'registerProvider';
+      discriminator: [254, 209, 54, 184, 46, 197, 109, 78];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'provider';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 114, 111, 118, 105, 100, 101, 114];
+              },
+              {
+                kind: 'arg';
+                path: 'providerKey';
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'name';
+          type: 'string';
+        },
+        {
+          name: 'providerKey';
+          type: 'pubkey';
+        },
+        {
+          name: 'endpointHash';
+          type: {
+            array: ['u8', 32];
+          };
+        }
+      ];
+    },
+    {
+      name: 'registerVideo';
+      discriminator: [213, 160, 14, 162, 203, 241, 158, 196];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'video';
+          docs: [
+            'duplicate registration returns VideoAlreadyExists instead of the',
+            'system program\'s generic "account already in use" error.'
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [118, 105, 100, 101, 111];
+              },
+              {
+                kind: 'account';
+                path: 'official';
+              },
+              {
+                kind: 'arg';
+                path: 'videoHash';
+              }
+            ];
+          };
+        },
+        {
+          name: 'cidIndex';
+          writable: true;
+        },
+        {
+          name: 'refIndex';
+          docs: ['Address checked and account created in the handler.'];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'authority';
+          writable: true;
+          signer: true;
+          relations: ['official'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'videoHash';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'ipfsCid';
+          type: 'string';
+        },
+        {
+          name: 'visibility';
+          type: 'u8';
+        },
+        {
+          name: 'externalRef';
+          type: {
+            option: 'string';
+          };
+        }
+      ];
+    },
+    {
+      name: 'removeBroadcastAttestor';
+      discriminator: [228, 9, 71, 143, 147, 199, 239, 84];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'broadcastAttestor';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  98, 114, 111, 97, 100, 99, 97, 115, 116, 95, 97, 116, 116,
+                  101, 115, 116, 111, 114
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'broadcastAttestor.attestor';
+                account: 'BroadcastAttestor';
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'removeMirror';
+      discriminator: [231, 23, 144, 62, 254, 56, 20, 81];
+      accounts: [
+        {
+          name: 'official';
+        },
+        {
+          name: 'video';
+        },
+        {
+          name: 'mirrors';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [109, 105, 114, 114, 111, 114, 115];
+              },
+              {
+                kind: 'account';
+                path: 'video';
+              }
+            ];
+          };
+        },
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['official'];
+        }
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u8';
+        }
+      ];
+    },
+    {
+      name: 'requireVerified';
+      discriminator: [125, 36, 43, 119, 230, 69, 115, 200];
+      accounts: [
+        {
+          name: 'video';
+        }
+      ];
+      args: [
+        {
+          name: 'maxAgeSecs';
+          type: 'i64';
+        },
+        {
+          name: 'requiredStatus';
+          type: 'u8';
+        }
+      ];
+    },
+    {
+      name: 'resolveCounterClaim';
+      discriminator: [80, 240, 179, 159, 13, 158, 203, 242];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'video';
+          writable: true;
+          relations: ['counterClaim'];
+        },
+        {
+          name: 'counterClaim';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  99, 111, 117, 110, 116, 101, 114, 95, 99, 108, 97, 105, 109
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'video';
+              },
+              {
+                kind: 'account';
+                path: 'claimant';
+              }
+            ];
+          };
+        },
+        {
+          name: 'claimant';
+          writable: true;
+          relations: ['counterClaim'];
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'valid';
+          type: 'bool';
+        }
+      ];
+    },
+    {
+      name: 'revokeCoAttestation';
+      discriminator: [93, 174, 160, 24, 78, 50, 138, 23];
+      accounts: [
+        {
+          name: 'attestingOfficial';
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'coAttestation';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 95, 97, 116, 116, 101, 115, 116];
+              },
+              {
+                kind: 'account';
+                path: 'video';
+              },
+              {
+                kind: 'account';
+                path: 'attestingOfficial';
+              }
+            ];
+          };
+        },
+        {
+          name: 'rentPayer';
+          docs: [
+            "(and paid); it stays the target after the official's authority rotates."
+          ];
+          writable: true;
+        },
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['attestingOfficial'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'revokeDomainAttestation';
+      discriminator: [130, 41, 213, 165, 238, 192, 174, 214];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'domainIndex';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [100, 111, 109, 97, 105, 110];
+              },
+              {
+                kind: 'account';
+                path: 'domainIndex.domainHash';
+                account: 'DomainIndex';
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'revokeProvider';
+      discriminator: [125, 104, 74, 119, 247, 33, 128, 112];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'provider';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 114, 111, 118, 105, 100, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'provider.providerKey';
+                account: 'Provider';
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'revokeSocial';
+      discriminator: [99, 111, 84, 50, 81, 10, 38, 10];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'socialIndex';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'revokeVideo';
+      discriminator: [144, 198, 242, 250, 9, 43, 46, 112];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'rentPayer';
+          docs: ['when a bond is escrowed and the video is Authentic.'];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'statusIndex';
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['official'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'scheduleRotation';
+      discriminator: [96, 13, 69, 102, 123, 122, 90, 101];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'effectiveAt';
+          type: 'i64';
+        },
+        {
+          name: 'newEndorsers';
+          type: {
+            vec: 'pubkey';
+          };
+        },
+        {
+          name: 'rationaleHash';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'rationaleCid';
+          type: 'string';
+        }
+      ];
+    },
+    {
+      name: 'setBondSettleDelay';
+      discriminator: [31, 212, 122, 252, 222, 175, 105, 176];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'secs';
+          type: 'i64';
+        }
+      ];
+    },
+    {
+      name: 'setClockOverride';
+      discriminator: [17, 204, 153, 196, 183, 110, 174, 227];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'clockOverride';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  99, 108, 111, 99, 107, 95, 111, 118, 101, 114, 114, 105, 100,
+                  101
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'timestamp';
+          type: 'i64';
+        }
+      ];
+    },
+    {
+      name: 'setCloseAuthority';
+      discriminator: [144, 183, 224, 104, 83, 213, 219, 172];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'closeAuthority';
+          type: 'pubkey';
+        }
+      ];
+    },
+    {
+      name: 'setEndorserCosigner';
+      discriminator: [249, 12, 106, 112, 113, 146, 99, 93];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'slotIndex';
+          type: 'u8';
+        },
+        {
+          name: 'cosigner';
+          type: {
+            option: 'pubkey';
+          };
+        }
+      ];
+    },
+    {
+      name: 'setIntegrityParams';
+      discriminator: [133, 224, 3, 204, 247, 72, 79, 178];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'disputeWeightBps';
+          type: 'u16';
+        },
+        {
+          name: 'alertDelta';
+          type: 'u8';
+        }
+      ];
+    },
+    {
+      name: 'setLivenessWindow';
+      discriminator: [168, 214, 59, 218, 155, 188, 106, 87];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'secs';
+          type: 'i64';
+        }
+      ];
+    },
+    {
+      name: 'setProgramVersion';
+      discriminator: [41, 99, 32, 201, 200, 35, 2, 246];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'programVersion';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [118, 101, 114, 115, 105, 111, 110];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          writable: true;
+          signer: true;
+          relations: ['config'];
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        }
+      ];
+      args: [
+        {
+          name: 'major';
+          type: 'u16';
+        },
+        {
+          name: 'minor';
+          type: 'u16';
+        },
+        {
+          name: 'patch';
+          type: 'u16';
+        },
+        {
+          name: 'minSupportedAccountVersion';
+          type: 'u8';
+        },
+        {
+          name: 'changelogHash';
+          type: {
+            array: ['u8', 32];
+          };
+        }
+      ];
+    },
+    {
+      name: 'setRecoveryWindow';
+      discriminator: [65, 103, 105, 100, 202, 110, 128, 132];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'secs';
+          type: 'i64';
+        }
+      ];
+    },
+    {
+      name: 'setRegistrationBond';
+      discriminator: [213, 246, 254, 168, 251, 2, 75, 68];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'lamports';
+          type: 'u64';
+        }
+      ];
+    },
+    {
+      name: 'setRequireEndorserRationale';
+      discriminator: [11, 156, 222, 176, 188, 227, 73, 119];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'required';
+          type: 'bool';
+        }
+      ];
+    },
+    {
+      name: 'setSnapshotter';
+      discriminator: [163, 237, 114, 172, 3, 226, 68, 132];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'snapshotter';
+          type: 'pubkey';
+        }
+      ];
+    },
+    {
+      name: 'setStandbyActivationSecs';
+      discriminator: [220, 116, 21, 156, 59, 234, 44, 16];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'secs';
+          type: 'i64';
+        }
+      ];
+    },
+    {
+      name: 'setStandbyEndorser';
+      discriminator: [179, 12, 4, 230, 50, 101, 69, 238];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'standby';
+          type: 'pubkey';
+        }
+      ];
+    },
+    {
+      name: 'setThresholdProposalWindow';
+      discriminator: [45, 36, 148, 152, 185, 21, 188, 112];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'secs';
+          type: 'i64';
+        }
+      ];
+    },
+    {
+      name: 'slashBond';
+      discriminator: [143, 246, 51, 243, 88, 198, 217, 48];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'video';
+          writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'unfreezeVideo';
+      discriminator: [227, 81, 184, 136, 140, 41, 144, 153];
+      accounts: [
+        {
+          name: 'config';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'video';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'unlinkSnsDomain';
+      discriminator: [188, 38, 1, 3, 163, 66, 239, 134];
+      accounts: [
+        {
+          name: 'official';
+          writable: true;
+        },
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['official'];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: 'updateAdmin';
+      discriminator: [161, 176, 40, 213, 60, 184, 179, 228];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'newAdmin';
+          type: 'pubkey';
+        }
+      ];
+    },
+    {
+      name: 'updateMaxVotes';
+      discriminator: [45, 178, 36, 24, 217, 199, 60, 229];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'maxVotes';
+          type: 'u8';
+        }
+      ];
+    },
+    {
+      name: 'verifyBundle';
+      discriminator: [60, 217, 95, 233, 51, 166, 59, 191];
+      accounts: [
+        {
+          name: 'official';
+        }
+      ];
+      args: [
+        {
+          name: 'hashes';
+          type: {
+            vec: {
+              array: ['u8', 32];
+            };
+          };
+        }
+      ];
+      returns: {
+        defined: {
+          name: 'BundleResult';
+        };
+      };
+    },
+    {
+      name: 'withdrawTreasury';
+      discriminator: [40, 63, 122, 158, 144, 216, 83, 96];
+      accounts: [
+        {
+          name: 'config';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [99, 111, 110, 102, 105, 103];
+              }
+            ];
+          };
+        },
+        {
+          name: 'recipient';
+          writable: true;
+        },
+        {
+          name: 'admin';
+          signer: true;
+          relations: ['config'];
+        }
+      ];
+      args: [
+        {
+          name: 'lamports';
+          type: 'u64';
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: 'BroadcastAttestations';
+      discriminator: [21, 5, 181, 161, 55, 226, 165, 38];
+    },
+    {
+      name: 'BroadcastAttestor';
+      discriminator: [213, 23, 96, 20, 219, 161, 113, 42];
+    },
+    {
+      name: 'ClockOverride';
+      discriminator: [48, 70, 43, 54, 21, 94, 161, 233];
+    },
+    {
+      name: 'CoAttestation';
+      discriminator: [65, 27, 164, 154, 56, 36, 184, 39];
+    },
+    {
+      name: 'Config';
+      discriminator: [155, 12, 170, 224, 30, 250, 204, 130];
+    },
+    {
+      name: 'CorrectionNotices';
+      discriminator: [157, 24, 135, 248, 58, 47, 52, 1];
+    },
+    {
+      name: 'CounterClaim';
+      discriminator: [154, 126, 133, 91, 148, 192, 194, 109];
+    },
+    {
+      name: 'DomainIndex';
+      discriminator: [95, 19, 136, 145, 133, 155, 40, 213];
+    },
+    {
+      name: 'EndorserProfile';
+      discriminator: [169, 236, 231, 232, 237, 114, 247, 254];
+    },
+    {
+      name: 'Handle';
+      discriminator: [150, 96, 143, 54, 64, 147, 63, 63];
+    },
+    {
+      name: 'HashAuthorization';
+      discriminator: [173, 204, 165, 247, 69, 124, 210, 215];
+    },
+    {
+      name: 'IndexerCursor';
+      discriminator: [244, 229, 94, 147, 20, 234, 140, 44];
+    },
+    {
+      name: 'Mirrors';
+      discriminator: [238, 198, 1, 177, 137, 53, 89, 197];
+    },
+    {
+      name: 'Official';
+      discriminator: [111, 189, 248, 110, 255, 197, 65, 87];
+    },
+    {
+      name: 'PinReceipt';
+      discriminator: [236, 197, 213, 123, 140, 207, 219, 226];
+    },
+    {
+      name: 'ProgramVersion';
+      discriminator: [138, 104, 244, 197, 206, 47, 159, 154];
+    },
+    {
+      name: 'Provider';
+      discriminator: [164, 180, 71, 17, 75, 216, 80, 195];
+    },
+    {
+      name: 'RecoveryProposal';
+      discriminator: [87, 221, 41, 148, 57, 150, 240, 111];
+    },
+    {
+      name: 'Snapshot';
+      discriminator: [137, 213, 28, 133, 224, 161, 48, 108];
+    },
+    {
+      name: 'SocialIndex';
+      discriminator: [139, 187, 10, 104, 206, 184, 16, 214];
+    },
+    {
+      name: 'StatusIndex';
+      discriminator: [209, 25, 102, 47, 220, 178, 239, 6];
+    },
+    {
+      name: 'Video';
+      discriminator: [57, 77, 160, 7, 234, 235, 39, 112];
+    },
+    {
+      name: 'VoteReceipt';
+      discriminator: [104, 20, 204, 252, 45, 84, 37, 195];
+    }
+  ];
+  events: [
+    {
+      discriminator: [163, 61, 32, 236, 183, 119, 168, 146];
+      name: 'AuthorityRecovered';
+    },
+    {
+      discriminator: [187, 150, 172, 212, 229, 119, 165, 123];
+      name: 'BondSettled';
+    },
+    {
+      discriminator: [102, 137, 119, 206, 11, 81, 196, 245];
+      name: 'BroadcastAttested';
+    },
+    {
+      discriminator: [166, 221, 194, 44, 214, 245, 4, 3];
+      name: 'CoAttestationRevoked';
+    },
+    {
+      discriminator: [112, 58, 203, 97, 28, 59, 219, 91];
+      name: 'CoAttested';
+    },
+    {
+      discriminator: [30, 165, 51, 53, 117, 114, 185, 155];
+      name: 'CorrectionNoticeAppended';
+    },
+    {
+      discriminator: [101, 69, 122, 165, 97, 98, 75, 165];
+      name: 'CounterClaimFiled';
+    },
+    {
+      discriminator: [74, 130, 119, 111, 232, 76, 244, 200];
+      name: 'CounterClaimResolved';
+    },
+    {
+      discriminator: [156, 206, 169, 84, 165, 227, 252, 179];
+      name: 'DomainAttestationRevoked';
+    },
+    {
+      discriminator: [96, 132, 58, 201, 252, 151, 195, 141];
+      name: 'DomainAttested';
+    },
+    {
+      discriminator: [10, 128, 242, 114, 236, 13, 91, 53];
+      name: 'EndorserMarkedActive';
+    },
+    {
+      discriminator: [12, 25, 147, 198, 226, 129, 133, 135];
+      name: 'EndorserMarkedInactive';
+    },
+    {
+      discriminator: [98, 155, 134, 113, 142, 8, 146, 68];
+      name: 'EndorsersRotated';
+    },
+    {
+      discriminator: [77, 63, 207, 217, 63, 103, 71, 91];
+      name: 'ExternalKeyBound';
+    },
+    {
+      discriminator: [23, 183, 225, 13, 62, 87, 199, 150];
+      name: 'HandleClaimed';
+    },
+    {
+      discriminator: [73, 24, 200, 93, 243, 42, 221, 99];
+      name: 'HandleReassigned';
+    },
+    {
+      discriminator: [242, 188, 66, 13, 109, 69, 249, 162];
+      name: 'IntegrityScoreChanged';
+    },
+    {
+      discriminator: [6, 234, 132, 144, 177, 43, 53, 20];
+      name: 'MirrorAdded';
+    },
+    {
+      discriminator: [168, 255, 22, 84, 79, 137, 151, 24];
+      name: 'MirrorRemoved';
+    },
+    {
+      discriminator: [35, 218, 168, 205, 183, 16, 63, 221];
+      name: 'OfficialSnapshot';
+    },
+    {
+      discriminator: [198, 44, 167, 85, 10, 107, 125, 20];
+      name: 'PinAttested';
+    },
+    {
+      discriminator: [38, 209, 137, 78, 185, 19, 147, 14];
+      name: 'ProviderRegistered';
+    },
+    {
+      discriminator: [117, 68, 121, 195, 229, 6, 85, 214];
+      name: 'ProviderRevoked';
+    },
+    {
+      discriminator: [155, 203, 17, 165, 216, 224, 11, 239];
+      name: 'SnapshotPublished';
+    },
+    {
+      discriminator: [25, 31, 249, 231, 143, 199, 202, 109];
+      name: 'SnsDomainLinked';
+    },
+    {
+      discriminator: [2, 146, 59, 53, 244, 162, 49, 21];
+      name: 'SnsDomainUnlinked';
+    },
+    {
+      discriminator: [230, 107, 19, 190, 192, 1, 88, 167];
+      name: 'SocialAttested';
+    },
+    {
+      discriminator: [52, 34, 108, 49, 177, 39, 144, 236];
+      name: 'SocialRevoked';
+    },
+    {
+      discriminator: [240, 251, 183, 69, 105, 225, 8, 214];
+      name: 'StandbyActivated';
+    },
+    {
+      discriminator: [146, 235, 222, 125, 145, 246, 34, 240];
+      name: 'StatusChanged';
+    },
+    {
+      discriminator: [25, 214, 31, 165, 231, 140, 31, 146];
+      name: 'TakedownFiled';
+    },
+    {
+      discriminator: [141, 175, 71, 144, 84, 192, 127, 161];
+      name: 'TakedownLifted';
+    },
+    {
+      discriminator: [212, 208, 6, 73, 92, 65, 97, 229];
+      name: 'ThresholdChanged';
+    },
+    {
+      discriminator: [143, 181, 157, 169, 87, 155, 170, 46];
+      name: 'TreasuryWithdrawn';
+    },
+    {
+      discriminator: [254, 102, 154, 86, 213, 228, 14, 69];
+      name: 'VideoFrozen';
+    },
+    {
+      discriminator: [0, 118, 237, 5, 23, 192, 223, 107];
+      name: 'VideoListed';
+    },
+    {
+      discriminator: [103, 240, 201, 70, 245, 230, 116, 154];
+      name: 'VideoRegistered';
+    },
+    {
+      discriminator: [153, 15, 92, 133, 166, 254, 152, 169];
+      name: 'VideoRevoked';
+    },
+    {
+      discriminator: [163, 99, 214, 54, 171, 132, 113, 184];
+      name: 'VideoSnapshot';
+    },
+    {
+      discriminator: [162, 77, 251, 138, 55, 13, 250, 15];
+      name: 'VideosLinked';
+    },
+    {
+      discriminator: [39, 53, 195, 104, 188, 17, 225, 213];
+      name: 'VoteCast';
+    },
+    {
+      discriminator: [79, 26, 11, 164, 223, 15, 1, 154];
+      name: 'VoteChanged';
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: 'UnauthorizedOfficial';
+      msg: "Only the official's authority can register videos";
+    },
+    {
+      code: 6001;
+      name: 'UnauthorizedEndorser';
+      msg: 'Only approved endorsers can vote on videos';
+    },
+    {
+      code: 6002;
+      name: 'AlreadyVoted';
+      msg: 'This endorser has already voted on this video';
+    },
+    {
+      code: 6003;
+      name: 'TooManyVotes';
+      msg: 'Maximum number of votes reached for this video';
+    },
+    {
+      code: 6004;
+      name: 'VideoAlreadyExists';
+      msg: 'Video already registered for this official';
+    },
+    {
+      code: 6005;
+      name: 'InvalidOfficialName';
+      msg: 'Invalid official name (must be <= 32 bytes UTF-8)';
+    },
+    {
+      code: 6006;
+      name: 'InvalidIpfsCid';
       msg: 'Invalid IPFS CID format (must be non-empty and <= 64 bytes)';
     },
     {
-      code: 6007;
-      name: 'InvalidEndorserCount';
-      msg: 'Must provide exactly 3 endorsers';
+      code: 6007;
+      name: 'InvalidEndorserCount';
+      msg: 'Endorser count must be between 1 and config.max_votes';
+    },
+    {
+      code: 6008;
+      name: 'DuplicateEndorsers';
+      msg: 'Duplicate endorsers not allowed - each endorser must be unique';
+    },
+    {
+      code: 6009;
+      name: 'InvalidEndorser';
+      msg: 'Invalid endorser pubkey - cannot be default or system program';
+    },
+    {
+      code: 6010;
+      name: 'UnauthorizedAdmin';
+      msg: 'Only the config admin can perform this action';
+    },
+    {
+      code: 6011;
+      name: 'InvalidMaxVotes';
+      msg: 'Invalid max_votes (must be between 1 and 10)';
+    },
+    {
+      code: 6012;
+      name: 'VoteReceiptMismatch';
+      msg: 'issue_receipt must match whether a receipt account was passed';
+    },
+    {
+      code: 6013;
+      name: 'ReceiptVideoMismatch';
+      msg: 'Vote receipt does not belong to this video';
+    },
+    {
+      code: 6014;
+      name: 'VideoNotFinalized';
+      msg: 'Video has not been finalized yet';
+    },
+    {
+      code: 6015;
+      name: 'OfficialAlreadyExists';
+      msg: 'An official with this id is already registered';
+    },
+    {
+      code: 6016;
+      name: 'InvalidAuthorizationBatch';
+      msg: 'Hash batch must be 1..=10 hashes with matching authorization accounts in order';
+    },
+    {
+      code: 6017;
+      name: 'HashAlreadyAuthorized';
+      msg: 'This hash is already authorized for registration';
+    },
+    {
+      code: 6018;
+      name: 'InvalidMirrorKind';
+      msg: 'Unknown mirror pointer kind';
+    },
+    {
+      code: 6019;
+      name: 'InvalidMirrorUri';
+      msg: 'Mirror URI is not valid for its pointer kind';
+    },
+    {
+      code: 6020;
+      name: 'DuplicateMirror';
+      msg: 'This mirror URI is already recorded for the video';
+    },
+    {
+      code: 6021;
+      name: 'TooManyMirrors';
+      msg: 'Maximum number of mirrors reached for this video';
+    },
+    {
+      code: 6022;
+      name: 'MirrorIndexOutOfBounds';
+      msg: 'Mirror index out of bounds';
+    },
+    {
+      code: 6023;
+      name: 'VideoTakenDown';
+      msg: 'This video is under a takedown order';
+    },
+    {
+      code: 6024;
+      name: 'TakedownAlreadyActive';
+      msg: 'A takedown is already active for this video';
+    },
+    {
+      code: 6025;
+      name: 'NoActiveTakedown';
+      msg: 'No active takedown for this video';
+    },
+    {
+      code: 6026;
+      name: 'CounterClaimMismatch';
+      msg: 'Counter-claim does not belong to this video/claimant';
+    },
+    {
+      code: 6027;
+      name: 'VideoFrozen';
+      msg: 'This video is frozen for review';
+    },
+    {
+      code: 6028;
+      name: 'VideoNotFrozen';
+      msg: 'This video is not frozen';
+    },
+    {
+      code: 6029;
+      name: 'CannotLinkSelf';
+      msg: 'A video cannot be linked to itself';
+    },
+    {
+      code: 6030;
+      name: 'AlreadyLinked';
+      msg: 'These videos are already linked';
+    },
+    {
+      code: 6031;
+      name: 'TooManyRelatedVideos';
+      msg: 'Maximum number of related videos reached';
+    },
+    {
+      code: 6032;
+      name: 'InvalidEndorserSlot';
+      msg: 'Endorser slot index out of range';
+    },
+    {
+      code: 6033;
+      name: 'InvalidCosigner';
+      msg: 'Cosigner cannot be default, the authority, or a panel endorser';
+    },
+    {
+      code: 6034;
+      name: 'MissingCosigner';
+      msg: 'This endorser slot requires its cosigner to sign';
+    },
+    {
+      code: 6035;
+      name: 'InvalidHandle';
+      msg: 'Invalid handle (3-32 chars of a-z, 0-9, inner hyphens; not reserved)';
+    },
+    {
+      code: 6036;
+      name: 'OfficialHasHandle';
+      msg: 'This official already has a handle';
+    },
+    {
+      code: 6037;
+      name: 'HandleOwnerMismatch';
+      msg: 'Handle is not held by the given official';
+    },
+    {
+      code: 6038;
+      name: 'InvalidClassification';
+      msg: 'Unknown classification value';
+    },
+    {
+      code: 6039;
+      name: 'SelfAttestation';
+      msg: 'An official cannot co-attest its own video';
+    },
+    {
+      code: 6040;
+      name: 'VideoNotAuthentic';
+      msg: 'Video must be Authentic';
+    },
+    {
+      code: 6041;
+      name: 'CounterOverflow';
+      msg: 'Counter overflow';
+    },
+    {
+      code: 6042;
+      name: 'BondNotEscrowed';
+      msg: 'No escrowed registration bond on this video';
+    },
+    {
+      code: 6043;
+      name: 'BondPayerMismatch';
+      msg: 'Bond refund must go to the recorded bond payer';
+    },
+    {
+      code: 6044;
+      name: 'VideoNotDisputed';
+      msg: 'Only Disputed videos can have their bond slashed';
+    },
+    {
+      code: 6045;
+      name: 'InvalidIntegrityParams';
+      msg: 'Integrity dispute weight must be at most 10000 bps';
+    },
+    {
+      code: 6046;
+      name: 'InvalidStatusValue';
+      msg: 'Unknown video status value';
+    },
+    {
+      code: 6047;
+      name: 'VerificationRequirementNotMet';
+      msg: 'Video does not meet the required verification status';
+    },
+    {
+      code: 6048;
+      name: 'VerificationTooOld';
+      msg: 'Video verification is older than the allowed maximum age';
+    },
+    {
+      code: 6049;
+      name: 'UnauthorizedCursorOwner';
+      msg: 'Only the cursor owner can update it';
+    },
+    {
+      code: 6050;
+      name: 'NonMonotonicCursor';
+      msg: 'Cursor sequence must strictly increase';
+    },
+    {
+      code: 6051;
+      name: 'InvalidExternalKey';
+      msg: 'Unknown external key kind or empty fingerprint';
+    },
+    {
+      code: 6052;
+      name: 'ExternalKeyProofUnsupported';
+      msg: 'This external key kind cannot be proven on-chain';
+    },
+    {
+      code: 6053;
+      name: 'InvalidExternalKeyProof';
+      msg: 'Missing or mismatched ed25519 proof for the external key';
+    },
+    {
+      code: 6054;
+      name: 'InvalidDomain';
+      msg: 'Domain must be a lowercase hostname of at most 64 bytes';
+    },
+    {
+      code: 6055;
+      name: 'DomainAlreadyAttested';
+      msg: 'Domain is already attested for another official';
+    },
+    {
+      code: 6056;
+      name: 'OfficialHasDomain';
+      msg: 'Official already has an attested domain; revoke it first';
+    },
+    {
+      code: 6057;
+      name: 'DomainOwnerMismatch';
+      msg: 'Domain index does not belong to this official';
+    },
+    {
+      code: 6058;
+      name: 'InvalidSocialHandle';
+      msg: 'Unknown social platform or malformed handle';
+    },
+    {
+      code: 6059;
+      name: 'SocialHandleTaken';
+      msg: 'Social handle is already attested for another official';
+    },
+    {
+      code: 6060;
+      name: 'SocialIndexMismatch';
+      msg: "Social index does not match the official's attestation";
+    },
+    {
+      code: 6061;
+      name: 'InvalidRotationTime';
+      msg: 'Rotation must take effect in the future';
+    },
+    {
+      code: 6062;
+      name: 'NoScheduledRotation';
+      msg: 'Official has no scheduled rotation';
+    },
+    {
+      code: 6063;
+      name: 'RotationNotDue';
+      msg: 'Scheduled rotation is not yet effective';
+    },
+    {
+      code: 6064;
+      name: 'InvalidSnsNameAccount';
+      msg: 'Account is not an SNS name registry account';
+    },
+    {
+      code: 6065;
+      name: 'SnsOwnerMismatch';
+      msg: "SNS domain is not owned by the official's authority";
+    },
+    {
+      code: 6066;
+      name: 'NoSnsLink';
+      msg: 'Official has no linked SNS domain';
+    },
+    {
+      code: 6067;
+      name: 'SnsLinkStillValid';
+      msg: "SNS domain is still owned by the official's authority";
+    },
+    {
+      code: 6068;
+      name: 'InvalidBundle';
+      msg: 'Bundle must have 1..=MAX_BUNDLE_SIZE hashes and one account per hash';
+    },
+    {
+      code: 6069;
+      name: 'BundleOrderMismatch';
+      msg: 'Bundle account does not match the hash at the same position';
+    },
+    {
+      code: 6070;
+      name: 'UnauthorizedSnapshotter';
+      msg: 'Only the configured snapshotter can publish snapshots';
+    },
+    {
+      code: 6071;
+      name: 'SnapshotCountMismatch';
+      msg: "Snapshot video count does not match the official's registered videos";
+    },
+    {
+      code: 6072;
+      name: 'UnauthorizedCloser';
+      msg: 'Signer is not the rent payer, the admin, or the close authority';
+    },
+    {
+      code: 6073;
+      name: 'InvalidStandbyActivation';
+      msg: 'Standby activation delay must be positive';
+    },
+    {
+      code: 6074;
+      name: 'NoStandbyEndorser';
+      msg: 'Official has no standby endorser';
+    },
+    {
+      code: 6075;
+      name: 'VideoAlreadyFinalized';
+      msg: 'Video has already been finalized';
+    },
+    {
+      code: 6076;
+      name: 'StandbyAlreadyActive';
+      msg: 'Standby endorser is already active on this video';
+    },
+    {
+      code: 6077;
+      name: 'StandbyNotDue';
+      msg: 'Video is not old enough to activate the standby endorser';
+    },
+    {
+      code: 6078;
+      name: 'StandbyNotNeeded';
+      msg: 'Video has no missing votes for the standby to fill';
+    },
+    {
+      code: 6079;
+      name: 'NothingToEmit';
+      msg: 'Pass an Official and/or a Video to emit';
+    },
+    {
+      code: 6080;
+      name: 'CidHashMismatch';
+      msg: 'CID was already registered by this official with a different video hash';
+    },
+    {
+      code: 6081;
+      name: 'InvalidProviderName';
+      msg: 'Provider name must be 1-32 bytes';
+    },
+    {
+      code: 6082;
+      name: 'UnauthorizedProvider';
+      msg: "Signer is not this provider's key";
+    },
+    {
+      code: 6083;
+      name: 'ProviderRevoked';
+      msg: 'Provider has been revoked';
+    },
+    {
+      code: 6084;
+      name: 'InvalidPinExpiry';
+      msg: 'Pin expiry must be in the future';
+    },
+    {
+      code: 6085;
+      name: 'PinReceiptNotExpired';
+      msg: 'Pin receipt is still valid: not expired and its provider is not revoked';
+    },
+    {
+      code: 6086;
+      name: 'RentPayerMismatch';
+      msg: 'Refund account does not match the recorded rent payer';
+    },
+    {
+      code: 6087;
+      name: 'InvalidLivenessWindow';
+      msg: 'Liveness window must be positive';
+    },
+    {
+      code: 6088;
+      name: 'EndorserProfileMismatch';
+      msg: "Profile account is not the slot endorser's profile";
+    },
+    {
+      code: 6089;
+      name: 'EndorserStillLive';
+      msg: 'Endorser has a heartbeat within the liveness window';
+    },
+    {
+      code: 6090;
+      name: 'EndorserStillStale';
+      msg: 'Endorser has no heartbeat within the liveness window';
+    },
+    {
+      code: 6091;
+      name: 'InvalidBroadcastTime';
+      msg: 'Broadcast time cannot be in the future';
+    },
+    {
+      code: 6092;
+      name: 'BroadcastAlreadyAttested';
+      msg: 'Attestor has already attested a broadcast for this video';
+    },
+    {
+      code: 6093;
+      name: 'TooManyBroadcastAttestations';
+      msg: 'Maximum number of broadcast attestations reached';
+    },
+    {
+      code: 6094;
+      name: 'InvalidClockOverride';
+      msg: 'Clock override must not be negative';
+    },
+    {
+      code: 6095;
+      name: 'ClockOverrideDisabled';
+      msg: 'Clock override is only available in test-clock builds';
+    },
+    {
+      code: 6096;
+      name: 'InvalidVisibility';
+      msg: 'Invalid visibility value';
+    },
+    {
+      code: 6097;
+      name: 'VideoAlreadyListed';
+      msg: 'Video is already listed';
+    },
+    {
+      code: 6098;
+      name: 'InvalidEndorserRationale';
+      msg: 'Endorser rationale hash and CID must be given together, CID at most 64 bytes';
+    },
+    {
+      code: 6099;
+      name: 'MissingEndorserRationale';
+      msg: 'An endorser-selection rationale is required';
+    },
+    {
+      code: 6100;
+      name: 'StaleEndorserRationale';
+      msg: 'A panel change needs a new endorser-selection rationale';
+    },
+    {
+      code: 6101;
+      name: 'NoticesFull';
+      msg: 'Maximum number of correction notices reached';
+    },
+    {
+      code: 6102;
+      name: 'InvalidExternalRef';
+      msg: 'External reference must be 1-32 bytes of printable ASCII';
+    },
+    {
+      code: 6103;
+      name: 'ExternalRefTaken';
+      msg: 'External reference already used by this official';
+    },
+    {
+      code: 6104;
+      name: 'RefIndexMismatch';
+      msg: 'Ref index account does not match the external reference';
+    },
+    {
+      code: 6105;
+      name: 'InvalidRecoveryWindow';
+      msg: 'Recovery window must be positive';
+    },
+    {
+      code: 6106;
+      name: 'InvalidRecoveryAuthority';
+      msg: 'Invalid recovery authority';
+    },
+    {
+      code: 6107;
+      name: 'RecoveryTargetIsEndorser';
+      msg: "New authority cannot be one of the official's endorsers";
+    },
+    {
+      code: 6108;
+      name: 'RecoveryPending';
+      msg: 'A recovery proposal is already open for this official';
+    },
+    {
+      code: 6109;
+      name: 'RecoveryExpired';
+      msg: 'Recovery proposal has expired';
+    },
+    {
+      code: 6110;
+      name: 'RecoveryAlreadyApproved';
+      msg: 'Endorser has already approved this recovery';
+    },
+    {
+      code: 6111;
+      name: 'RecoveryQuorumNotMet';
+      msg: 'Not enough endorser approvals to execute the recovery';
+    },
+    {
+      code: 6112;
+      name: 'StatusIndexMismatch';
+      msg: 'Status index does not match this official, status or page';
+    },
+    {
+      code: 6113;
+      name: 'StatusIndexFull';
+      msg: 'Status index page is full; pass its continuation page';
+    },
+    {
+      code: 6114;
+      name: 'StatusIndexNotFull';
+      msg: 'A continuation page can only follow a full page';
+    },
+    {
+      code: 6115;
+      name: 'InvalidThreshold';
+      msg: 'Threshold must be between 1 and the panel size';
+    },
+    {
+      code: 6116;
+      name: 'ThresholdChangePending';
+      msg: 'A threshold change is already pending for this official';
+    },
+    {
+      code: 6117;
+      name: 'NoThresholdChange';
+      msg: 'No threshold change is pending';
+    },
+    {
+      code: 6118;
+      name: 'ThresholdChangeExpired';
+      msg: 'Threshold change proposal has expired';
+    },
+    {
+      code: 6119;
+      name: 'ThresholdAlreadyApproved';
+      msg: 'Endorser has already approved this threshold change';
+    },
+    {
+      code: 6120;
+      name: 'InvalidThresholdProposalWindow';
+      msg: 'Threshold proposal window must be positive';
+    },
+    {
+      code: 6121;
+      name: 'VersionRegression';
+      msg: 'Program version must be greater than the published one';
+    },
+    {
+      code: 6122;
+      name: 'InvalidAdmin';
+      msg: 'Admin cannot be the default pubkey';
+    },
+    {
+      code: 6123;
+      name: 'VideoRevoked';
+      msg: 'Video has been revoked';
+    },
+    {
+      code: 6124;
+      name: 'VoteNotFound';
+      msg: 'Endorser has not voted on this video';
+    },
+    {
+      code: 6125;
+      name: 'VoteUnchanged';
+      msg: 'Vote already has this value';
+    },
+    {
+      code: 6126;
+      name: 'InsufficientTreasury';
+      msg: 'Treasury holds fewer withdrawable lamports than requested';
+    },
+    {
+      code: 6127;
+      name: 'ThresholdExceedsVoteCap';
+      msg: "Official's threshold exceeds the vote capacity of new videos";
+    },
+    {
+      code: 6128;
+      name: 'BondAlreadySettled';
+      msg: 'Bond was already settled under the current verdict';
+    },
+    {
+      code: 6129;
+      name: 'StatusIndexRequired';
+      msg: 'A status index page must be passed for this status change';
+    },
+    {
+      code: 6130;
+      name: 'InvalidBondSettleDelay';
+      msg: 'Bond settle delay cannot be negative';
+    },
+    {
+      code: 6131;
+      name: 'BondSettleDelayActive';
+      msg: "Bond can't be settled until the settle delay after finalization has passed";
+    },
+    {
+      code: 6132;
+      name: 'StaleHashAuthorization';
+      msg: 'Hash authorization was signed by a previous authority of this official';
+    }
+  ];
+  types: [
+    {
+      name: 'AuthorityRecovered';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'oldAuthority';
+            type: 'pubkey';
+          },
+          {
+            name: 'newAuthority';
+            type: 'pubkey';
+          },
+          {
+            name: 'approvers';
+            type: {
+              vec: 'pubkey';
+            };
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'BondSettled';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'recipient';
+            type: 'pubkey';
+          },
+          {
+            name: 'lamports';
+            type: 'u64';
+          },
+          {
+            name: 'slashed';
+            type: 'bool';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'BondState';
+      repr: {
+        kind: 'rust';
+      };
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'None';
+          },
+          {
+            name: 'Escrowed';
+          },
+          {
+            name: 'Reclaimed';
+          },
+          {
+            name: 'Slashed';
+          }
+        ];
+      };
+    },
+    {
+      name: 'BroadcastAttestation';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'attestor';
+            type: 'pubkey';
+          },
+          {
+            name: 'broadcastTime';
+            type: 'i64';
+          },
+          {
+            name: 'channelHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'attestedAt';
+            type: 'i64';
+          }
+        ];
+      };
+    },
+    {
+      name: 'BroadcastAttestations';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'entries';
+            type: {
+              vec: {
+                defined: {
+                  name: 'BroadcastAttestation';
+                };
+              };
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'BroadcastAttested';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'attestor';
+            type: 'pubkey';
+          },
+          {
+            name: 'broadcastTime';
+            type: 'i64';
+          },
+          {
+            name: 'channelHash';
+            type: {
+              array: ['u8', 32];
+            };
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'BroadcastAttestor';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'attestor';
+            type: 'pubkey';
+          },
+          {
+            name: 'addedAt';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'BundleResult';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'authentic';
+            type: 'bytes';
+          },
+          {
+            name: 'notAuthentic';
+            type: 'bytes';
+          }
+        ];
+      };
+    },
+    {
+      name: 'ClockOverride';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'unixTimestamp';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'CoAttestation';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'attestingOfficial';
+            type: 'pubkey';
+          },
+          {
+            name: 'authority';
+            type: 'pubkey';
+          },
+          {
+            name: 'attestedAt';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'CoAttestationRevoked';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'attestingOfficial';
+            type: 'pubkey';
+          },
+          {
+            name: 'coAttestationCount';
+            type: 'u16';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'CoAttested';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'attestingOfficial';
+            type: 'pubkey';
+          },
+          {
+            name: 'coAttestationCount';
+            type: 'u16';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'Config';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'admin';
+            type: 'pubkey';
+          },
+          {
+            name: 'maxVotes';
+            type: 'u8';
+          },
+          {
+            name: 'registrationBondLamports';
+            type: 'u64';
+          },
+          {
+            name: 'integrityDisputeWeightBps';
+            type: 'u16';
+          },
+          {
+            name: 'integrityAlertDelta';
+            type: 'u8';
+          },
+          {
+            name: 'snapshotter';
+            type: 'pubkey';
+          },
+          {
+            name: 'closeAuthority';
+            type: 'pubkey';
+          },
+          {
+            name: 'standbyActivationSecs';
+            type: 'i64';
+          },
+          {
+            name: 'livenessWindowSecs';
+            type: 'i64';
+          },
+          {
+            name: 'requireEndorserRationale';
+            type: 'bool';
+          },
+          {
+            name: 'recoveryWindowSecs';
+            type: 'i64';
+          },
+          {
+            name: 'thresholdProposalSecs';
+            type: 'i64';
+          },
+          {
+            name: 'bondSettleDelaySecs';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'CorrectionNotice';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'noticeHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'noticeCid';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'appendedAt';
+            type: 'i64';
+          }
+        ];
+      };
+    },
+    {
+      name: 'CorrectionNoticeAppended';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'index';
+            type: 'u8';
+          },
+          {
+            name: 'noticeHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'noticeCid';
+            type: 'string';
+          },
+          {
+            name: 'hasNotice';
+            type: 'bool';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'CorrectionNotices';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'notices';
+            type: {
+              vec: {
+                defined: {
+                  name: 'CorrectionNotice';
+                };
+              };
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'CounterClaim';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'claimant';
+            type: 'pubkey';
+          },
+          {
+            name: 'claimedHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'evidenceCid';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'bondLamports';
+            type: 'u64';
+          },
+          {
+            name: 'filedAt';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'CounterClaimFiled';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'claimant';
+            type: 'pubkey';
+          },
+          {
+            name: 'claimedHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'bondLamports';
+            type: 'u64';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'CounterClaimResolved';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'claimant';
+            type: 'pubkey';
+          },
+          {
+            name: 'valid';
+            type: 'bool';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'DomainAttestationRevoked';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'domain';
+            type: 'string';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'DomainAttested';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'domain';
+            type: 'string';
+          },
+          {
+            name: 'attestedBy';
+            type: 'pubkey';
+          },
+          {
+            name: 'attestedAt';
+            type: 'i64';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'DomainIndex';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'domainHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'EndorserMarkedActive';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'slotIndex';
+            type: 'u8';
+          },
+          {
+            name: 'endorser';
+            type: 'pubkey';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'EndorserMarkedInactive';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'slotIndex';
+            type: 'u8';
+          },
+          {
+            name: 'endorser';
+            type: 'pubkey';
+          },
+          {
+            name: 'lastHeartbeatAt';
+            type: 'i64';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'EndorserProfile';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'endorser';
+            type: 'pubkey';
+          },
+          {
+            name: 'lastHeartbeatAt';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'EndorsersRotated';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'oldEndorsers';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'newEndorsers';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'purgedVotes';
+            type: 'u32';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'ExternalKeyBound';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'keyKind';
+            type: 'u8';
+          },
+          {
+            name: 'oldFingerprint';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'newFingerprint';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'proofVerified';
+            type: 'bool';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'Handle';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'handle';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'HandleClaimed';
+      type: {
+        fields: [
+          {
+            name: 'handle';
+            type: 'string';
+          },
+          {
+            name: 'official';
+            type: 'pubkey';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'HandleReassigned';
+      type: {
+        fields: [
+          {
+            name: 'handle';
+            type: 'string';
+          },
+          {
+            name: 'fromOfficial';
+            type: 'pubkey';
+          },
+          {
+            name: 'toOfficial';
+            type: 'pubkey';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'HashAuthorization';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'videoHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'authority';
+            type: 'pubkey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'IndexerCursor';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'labelHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'owner';
+            type: 'pubkey';
+          },
+          {
+            name: 'lastSequence';
+            type: 'u64';
+          },
+          {
+            name: 'updatedAt';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'IntegrityScoreChanged';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'oldScore';
+            type: 'u8';
+          },
+          {
+            name: 'newScore';
+            type: 'u8';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'Mirror';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'kind';
+            type: 'u8';
+          },
+          {
+            name: 'uri';
+            type: {
+              array: ['u8', 128];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: 'MirrorAdded';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'index';
+            type: 'u8';
+          },
+          {
+            name: 'kind';
+            type: 'u8';
+          },
+          {
+            name: 'uri';
+            type: 'string';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'MirrorRemoved';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'index';
+            type: 'u8';
+          },
+          {
+            name: 'kind';
+            type: 'u8';
+          },
+          {
+            name: 'uri';
+            type: 'string';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'Mirrors';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'mirrors';
+            type: {
+              vec: {
+                defined: {
+                  name: 'Mirror';
+                };
+              };
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'Official';
+      type: {
+        fields: [
+          {
+            name: 'officialId';
+            type: 'u64';
+          },
+          {
+            name: 'name';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'authority';
+            type: 'pubkey';
+          },
+          {
+            name: 'endorsers';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'endorserCosigners';
+            type: {
+              vec: {
+                option: 'pubkey';
+              };
+            };
+          },
+          {
+            name: 'handle';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'finalizedCount';
+            type: 'u32';
+          },
+          {
+            name: 'disputedCount';
+            type: 'u32';
+          },
+          {
+            name: 'integrityScore';
+            type: 'u8';
+          },
+          {
+            name: 'externalKeyFingerprint';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'externalKeyKind';
+            type: 'u8';
+          },
+          {
+            name: 'domain';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'domainAttestedAt';
+            type: 'i64';
+          },
+          {
+            name: 'domainAttestedBy';
+            type: 'pubkey';
+          },
+          {
+            name: 'socialAttestations';
+            type: {
+              vec: {
+                defined: {
+                  name: 'SocialAttestation';
+                };
+              };
+            };
+          },
+          {
+            name: 'scheduledRotation';
+            type: {
+              option: {
+                defined: {
+                  name: 'ScheduledRotation';
+                };
+              };
+            };
+          },
+          {
+            name: 'snsNameAccount';
+            type: 'pubkey';
+          },
+          {
+            name: 'videoCount';
+            type: 'u64';
+          },
+          {
+            name: 'snapshotEpoch';
+            type: 'u64';
+          },
+          {
+            name: 'standbyEndorser';
+            type: 'pubkey';
+          },
+          {
+            name: 'inactiveSlots';
+            type: 'u16';
+          },
+          {
+            name: 'endorserRationaleHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'endorserRationaleCid';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'minFinalizeSecs';
+            type: 'u64';
+          },
+          {
+            name: 'maxFinalizeSecs';
+            type: 'u64';
+          },
+          {
+            name: 'meanFinalizeMillis';
+            type: 'u64';
+          },
+          {
+            name: 'totalFinalizeSecs';
+            type: 'u64';
+          },
+          {
+            name: 'threshold';
+            type: 'u8';
+          },
+          {
+            name: 'pendingThreshold';
+            type: {
+              option: {
+                defined: {
+                  name: 'ThresholdProposal';
+                };
+              };
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'OfficialSnapshot';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'state';
+            type: {
+              defined: {
+                name: 'Official';
+              };
+            };
+          },
+          {
+            name: 'slot';
+            type: 'u64';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'PinAttested';
+      type: {
+        fields: [
+          {
+            name: 'provider';
+            type: 'pubkey';
+          },
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'expiresAt';
+            type: 'i64';
+          },
+          {
+            name: 'activeReceipts';
+            type: 'u32';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'PinReceipt';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'provider';
+            type: 'pubkey';
+          },
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'attestedAt';
+            type: 'i64';
+          },
+          {
+            name: 'expiresAt';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'ProgramVersion';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'major';
+            type: 'u16';
+          },
+          {
+            name: 'minor';
+            type: 'u16';
+          },
+          {
+            name: 'patch';
+            type: 'u16';
+          },
+          {
+            name: 'minSupportedAccountVersion';
+            type: 'u8';
+          },
+          {
+            name: 'upgradeSlot';
+            type: 'u64';
+          },
+          {
+            name: 'changelogHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'Provider';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'providerKey';
+            type: 'pubkey';
+          },
+          {
+            name: 'name';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'endpointHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'revoked';
+            type: 'bool';
+          },
+          {
+            name: 'activeReceipts';
+            type: 'u32';
+          },
+          {
+            name: 'registeredAt';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'ProviderRegistered';
+      type: {
+        fields: [
+          {
+            name: 'provider';
+            type: 'pubkey';
+          },
+          {
+            name: 'providerKey';
+            type: 'pubkey';
+          },
+          {
+            name: 'name';
+            type: 'string';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'ProviderRevoked';
+      type: {
+        fields: [
+          {
+            name: 'provider';
+            type: 'pubkey';
+          },
+          {
+            name: 'activeReceipts';
+            type: 'u32';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'RecoveryProposal';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'newAuthority';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposer';
+            type: 'pubkey';
+          },
+          {
+            name: 'approvals';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'expiresAt';
+            type: 'i64';
+          },
+          {
+            name: 'rentPayer';
+            type: 'pubkey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'ScheduledRotation';
+      type: {
+        fields: [
+          {
+            name: 'effectiveAt';
+            type: 'i64';
+          },
+          {
+            name: 'newEndorsers';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'rationaleHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'rationaleCid';
+            type: {
+              array: ['u8', 64];
+            };
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'Snapshot';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'epoch';
+            type: 'u64';
+          },
+          {
+            name: 'merkleRoot';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'videoCount';
+            type: 'u64';
+          },
+          {
+            name: 'slot';
+            type: 'u64';
+          },
+          {
+            name: 'publishedAt';
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'SnapshotPublished';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'epoch';
+            type: 'u64';
+          },
+          {
+            name: 'merkleRoot';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'videoCount';
+            type: 'u64';
+          },
+          {
+            name: 'slot';
+            type: 'u64';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'SnsDomainLinked';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'nameAccount';
+            type: 'pubkey';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'SnsDomainUnlinked';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'nameAccount';
+            type: 'pubkey';
+          },
+          {
+            name: 'invalidated';
+            type: 'bool';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'SocialAttestation';
+      type: {
+        fields: [
+          {
+            name: 'platform';
+            type: 'u8';
+          },
+          {
+            name: 'handle';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'attestedAt';
+            type: 'i64';
+          }
+        ];
+        kind: 'struct';
+      };
     },
     {
-      code: 6008;
-      name: 'DuplicateEndorsers';
-      msg: 'Duplicate endorsers not allowed - each endorser must be unique';
+      name: 'SocialAttested';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'platform';
+            type: 'u8';
+          },
+          {
+            name: 'handle';
+            type: 'string';
+          },
+          {
+            name: 'replacedHandle';
+            type: {
+              option: 'string';
+            };
+          }
+        ];
+        kind: 'struct';
+      };
     },
     {
-      code: 6009;
-      name: 'InvalidEndorser';
-      msg: 'Invalid endorser pubkey - cannot be default or system program';
-    }
-  ];
-  types: [
+      name: 'SocialIndex';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'platform';
+            type: 'u8';
+          },
+          {
+            name: 'handle';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
     {
-      name: 'Official';
+      name: 'SocialRevoked';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'platform';
+            type: 'u8';
+          },
+          {
+            name: 'handle';
+            type: 'string';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'StandbyActivated';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'standby';
+            type: 'pubkey';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'StatusChanged';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'old';
+            type: {
+              defined: {
+                name: 'VideoStatus';
+              };
+            };
+          },
+          {
+            name: 'new';
+            type: {
+              defined: {
+                name: 'VideoStatus';
+              };
+            };
+          },
+          {
+            name: 'classification';
+            type: {
+              option: 'u8';
+            };
+          },
+          {
+            name: 'minFinalizeSecs';
+            type: 'u64';
+          },
+          {
+            name: 'maxFinalizeSecs';
+            type: 'u64';
+          },
+          {
+            name: 'meanFinalizeMillis';
+            type: 'u64';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'StatusIndex';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'status';
+            type: {
+              defined: {
+                name: 'VideoStatus';
+              };
+            };
+          },
+          {
+            name: 'page';
+            type: 'u32';
+          },
+          {
+            name: 'videos';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'next';
+            type: 'pubkey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          }
+        ];
+      };
+    },
+    {
+      name: 'TakedownFiled';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'requesterHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'reasonCode';
+            type: 'u16';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'TakedownLifted';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'reasonCode';
+            type: 'u16';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'ThresholdChanged';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'oldThreshold';
+            type: 'u8';
+          },
+          {
+            name: 'newThreshold';
+            type: 'u8';
+          },
+          {
+            name: 'approvers';
+            type: {
+              vec: 'pubkey';
+            };
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'ThresholdProposal';
+      type: {
+        fields: [
+          {
+            name: 'newThreshold';
+            type: 'u8';
+          },
+          {
+            name: 'proposedBy';
+            type: 'pubkey';
+          },
+          {
+            name: 'expiresAt';
+            type: 'i64';
+          },
+          {
+            name: 'approvals';
+            type: {
+              vec: 'pubkey';
+            };
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'TreasuryWithdrawn';
       type: {
+        fields: [
+          {
+            name: 'recipient';
+            type: 'pubkey';
+          },
+          {
+            name: 'lamports';
+            type: 'u64';
+          }
+        ];
         kind: 'struct';
+      };
+    },
+    {
+      name: 'Video';
+      type: {
         fields: [
           {
-            name: 'officialId';
-            type: 'u64';
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'videoHash';
+            type: {
+              array: ['u8', 32];
+            };
           },
           {
-            name: 'name';
+            name: 'ipfsCid';
+            type: {
+              array: ['u8', 64];
+            };
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+          {
+            name: 'maxVotesAtRegistration';
+            type: 'u8';
+          },
+          {
+            name: 'votes';
+            type: {
+              vec: {
+                defined: {
+                  name: 'Vote';
+                };
+              };
+            };
+          },
+          {
+            name: 'status';
+            type: {
+              defined: {
+                name: 'VideoStatus';
+              };
+            };
+          },
+          {
+            name: 'takedown';
+            type: 'bool';
+          },
+          {
+            name: 'takedownRequesterHash';
             type: {
               array: ['u8', 32];
             };
           },
           {
-            name: 'authority';
+            name: 'takedownReasonCode';
+            type: 'u16';
+          },
+          {
+            name: 'frozen';
+            type: 'bool';
+          },
+          {
+            name: 'relatedVideos';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'classification';
+            type: {
+              option: 'u8';
+            };
+          },
+          {
+            name: 'coAttestationCount';
+            type: 'u16';
+          },
+          {
+            name: 'bondLamports';
+            type: 'u64';
+          },
+          {
+            name: 'bondState';
+            type: {
+              defined: {
+                name: 'BondState';
+              };
+            };
+          },
+          {
+            name: 'finalizedAt';
+            type: 'i64';
+          },
+          {
+            name: 'standbyActive';
+            type: 'bool';
+          },
+          {
+            name: 'rentPayer';
             type: 'pubkey';
           },
           {
-            name: 'endorsers';
+            name: 'listed';
+            type: 'bool';
+          },
+          {
+            name: 'hasNotice';
+            type: 'bool';
+          },
+          {
+            name: 'externalRef';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'thresholdAtRegistration';
+            type: 'u8';
+          },
+          {
+            name: 'statusIndexPage';
             type: {
-              array: ['pubkey', 3];
+              option: 'u32';
             };
           },
           {
@@ -233,17 +5862,57 @@ export type Truchain = {
             type: 'u8';
           }
         ];
+        kind: 'struct';
       };
     },
     {
-      name: 'Video';
+      name: 'VideoFrozen';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'frozen';
+            type: 'bool';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'VideoListed';
       type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'ipfsCid';
+            type: 'string';
+          }
+        ];
         kind: 'struct';
+      };
+    },
+    {
+      name: 'VideoRegistered';
+      type: {
         fields: [
           {
             name: 'official';
             type: 'pubkey';
           },
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
           {
             name: 'videoHash';
             type: {
@@ -253,36 +5922,59 @@ export type Truchain = {
           {
             name: 'ipfsCid';
             type: {
-              array: ['u8', 64];
+              option: 'string';
             };
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'VideoRevoked';
+      type: {
+        fields: [
+          {
+            name: 'official';
+            type: 'pubkey';
           },
           {
-            name: 'timestamp';
-            type: 'i64';
+            name: 'video';
+            type: 'pubkey';
           },
           {
-            name: 'votes';
+            name: 'oldStatus';
             type: {
-              vec: {
-                defined: {
-                  name: 'Vote';
-                };
+              defined: {
+                name: 'VideoStatus';
               };
             };
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'VideoSnapshot';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
           },
           {
-            name: 'status';
+            name: 'state';
             type: {
               defined: {
-                name: 'VideoStatus';
+                name: 'Video';
               };
             };
           },
           {
-            name: 'bump';
-            type: 'u8';
+            name: 'slot';
+            type: 'u64';
           }
         ];
+        kind: 'struct';
       };
     },
     {
@@ -301,12 +5993,93 @@ export type Truchain = {
           },
           {
             name: 'Disputed';
+          },
+          {
+            name: 'Revoked';
+          }
+        ];
+      };
+    },
+    {
+      name: 'VideosLinked';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'otherVideo';
+            type: 'pubkey';
           }
         ];
+        kind: 'struct';
       };
     },
     {
       name: 'Vote';
+      type: {
+        fields: [
+          {
+            name: 'endorser';
+            type: 'pubkey';
+          },
+          {
+            name: 'isAuthentic';
+            type: 'bool';
+          },
+          {
+            name: 'classification';
+            type: {
+              option: 'u8';
+            };
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'VoteCast';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'endorser';
+            type: 'pubkey';
+          },
+          {
+            name: 'isAuthentic';
+            type: 'bool';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'VoteChanged';
+      type: {
+        fields: [
+          {
+            name: 'video';
+            type: 'pubkey';
+          },
+          {
+            name: 'endorser';
+            type: 'pubkey';
+          },
+          {
+            name: 'isAuthentic';
+            type: 'bool';
+          }
+        ];
+        kind: 'struct';
+      };
+    },
+    {
+      name: 'VoteReceipt';
       type: {
         kind: 'struct';
         fields: [
@@ -314,9 +6087,37 @@ export type Truchain = {
             name: 'endorser';
             type: 'pubkey';
           },
+          {
+            name: 'official';
+            type: 'pubkey';
+          },
+          {
+            name: 'videoHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
           {
             name: 'isAuthentic';
             type: 'bool';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+          {
+            name: 'finalStatus';
+            type: {
+              option: {
+                defined: {
+                  name: 'VideoStatus';
+                };
+              };
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
           }
         ];
       };
@@ -130,6 +130,7 @@ export function EndorserPanel({ assignedOfficials }: EndorserPanelProps) {
     if (status.unverified !== undefined) return 'Unverified';
     if (status.authentic !== undefined) return 'Authentic';
     if (status.disputed !== undefined) return 'Disputed';
+    if (status.revoked !== undefined) return 'Revoked';
     return 'Unknown';
   };
 
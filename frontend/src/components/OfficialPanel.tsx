@@ -84,6 +84,7 @@ export function OfficialPanel({ officialAccount }: OfficialPanelProps) {
     if (status.unverified !== undefined) return 'Unverified';
     if (status.authentic !== undefined) return 'Authentic';
     if (status.disputed !== undefined) return 'Disputed';
+    if (status.revoked !== undefined) return 'Revoked';
     return 'Unknown';
   };
 
@@ -75,15 +75,29 @@ export async function checkIsEndorser(
   );
 }
 
+/**
+ * Derive the program's Config PDA
+ */
+function configPda(program: Program<Truchain>) {
+  return PublicKey.findProgramAddressSync(
+    [new TextEncoder().encode('config')],
+    program.programId
+  )[0];
+}
+
 /**
  * Register a new official (admin only)
+ * Defaults to the original 2-of-N panel with no published rationale
  */
 export async function registerOfficial(
   program: Program<Truchain>,
   officialId: number,
   name: string,
   authority: PublicKey,
-  endorsers: [PublicKey, PublicKey, PublicKey]
+  endorsers: PublicKey[],
+  threshold: number = Math.min(2, endorsers.length),
+  rationaleHash: number[] = Array(32).fill(0),
+  rationaleCid: string = ''
 ) {
   // Convert officialId to u64 bytes (8 bytes, little-endian)
   const officialIdBuffer = new Uint8Array(8);
@@ -96,8 +110,9 @@ export async function registerOfficial(
   );
 
   return await program.methods
-    .registerOfficial(new BN(officialId), name, authority, endorsers)
+    .registerOfficial(new BN(officialId), name, authority, endorsers, threshold, rationaleHash, rationaleCid)
     .accountsPartial({
+      config: configPda(program),
       official: officialPda,
       admin: program.provider.publicKey!,
     })
@@ -106,30 +121,103 @@ export async function registerOfficial(
 
 /**
  * Register a video (official's authority only)
+ * Visibility 0 publishes the CID immediately; 1 holds it back until the panel rules
  */
 export async function registerVideo(
   program: Program<Truchain>,
   officialPubkey: PublicKey,
   videoHash: number[],
-  ipfsCid: string
+  ipfsCid: string,
+  visibility: number = 0,
+  externalRef: string | null = null
 ) {
   const [videoPda] = PublicKey.findProgramAddressSync(
     [new TextEncoder().encode('video'), officialPubkey.toBuffer(), Uint8Array.from(videoHash)],
     program.programId
   );
+  const cidHash = new Uint8Array(
+    await crypto.subtle.digest('SHA-256', new TextEncoder().encode(ipfsCid))
+  );
+  const [cidIndexPda] = PublicKey.findProgramAddressSync(
+    [new TextEncoder().encode('cid'), officialPubkey.toBuffer(), cidHash],
+    program.programId
+  );
+  const refIndexPda = externalRef === null
+    ? null
+    : PublicKey.findProgramAddressSync(
+        [
+          new TextEncoder().encode('ref'),
+          officialPubkey.toBuffer(),
+          new Uint8Array(await crypto.subtle.digest('SHA-256', new TextEncoder().encode(externalRef))),
+        ],
+        program.programId
+      )[0];
 
   return await program.methods
-    .registerVideo(videoHash, ipfsCid)
+    .registerVideo(videoHash, ipfsCid, visibility, externalRef)
     .accountsPartial({
+      config: configPda(program),
       official: officialPubkey,
       video: videoPda,
+      cidIndex: cidIndexPda,
+      refIndex: refIndexPda,
       authority: program.provider.publicKey!,
     })
     .rpc();
 }
 
+// Video keys per StatusIndex page (STATUS_INDEX_CAPACITY on-chain)
+const STATUS_INDEX_CAPACITY = 64;
+
+function statusIndexPda(program: Program<Truchain>, officialPubkey: PublicKey, status: number, page: number) {
+  const pageBytes = new Uint8Array(4);
+  new DataView(pageBytes.buffer).setUint32(0, page, true);
+  return PublicKey.findProgramAddressSync(
+    [new TextEncoder().encode('status_index'), officialPubkey.toBuffer(), Uint8Array.from([status]), pageBytes],
+    program.programId
+  )[0];
+}
+
+/**
+ * Find the page a newly finalized video should be appended to: the tail of
+ * the official's bucket for `status` (1 = Authentic, 2 = Disputed).
+ * Returns the instruction opening a fresh page when the bucket has none or
+ * its tail is full.
+ */
+async function tailStatusIndex(program: Program<Truchain>, officialPubkey: PublicKey, status: number) {
+  let page = 0;
+  let pda = statusIndexPda(program, officialPubkey, status, page);
+  let account = await program.account.statusIndex.fetchNullable(pda);
+  if (!account) {
+    const openIx = await program.methods
+      .openStatusIndex(status, page)
+      .accountsPartial({ official: officialPubkey, statusIndex: pda, previous: null, payer: program.provider.publicKey! })
+      .instruction();
+    return { statusIndex: pda, openIx };
+  }
+
+  while (!account.next.equals(PublicKey.default)) {
+    page += 1;
+    pda = account.next;
+    account = await program.account.statusIndex.fetch(pda);
+  }
+  if (account.videos.length < STATUS_INDEX_CAPACITY) {
+    return { statusIndex: pda, openIx: null };
+  }
+
+  const next = statusIndexPda(program, officialPubkey, status, page + 1);
+  const openIx = await program.methods
+    .openStatusIndex(status, page + 1)
+    .accountsPartial({ official: officialPubkey, statusIndex: next, previous: pda, payer: program.provider.publicKey! })
+    .instruction();
+  return { statusIndex: next, openIx };
+}
+
 /**
  * Endorse a video (endorser only)
+ * Leaves out the optional receipt and cosigner accounts and sends no
+ * classification label. The status index page is always passed, since a
+ * vote that finalizes the video must append it to its bucket.
  */
 export async function endorseVideo(
   program: Program<Truchain>,
@@ -141,13 +229,20 @@ export async function endorseVideo(
     [new TextEncoder().encode('video'), officialPubkey.toBuffer(), Uint8Array.from(videoHash)],
     program.programId
   );
+  // a vote can only finalize the video in its own direction
+  const { statusIndex, openIx } = await tailStatusIndex(program, officialPubkey, isAuthentic ? 1 : 2);
 
   return await program.methods
-    .endorseVideo(isAuthentic)
+    .endorseVideo(isAuthentic, false, null)
     .accountsPartial({
+      config: configPda(program),
       official: officialPubkey,
       video: videoPda,
+      receipt: null,
       endorser: program.provider.publicKey!,
+      cosigner: null,
+      statusIndex,
     })
+    .preInstructions(openIx ? [openIx] : [])
     .rpc();
 }
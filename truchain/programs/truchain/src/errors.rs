@@ -23,7 +23,7 @@ pub enum TruChainError {
     #[msg("Invalid IPFS CID format (must be non-empty and <= 64 bytes)")]
     InvalidIpfsCid,
 
-    #[msg("Must provide exactly 3 endorsers")]
+    #[msg("Endorser count must be between 1 and config.max_votes")]
     InvalidEndorserCount,
 
     #[msg("Duplicate endorsers not allowed - each endorser must be unique")]
@@ -31,4 +31,373 @@ pub enum TruChainError {
 
     #[msg("Invalid endorser pubkey - cannot be default or system program")]
     InvalidEndorser,
+
+    #[msg("Only the config admin can perform this action")]
+    UnauthorizedAdmin,
+
+    #[msg("Invalid max_votes (must be between 1 and 10)")]
+    InvalidMaxVotes,
+
+    #[msg("issue_receipt must match whether a receipt account was passed")]
+    VoteReceiptMismatch,
+
+    #[msg("Vote receipt does not belong to this video")]
+    ReceiptVideoMismatch,
+
+    #[msg("Video has not been finalized yet")]
+    VideoNotFinalized,
+
+    #[msg("An official with this id is already registered")]
+    OfficialAlreadyExists,
+
+    #[msg("Hash batch must be 1..=10 hashes with matching authorization accounts in order")]
+    InvalidAuthorizationBatch,
+
+    #[msg("This hash is already authorized for registration")]
+    HashAlreadyAuthorized,
+
+    #[msg("Unknown mirror pointer kind")]
+    InvalidMirrorKind,
+
+    #[msg("Mirror URI is not valid for its pointer kind")]
+    InvalidMirrorUri,
+
+    #[msg("This mirror URI is already recorded for the video")]
+    DuplicateMirror,
+
+    #[msg("Maximum number of mirrors reached for this video")]
+    TooManyMirrors,
+
+    #[msg("Mirror index out of bounds")]
+    MirrorIndexOutOfBounds,
+
+    #[msg("This video is under a takedown order")]
+    VideoTakenDown,
+
+    #[msg("A takedown is already active for this video")]
+    TakedownAlreadyActive,
+
+    #[msg("No active takedown for this video")]
+    NoActiveTakedown,
+
+    #[msg("Counter-claim does not belong to this video/claimant")]
+    CounterClaimMismatch,
+
+    #[msg("This video is frozen for review")]
+    VideoFrozen,
+
+    #[msg("This video is not frozen")]
+    VideoNotFrozen,
+
+    #[msg("A video cannot be linked to itself")]
+    CannotLinkSelf,
+
+    #[msg("These videos are already linked")]
+    AlreadyLinked,
+
+    #[msg("Maximum number of related videos reached")]
+    TooManyRelatedVideos,
+
+    #[msg("Endorser slot index out of range")]
+    InvalidEndorserSlot,
+
+    #[msg("Cosigner cannot be default, the authority, or a panel endorser")]
+    InvalidCosigner,
+
+    #[msg("This endorser slot requires its cosigner to sign")]
+    MissingCosigner,
+
+    #[msg("Invalid handle (3-32 chars of a-z, 0-9, inner hyphens; not reserved)")]
+    InvalidHandle,
+
+    #[msg("This official already has a handle")]
+    OfficialHasHandle,
+
+    #[msg("Handle is not held by the given official")]
+    HandleOwnerMismatch,
+
+    #[msg("Unknown classification value")]
+    InvalidClassification,
+
+    #[msg("An official cannot co-attest its own video")]
+    SelfAttestation,
+
+    #[msg("Video must be Authentic")]
+    VideoNotAuthentic,
+
+    #[msg("Counter overflow")]
+    CounterOverflow,
+
+    #[msg("No escrowed registration bond on this video")]
+    BondNotEscrowed,
+
+    #[msg("Bond refund must go to the recorded bond payer")]
+    BondPayerMismatch,
+
+    #[msg("Only Disputed videos can have their bond slashed")]
+    VideoNotDisputed,
+
+    #[msg("Integrity dispute weight must be at most 10000 bps")]
+    InvalidIntegrityParams,
+
+    #[msg("Unknown video status value")]
+    InvalidStatusValue,
+
+    #[msg("Video does not meet the required verification status")]
+    VerificationRequirementNotMet,
+
+    #[msg("Video verification is older than the allowed maximum age")]
+    VerificationTooOld,
+
+    #[msg("Only the cursor owner can update it")]
+    UnauthorizedCursorOwner,
+
+    #[msg("Cursor sequence must strictly increase")]
+    NonMonotonicCursor,
+
+    #[msg("Unknown external key kind or empty fingerprint")]
+    InvalidExternalKey,
+
+    #[msg("This external key kind cannot be proven on-chain")]
+    ExternalKeyProofUnsupported,
+
+    #[msg("Missing or mismatched ed25519 proof for the external key")]
+    InvalidExternalKeyProof,
+
+    #[msg("Domain must be a lowercase hostname of at most 64 bytes")]
+    InvalidDomain,
+
+    #[msg("Domain is already attested for another official")]
+    DomainAlreadyAttested,
+
+    #[msg("Official already has an attested domain; revoke it first")]
+    OfficialHasDomain,
+
+    #[msg("Domain index does not belong to this official")]
+    DomainOwnerMismatch,
+
+    #[msg("Unknown social platform or malformed handle")]
+    InvalidSocialHandle,
+
+    #[msg("Social handle is already attested for another official")]
+    SocialHandleTaken,
+
+    #[msg("Social index does not match the official's attestation")]
+    SocialIndexMismatch,
+
+    #[msg("Rotation must take effect in the future")]
+    InvalidRotationTime,
+
+    #[msg("Official has no scheduled rotation")]
+    NoScheduledRotation,
+
+    #[msg("Scheduled rotation is not yet effective")]
+    RotationNotDue,
+
+    #[msg("Account is not an SNS name registry account")]
+    InvalidSnsNameAccount,
+
+    #[msg("SNS domain is not owned by the official's authority")]
+    SnsOwnerMismatch,
+
+    #[msg("Official has no linked SNS domain")]
+    NoSnsLink,
+
+    #[msg("SNS domain is still owned by the official's authority")]
+    SnsLinkStillValid,
+
+    #[msg("Bundle must have 1..=MAX_BUNDLE_SIZE hashes and one account per hash")]
+    InvalidBundle,
+
+    #[msg("Bundle account does not match the hash at the same position")]
+    BundleOrderMismatch,
+
+    #[msg("Only the configured snapshotter can publish snapshots")]
+    UnauthorizedSnapshotter,
+
+    #[msg("Snapshot video count does not match the official's registered videos")]
+    SnapshotCountMismatch,
+
+    #[msg("Signer is not the rent payer, the admin, or the close authority")]
+    UnauthorizedCloser,
+
+    #[msg("Standby activation delay must be positive")]
+    InvalidStandbyActivation,
+
+    #[msg("Official has no standby endorser")]
+    NoStandbyEndorser,
+
+    #[msg("Video has already been finalized")]
+    VideoAlreadyFinalized,
+
+    #[msg("Standby endorser is already active on this video")]
+    StandbyAlreadyActive,
+
+    #[msg("Video is not old enough to activate the standby endorser")]
+    StandbyNotDue,
+
+    #[msg("Video has no missing votes for the standby to fill")]
+    StandbyNotNeeded,
+
+    #[msg("Pass an Official and/or a Video to emit")]
+    NothingToEmit,
+
+    #[msg("CID was already registered by this official with a different video hash")]
+    CidHashMismatch,
+
+    #[msg("Provider name must be 1-32 bytes")]
+    InvalidProviderName,
+
+    #[msg("Signer is not this provider's key")]
+    UnauthorizedProvider,
+
+    #[msg("Provider has been revoked")]
+    ProviderRevoked,
+
+    #[msg("Pin expiry must be in the future")]
+    InvalidPinExpiry,
+
+    #[msg("Pin receipt is still valid: not expired and its provider is not revoked")]
+    PinReceiptNotExpired,
+
+    #[msg("Refund account does not match the recorded rent payer")]
+    RentPayerMismatch,
+
+    #[msg("Liveness window must be positive")]
+    InvalidLivenessWindow,
+
+    #[msg("Profile account is not the slot endorser's profile")]
+    EndorserProfileMismatch,
+
+    #[msg("Endorser has a heartbeat within the liveness window")]
+    EndorserStillLive,
+
+    #[msg("Endorser has no heartbeat within the liveness window")]
+    EndorserStillStale,
+
+    #[msg("Broadcast time cannot be in the future")]
+    InvalidBroadcastTime,
+
+    #[msg("Attestor has already attested a broadcast for this video")]
+    BroadcastAlreadyAttested,
+
+    #[msg("Maximum number of broadcast attestations reached")]
+    TooManyBroadcastAttestations,
+
+    #[msg("Clock override must not be negative")]
+    InvalidClockOverride,
+
+    #[msg("Clock override is only available in test-clock builds")]
+    ClockOverrideDisabled,
+
+    #[msg("Invalid visibility value")]
+    InvalidVisibility,
+
+    #[msg("Video is already listed")]
+    VideoAlreadyListed,
+
+    #[msg("Endorser rationale hash and CID must be given together, CID at most 64 bytes")]
+    InvalidEndorserRationale,
+
+    #[msg("An endorser-selection rationale is required")]
+    MissingEndorserRationale,
+
+    #[msg("A panel change needs a new endorser-selection rationale")]
+    StaleEndorserRationale,
+
+    #[msg("Maximum number of correction notices reached")]
+    NoticesFull,
+
+    #[msg("External reference must be 1-32 bytes of printable ASCII")]
+    InvalidExternalRef,
+
+    #[msg("External reference already used by this official")]
+    ExternalRefTaken,
+
+    #[msg("Ref index account does not match the external reference")]
+    RefIndexMismatch,
+
+    #[msg("Recovery window must be positive")]
+    InvalidRecoveryWindow,
+
+    #[msg("Invalid recovery authority")]
+    InvalidRecoveryAuthority,
+
+    #[msg("New authority cannot be one of the official's endorsers")]
+    RecoveryTargetIsEndorser,
+
+    #[msg("A recovery proposal is already open for this official")]
+    RecoveryPending,
+
+    #[msg("Recovery proposal has expired")]
+    RecoveryExpired,
+
+    #[msg("Endorser has already approved this recovery")]
+    RecoveryAlreadyApproved,
+
+    #[msg("Not enough endorser approvals to execute the recovery")]
+    RecoveryQuorumNotMet,
+
+    #[msg("Status index does not match this official, status or page")]
+    StatusIndexMismatch,
+
+    #[msg("Status index page is full; pass its continuation page")]
+    StatusIndexFull,
+
+    #[msg("A continuation page can only follow a full page")]
+    StatusIndexNotFull,
+
+    #[msg("Threshold must be between 1 and the panel size")]
+    InvalidThreshold,
+
+    #[msg("A threshold change is already pending for this official")]
+    ThresholdChangePending,
+
+    #[msg("No threshold change is pending")]
+    NoThresholdChange,
+
+    #[msg("Threshold change proposal has expired")]
+    ThresholdChangeExpired,
+
+    #[msg("Endorser has already approved this threshold change")]
+    ThresholdAlreadyApproved,
+
+    #[msg("Threshold proposal window must be positive")]
+    InvalidThresholdProposalWindow,
+
+    #[msg("Program version must be greater than the published one")]
+    VersionRegression,
+
+    #[msg("Admin cannot be the default pubkey")]
+    InvalidAdmin,
+
+    #[msg("Video has been revoked")]
+    VideoRevoked,
+
+    #[msg("Endorser has not voted on this video")]
+    VoteNotFound,
+
+    #[msg("Vote already has this value")]
+    VoteUnchanged,
+
+    #[msg("Treasury holds fewer withdrawable lamports than requested")]
+    InsufficientTreasury,
+
+    #[msg("Official's threshold exceeds the vote capacity of new videos")]
+    ThresholdExceedsVoteCap,
+
+    #[msg("Bond was already settled under the current verdict")]
+    BondAlreadySettled,
+
+    #[msg("A status index page must be passed for this status change")]
+    StatusIndexRequired,
+
+    #[msg("Bond settle delay cannot be negative")]
+    InvalidBondSettleDelay,
+
+    #[msg("Bond can't be settled until the settle delay after finalization has passed")]
+    BondSettleDelayActive,
+
+    #[msg("Hash authorization was signed by a previous authority of this official")]
+    StaleHashAuthorization,
 }
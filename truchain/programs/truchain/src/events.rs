@@ -0,0 +1,316 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Official, Video, VideoStatus};
+
+#[event]
+pub struct MirrorAdded {
+    pub video: Pubkey,
+    pub index: u8,
+    pub kind: u8,
+    pub uri: String,
+}
+
+#[event]
+pub struct MirrorRemoved {
+    pub video: Pubkey,
+    pub index: u8,
+    pub kind: u8,
+    pub uri: String,
+}
+
+#[event]
+pub struct TakedownFiled {
+    pub video: Pubkey,
+    pub requester_hash: [u8; 32],
+    pub reason_code: u16,
+}
+
+#[event]
+pub struct TakedownLifted {
+    pub video: Pubkey,
+    pub reason_code: u16,
+}
+
+#[event]
+pub struct CounterClaimFiled {
+    pub video: Pubkey,
+    pub claimant: Pubkey,
+    pub claimed_hash: [u8; 32],
+    pub bond_lamports: u64,
+}
+
+#[event]
+pub struct CounterClaimResolved {
+    pub video: Pubkey,
+    pub claimant: Pubkey,
+    pub valid: bool,
+}
+
+#[event]
+pub struct VideoFrozen {
+    pub video: Pubkey,
+    pub frozen: bool,
+}
+
+#[event]
+pub struct VideosLinked {
+    pub video: Pubkey,
+    pub other_video: Pubkey,
+}
+
+#[event]
+pub struct HandleClaimed {
+    pub handle: String,
+    pub official: Pubkey,
+}
+
+#[event]
+pub struct HandleReassigned {
+    pub handle: String,
+    pub from_official: Pubkey,
+    pub to_official: Pubkey,
+}
+
+#[event]
+pub struct StatusChanged {
+    pub video: Pubkey,
+    pub old: VideoStatus,
+    pub new: VideoStatus,
+    pub classification: Option<u8>,
+    // the official's finalization latency stats after this change
+    pub min_finalize_secs: u64,
+    pub max_finalize_secs: u64,
+    pub mean_finalize_millis: u64,
+}
+
+#[event]
+pub struct CoAttested {
+    pub video: Pubkey,
+    pub attesting_official: Pubkey,
+    pub co_attestation_count: u16,
+}
+
+#[event]
+pub struct CoAttestationRevoked {
+    pub video: Pubkey,
+    pub attesting_official: Pubkey,
+    pub co_attestation_count: u16,
+}
+
+#[event]
+pub struct BondSettled {
+    pub video: Pubkey,
+    pub recipient: Pubkey,
+    pub lamports: u64,
+    pub slashed: bool,
+}
+
+#[event]
+pub struct IntegrityScoreChanged {
+    pub official: Pubkey,
+    pub old_score: u8,
+    pub new_score: u8,
+}
+
+#[event]
+pub struct ExternalKeyBound {
+    pub official: Pubkey,
+    pub key_kind: u8,
+    pub old_fingerprint: [u8; 32],
+    pub new_fingerprint: [u8; 32],
+    pub proof_verified: bool,
+}
+
+#[event]
+pub struct DomainAttested {
+    pub official: Pubkey,
+    pub domain: String,
+    pub attested_by: Pubkey,
+    pub attested_at: i64,
+}
+
+#[event]
+pub struct DomainAttestationRevoked {
+    pub official: Pubkey,
+    pub domain: String,
+}
+
+#[event]
+pub struct SocialAttested {
+    pub official: Pubkey,
+    pub platform: u8,
+    pub handle: String,
+    pub replaced_handle: Option<String>,
+}
+
+#[event]
+pub struct SocialRevoked {
+    pub official: Pubkey,
+    pub platform: u8,
+    pub handle: String,
+}
+
+#[event]
+pub struct EndorsersRotated {
+    pub official: Pubkey,
+    pub old_endorsers: Vec<Pubkey>,
+    pub new_endorsers: Vec<Pubkey>,
+    pub purged_votes: u32,
+}
+
+#[event]
+pub struct SnsDomainLinked {
+    pub official: Pubkey,
+    pub name_account: Pubkey,
+}
+
+#[event]
+pub struct SnsDomainUnlinked {
+    pub official: Pubkey,
+    pub name_account: Pubkey,
+    pub invalidated: bool,
+}
+
+#[event]
+pub struct SnapshotPublished {
+    pub official: Pubkey,
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub video_count: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct StandbyActivated {
+    pub video: Pubkey,
+    pub standby: Pubkey,
+}
+
+// Synthetic events from emit_current_state: full account state at `slot`,
+// not tied to any change.
+#[event]
+pub struct OfficialSnapshot {
+    pub official: Pubkey,
+    pub state: Official,
+    pub slot: u64,
+}
+
+#[event]
+pub struct VideoSnapshot {
+    pub video: Pubkey,
+    pub state: Video,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ProviderRegistered {
+    pub provider: Pubkey,
+    pub provider_key: Pubkey,
+    pub name: String,
+}
+
+#[event]
+pub struct ProviderRevoked {
+    pub provider: Pubkey,
+    pub active_receipts: u32,
+}
+
+#[event]
+pub struct PinAttested {
+    pub provider: Pubkey,
+    pub video: Pubkey,
+    pub expires_at: i64,
+    pub active_receipts: u32,
+}
+
+#[event]
+pub struct EndorserMarkedInactive {
+    pub official: Pubkey,
+    pub slot_index: u8,
+    pub endorser: Pubkey,
+    pub last_heartbeat_at: i64,
+}
+
+#[event]
+pub struct EndorserMarkedActive {
+    pub official: Pubkey,
+    pub slot_index: u8,
+    pub endorser: Pubkey,
+}
+
+#[event]
+pub struct BroadcastAttested {
+    pub video: Pubkey,
+    pub attestor: Pubkey,
+    pub broadcast_time: i64,
+    pub channel_hash: [u8; 32],
+}
+
+// ipfs_cid is None for videos registered as unlisted; see VideoListed.
+#[event]
+pub struct VideoRegistered {
+    pub official: Pubkey,
+    pub video: Pubkey,
+    pub video_hash: [u8; 32],
+    pub ipfs_cid: Option<String>,
+}
+
+#[event]
+pub struct VideoListed {
+    pub official: Pubkey,
+    pub video: Pubkey,
+    pub ipfs_cid: String,
+}
+
+#[event]
+pub struct CorrectionNoticeAppended {
+    pub official: Pubkey,
+    pub video: Pubkey,
+    pub index: u8,
+    pub notice_hash: [u8; 32],
+    pub notice_cid: String,
+    pub has_notice: bool,
+}
+
+#[event]
+pub struct AuthorityRecovered {
+    pub official: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub approvers: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ThresholdChanged {
+    pub official: Pubkey,
+    pub old_threshold: u8,
+    pub new_threshold: u8,
+    pub approvers: Vec<Pubkey>,
+}
+
+#[event]
+pub struct VoteCast {
+    pub video: Pubkey,
+    pub endorser: Pubkey,
+    pub is_authentic: bool,
+}
+
+#[event]
+pub struct VoteChanged {
+    pub video: Pubkey,
+    pub endorser: Pubkey,
+    pub is_authentic: bool,
+}
+
+#[event]
+pub struct VideoRevoked {
+    pub official: Pubkey,
+    pub video: Pubkey,
+    pub old_status: VideoStatus,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub recipient: Pubkey,
+    pub lamports: u64,
+}
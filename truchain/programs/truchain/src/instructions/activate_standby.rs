@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::StandbyActivated;
+use crate::state::{Config, Official, Video, VideoStatus};
+use crate::utils::current_time;
+
+// Permissionless: once a video has waited long enough with votes missing
+// (or a panelist who hasn't voted is marked inactive), the official's
+// standby endorser may cast one vote in place of a silent panelist.
+#[derive(Accounts)]
+pub struct ActivateStandby<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(constraint = official.has_standby() @ TruChainError::NoStandbyEndorser)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = !video.is_finalized() @ TruChainError::VideoAlreadyFinalized,
+        constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked,
+        constraint = !video.standby_active @ TruChainError::StandbyAlreadyActive
+    )]
+    pub video: Account<'info, Video>,
+}
+
+pub fn handler(ctx: Context<ActivateStandby>) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let video = &mut ctx.accounts.video;
+
+    // a panelist already marked inactive who hasn't voted skips the wait
+    let official = &ctx.accounts.official;
+    let silent_inactive = official.endorsers.iter().enumerate().any(|(slot, endorser)| {
+        official.is_slot_inactive(slot) && !video.votes.iter().any(|v| v.endorser == *endorser)
+    });
+
+    let age = now.saturating_sub(video.timestamp);
+    if age < ctx.accounts.config.standby_activation_secs && !silent_inactive {
+        return err!(TruChainError::StandbyNotDue);
+    }
+
+    if video.votes.len() >= video.max_votes_at_registration as usize {
+        return err!(TruChainError::StandbyNotNeeded);
+    }
+
+    video.standby_active = true;
+
+    emit!(StandbyActivated {
+        video: video.key(),
+        standby: ctx.accounts.official.standby_endorser,
+    });
+
+    Ok(())
+}
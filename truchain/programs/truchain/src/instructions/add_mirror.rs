@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::MirrorAdded;
+use crate::state::{
+    Mirror, MirrorKind, Mirrors, Official, Video, VideoStatus, MAX_MIRRORS, MAX_MIRROR_URI_LEN,
+    MIRRORS_SIZE,
+};
+
+#[derive(Accounts)]
+pub struct AddMirror<'info> {
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = !video.takedown @ TruChainError::VideoTakenDown,
+        constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked
+    )]
+    pub video: Account<'info, Video>,
+
+    // created on the first mirror, reused afterwards
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MIRRORS_SIZE,
+        seeds = [b"mirrors", video.key().as_ref()],
+        bump
+    )]
+    pub mirrors: Account<'info, Mirrors>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddMirror>, pointer_kind: u8, uri: String) -> Result<()> {
+    let kind = MirrorKind::from_u8(pointer_kind).ok_or(TruChainError::InvalidMirrorKind)?;
+    if !kind.validate(&uri) {
+        return err!(TruChainError::InvalidMirrorUri);
+    }
+
+    let mut uri_padded = [0u8; MAX_MIRROR_URI_LEN];
+    uri_padded[..uri.len()].copy_from_slice(uri.as_bytes());
+
+    let video_key = ctx.accounts.video.key();
+    let mirrors = &mut ctx.accounts.mirrors;
+
+    // fresh account: link it to the video
+    if mirrors.video == Pubkey::default() {
+        mirrors.video = video_key;
+        mirrors.bump = ctx.bumps.mirrors;
+    }
+
+    if mirrors.mirrors.iter().any(|m| m.uri == uri_padded) {
+        return err!(TruChainError::DuplicateMirror);
+    }
+    if mirrors.mirrors.len() >= MAX_MIRRORS {
+        return err!(TruChainError::TooManyMirrors);
+    }
+
+    mirrors.mirrors.push(Mirror {
+        kind: pointer_kind,
+        uri: uri_padded,
+    });
+
+    emit!(MirrorAdded {
+        video: video_key,
+        index: (mirrors.mirrors.len() - 1) as u8,
+        kind: pointer_kind,
+        uri,
+    });
+
+    Ok(())
+}
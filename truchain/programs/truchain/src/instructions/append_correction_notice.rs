@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::CorrectionNoticeAppended;
+use crate::state::{
+    CorrectionNotice, CorrectionNotices, Official, Video, VideoStatus, CORRECTION_NOTICES_SIZE,
+    MAX_CORRECTION_NOTICES,
+};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+pub struct AppendCorrectionNotice<'info> {
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    // only a settled Authentic record can carry notices
+    #[account(
+        mut,
+        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = video.status == VideoStatus::Authentic @ TruChainError::VideoNotAuthentic,
+        constraint = !video.takedown @ TruChainError::VideoTakenDown
+    )]
+    pub video: Account<'info, Video>,
+
+    // created on the first notice, reused for the second
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CORRECTION_NOTICES_SIZE,
+        seeds = [b"notices", video.key().as_ref()],
+        bump
+    )]
+    pub notices: Account<'info, CorrectionNotices>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AppendCorrectionNotice>,
+    notice_hash: [u8; 32],
+    notice_cid: String,
+) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let cid_bytes = notice_cid.as_bytes();
+    if cid_bytes.is_empty() || cid_bytes.len() > 64 {
+        return err!(TruChainError::InvalidIpfsCid);
+    }
+
+    let video_key = ctx.accounts.video.key();
+    let notices = &mut ctx.accounts.notices;
+
+    // fresh account: link it to the video
+    if notices.video == Pubkey::default() {
+        notices.video = video_key;
+        notices.bump = ctx.bumps.notices;
+    }
+
+    if notices.notices.len() >= MAX_CORRECTION_NOTICES {
+        return err!(TruChainError::NoticesFull);
+    }
+
+    let mut cid_padded = [0u8; 64];
+    cid_padded[..cid_bytes.len()].copy_from_slice(cid_bytes);
+    notices.notices.push(CorrectionNotice {
+        notice_hash,
+        notice_cid: cid_padded,
+        appended_at: now,
+    });
+    let index = (notices.notices.len() - 1) as u8;
+
+    ctx.accounts.video.has_notice = true;
+
+    emit!(CorrectionNoticeAppended {
+        official: ctx.accounts.official.key(),
+        video: video_key,
+        index,
+        notice_hash,
+        notice_cid,
+        has_notice: true,
+    });
+
+    Ok(())
+}
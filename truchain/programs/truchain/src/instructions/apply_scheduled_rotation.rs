@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::EndorsersRotated;
+use crate::state::{Official, Video};
+use crate::utils::{current_time, is_clock_override};
+
+// Permissionless crank. remaining_accounts: writable Unverified videos of
+// this official whose votes from outgoing endorsers should be purged now.
+// Videos left out are purged lazily by their next endorse_video/change_vote.
+#[derive(Accounts)]
+pub struct ApplyScheduledRotation<'info> {
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ApplyScheduledRotation<'info>>,
+) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let official = &mut ctx.accounts.official;
+    let rotation = official
+        .scheduled_rotation
+        .take()
+        .ok_or(TruChainError::NoScheduledRotation)?;
+
+    if now < rotation.effective_at {
+        return err!(TruChainError::RotationNotDue);
+    }
+
+    // cosigners and liveness belong to the endorser in the slot, not the slot itself
+    for (slot, endorser) in rotation.new_endorsers.iter().enumerate() {
+        if official.endorsers[slot] != *endorser {
+            official.endorser_cosigners[slot] = None;
+            official.set_slot_inactive(slot, false);
+        }
+    }
+    let old_endorsers = std::mem::replace(&mut official.endorsers, rotation.new_endorsers);
+    official.endorser_rationale_hash = rotation.rationale_hash;
+    official.endorser_rationale_cid = rotation.rationale_cid;
+
+    // approvals from the outgoing endorsers no longer count, and would
+    // otherwise leave no room in a Vec sized for one panel
+    let panel = official.endorsers.clone();
+    if let Some(proposal) = official.pending_threshold.as_mut() {
+        proposal.approvals.retain(|a| panel.contains(a));
+    }
+
+    let official_key = official.key();
+    let mut purged_votes: u32 = 0;
+    for info in ctx.remaining_accounts.iter().filter(|info| !is_clock_override(info)) {
+        let mut video: Account<Video> = Account::try_from(info)?;
+        if video.official != official_key {
+            return err!(TruChainError::UnauthorizedOfficial);
+        }
+        purged_votes += video.purge_stale_votes(official) as u32;
+        video.exit(ctx.program_id)?;
+    }
+
+    emit!(EndorsersRotated {
+        official: official_key,
+        old_endorsers,
+        new_endorsers: official.endorsers.clone(),
+        purged_votes,
+    });
+
+    Ok(())
+}
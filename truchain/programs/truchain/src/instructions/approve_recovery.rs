@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Official, RecoveryProposal};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    #[account(
+        constraint = official.endorser_slot(&endorser.key()).is_some() @ TruChainError::UnauthorizedEndorser
+    )]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        has_one = official,
+        seeds = [b"recovery", official.key().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, RecoveryProposal>,
+
+    pub endorser: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveRecovery>) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.is_expired(now) {
+        return err!(TruChainError::RecoveryExpired);
+    }
+
+    // approvals from endorsers rotated out since don't count or take room
+    let panel = &ctx.accounts.official.endorsers;
+    proposal.approvals.retain(|a| panel.contains(a));
+
+    let endorser = ctx.accounts.endorser.key();
+    if proposal.approvals.contains(&endorser) {
+        return err!(TruChainError::RecoveryAlreadyApproved);
+    }
+    proposal.approvals.push(endorser);
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::ThresholdChanged;
+use crate::state::Official;
+use crate::utils::current_time;
+
+// The approval that reaches a majority of the panel applies the change.
+// Videos keep the threshold they were registered with.
+#[derive(Accounts)]
+pub struct ApproveThresholdChange<'info> {
+    #[account(
+        mut,
+        constraint = official.endorser_slot(&endorser.key()).is_some() @ TruChainError::UnauthorizedEndorser
+    )]
+    pub official: Account<'info, Official>,
+
+    pub endorser: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveThresholdChange>) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let official = &mut ctx.accounts.official;
+    let majority = official.panel_majority();
+    let panel = official.endorsers.clone();
+    let proposal = official
+        .pending_threshold
+        .as_mut()
+        .ok_or(TruChainError::NoThresholdChange)?;
+    if now > proposal.expires_at {
+        return err!(TruChainError::ThresholdChangeExpired);
+    }
+
+    // the rotation crank prunes these too; repeated here so a stale entry
+    // can never count or take the room a current endorser needs
+    proposal.approvals.retain(|a| panel.contains(a));
+
+    let endorser = ctx.accounts.endorser.key();
+    if proposal.approvals.contains(&endorser) {
+        return err!(TruChainError::ThresholdAlreadyApproved);
+    }
+    proposal.approvals.push(endorser);
+
+    if proposal.approvals.len() < majority {
+        return Ok(());
+    }
+
+    let proposal = official
+        .pending_threshold
+        .take()
+        .ok_or(TruChainError::NoThresholdChange)?;
+    let old_threshold = std::mem::replace(&mut official.threshold, proposal.new_threshold);
+
+    emit!(ThresholdChanged {
+        official: official.key(),
+        old_threshold,
+        new_threshold: official.threshold,
+        approvers: proposal.approvals,
+    });
+
+    Ok(())
+}
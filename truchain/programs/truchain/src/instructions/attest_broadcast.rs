@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::BroadcastAttested;
+use crate::state::{
+    BroadcastAttestation, BroadcastAttestations, BroadcastAttestor, Video, VideoStatus,
+    BROADCAST_ATTESTATIONS_SIZE, MAX_BROADCAST_ATTESTATIONS,
+};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+pub struct AttestBroadcast<'info> {
+    // registry gate: only accredited attestors have this PDA
+    #[account(
+        seeds = [b"broadcast_attestor", attestor.key().as_ref()],
+        bump = broadcast_attestor.bump
+    )]
+    pub broadcast_attestor: Account<'info, BroadcastAttestor>,
+
+    #[account(
+        constraint = !video.takedown @ TruChainError::VideoTakenDown,
+        constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked
+    )]
+    pub video: Account<'info, Video>,
+
+    // created on the first attestation, reused for the second
+    #[account(
+        init_if_needed,
+        payer = attestor,
+        space = BROADCAST_ATTESTATIONS_SIZE,
+        seeds = [b"broadcast", video.key().as_ref()],
+        bump
+    )]
+    pub broadcast_attestations: Account<'info, BroadcastAttestations>,
+
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AttestBroadcast>, broadcast_time: i64, channel_hash: [u8; 32]) -> Result<()> {
+    let now = current_time(&ctx)?;
+    if broadcast_time > now {
+        return err!(TruChainError::InvalidBroadcastTime);
+    }
+
+    let video_key = ctx.accounts.video.key();
+    let attestor_key = ctx.accounts.attestor.key();
+    let attestations = &mut ctx.accounts.broadcast_attestations;
+
+    // fresh account: link it to the video
+    if attestations.video == Pubkey::default() {
+        attestations.video = video_key;
+        attestations.bump = ctx.bumps.broadcast_attestations;
+    }
+
+    if attestations.entries.iter().any(|e| e.attestor == attestor_key) {
+        return err!(TruChainError::BroadcastAlreadyAttested);
+    }
+    if attestations.entries.len() >= MAX_BROADCAST_ATTESTATIONS {
+        return err!(TruChainError::TooManyBroadcastAttestations);
+    }
+
+    attestations.entries.push(BroadcastAttestation {
+        attestor: attestor_key,
+        broadcast_time,
+        channel_hash,
+        attested_at: now,
+    });
+
+    emit!(BroadcastAttested {
+        video: video_key,
+        attestor: attestor_key,
+        broadcast_time,
+        channel_hash,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::DomainAttested;
+use crate::state::{domain_hash, pad_domain, validate_domain, Config, DomainIndex, Official, DOMAIN_INDEX_SIZE};
+use crate::utils::current_time;
+
+// Admin records that it checked the domain's DNS TXT record for the
+// official's pubkey. Re-attesting the same domain refreshes the timestamp.
+#[derive(Accounts)]
+#[instruction(domain: String)]
+pub struct AttestDomain<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = DOMAIN_INDEX_SIZE,
+        seeds = [b"domain", &domain_hash(&domain)[..]],
+        bump
+    )]
+    pub domain_index: Account<'info, DomainIndex>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AttestDomain>, domain: String) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    if !validate_domain(&domain) {
+        return err!(TruChainError::InvalidDomain);
+    }
+
+    let official_key = ctx.accounts.official.key();
+    let index = &mut ctx.accounts.domain_index;
+    if index.official != Pubkey::default() && index.official != official_key {
+        return err!(TruChainError::DomainAlreadyAttested);
+    }
+
+    // one domain per official; the old one has to be revoked first
+    let padded = pad_domain(&domain);
+    let official = &mut ctx.accounts.official;
+    if official.has_domain() && official.domain != padded {
+        return err!(TruChainError::OfficialHasDomain);
+    }
+
+    index.domain_hash = domain_hash(&domain);
+    index.official = official_key;
+    index.bump = ctx.bumps.domain_index;
+
+    official.domain = padded;
+    official.domain_attested_at = now;
+    official.domain_attested_by = ctx.accounts.admin.key();
+
+    emit!(DomainAttested {
+        official: official_key,
+        domain,
+        attested_by: official.domain_attested_by,
+        attested_at: now,
+    });
+
+    Ok(())
+}
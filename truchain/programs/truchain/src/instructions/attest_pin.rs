@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::PinAttested;
+use crate::state::{PinReceipt, Provider, Video, VideoStatus, PIN_RECEIPT_SIZE};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+pub struct AttestPin<'info> {
+    #[account(
+        mut,
+        seeds = [b"provider", provider_key.key().as_ref()],
+        bump = provider.bump,
+        has_one = provider_key @ TruChainError::UnauthorizedProvider,
+        constraint = !provider.revoked @ TruChainError::ProviderRevoked
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        constraint = !video.takedown @ TruChainError::VideoTakenDown,
+        constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked
+    )]
+    pub video: Account<'info, Video>,
+
+    // one live receipt per (video, provider); re-attest after it's collected
+    #[account(
+        init,
+        payer = provider_key,
+        space = PIN_RECEIPT_SIZE,
+        seeds = [b"pin", video.key().as_ref(), provider.key().as_ref()],
+        bump
+    )]
+    pub pin_receipt: Account<'info, PinReceipt>,
+
+    #[account(mut)]
+    pub provider_key: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AttestPin>, expires_at: i64) -> Result<()> {
+    let now = current_time(&ctx)?;
+    if expires_at <= now {
+        return err!(TruChainError::InvalidPinExpiry);
+    }
+
+    let provider = &mut ctx.accounts.provider;
+    provider.active_receipts = provider
+        .active_receipts
+        .checked_add(1)
+        .ok_or(TruChainError::CounterOverflow)?;
+
+    let receipt = &mut ctx.accounts.pin_receipt;
+    receipt.provider = provider.key();
+    receipt.video = ctx.accounts.video.key();
+    receipt.attested_at = now;
+    receipt.expires_at = expires_at;
+    receipt.bump = ctx.bumps.pin_receipt;
+
+    emit!(PinAttested {
+        provider: provider.key(),
+        video: receipt.video,
+        expires_at,
+        active_receipts: provider.active_receipts,
+    });
+
+    Ok(())
+}
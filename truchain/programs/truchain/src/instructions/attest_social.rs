@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::SocialAttested;
+use crate::state::{
+    pad_social_handle, Config, Official, SocialAttestation, SocialIndex, SocialPlatform,
+    SOCIAL_INDEX_SIZE,
+};
+use crate::utils::current_time;
+
+// Admin records a verified social account for an official. A second
+// attestation for the same platform replaces the first; the replaced
+// handle's index must be passed in so it can be released.
+#[derive(Accounts)]
+#[instruction(platform: u8, handle: String)]
+pub struct AttestSocial<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = SOCIAL_INDEX_SIZE,
+        seeds = [b"social", &[platform][..], handle.as_bytes()],
+        bump
+    )]
+    pub social_index: Account<'info, SocialIndex>,
+
+    // index of the handle being replaced on this platform, if any
+    #[account(mut)]
+    pub previous_social_index: Option<Account<'info, SocialIndex>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AttestSocial>, platform: u8, handle: String) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let kind = SocialPlatform::from_u8(platform).ok_or(TruChainError::InvalidSocialHandle)?;
+    if !kind.validate_handle(&handle) {
+        return err!(TruChainError::InvalidSocialHandle);
+    }
+
+    let official_key = ctx.accounts.official.key();
+    let index = &mut ctx.accounts.social_index;
+    if index.official != Pubkey::default() && index.official != official_key {
+        return err!(TruChainError::SocialHandleTaken);
+    }
+    index.platform = platform;
+    index.handle = pad_social_handle(&handle);
+    index.official = official_key;
+    index.bump = ctx.bumps.social_index;
+
+    let padded = pad_social_handle(&handle);
+    let official = &mut ctx.accounts.official;
+    let mut replaced_handle = None;
+
+    if let Some(slot) = official.social_slot(platform) {
+        let old = official.social_attestations[slot].handle;
+        if old != padded {
+            let previous = ctx
+                .accounts
+                .previous_social_index
+                .as_ref()
+                .ok_or(TruChainError::SocialIndexMismatch)?;
+            if previous.official != official_key || previous.platform != platform || previous.handle != old {
+                return err!(TruChainError::SocialIndexMismatch);
+            }
+            replaced_handle = Some(String::from_utf8_lossy(previous.handle_bytes()).into_owned());
+            previous.close(ctx.accounts.admin.to_account_info())?;
+        }
+        official.social_attestations.remove(slot);
+    }
+
+    official.social_attestations.push(SocialAttestation {
+        platform,
+        handle: padded,
+        attested_at: now,
+    });
+
+    emit!(SocialAttested {
+        official: official_key,
+        platform,
+        handle,
+        replaced_handle,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{
+    HashAuthorization, Official, HASH_AUTHORIZATION_SIZE, MAX_AUTHORIZATIONS_PER_TX,
+};
+use crate::utils::{create_pda_account, is_initialized, write_account};
+
+// remaining_accounts: one writable HashAuthorization PDA per hash, same order,
+// derived from [b"hash_auth", official, video_hash].
+#[derive(Accounts)]
+pub struct AuthorizeHashes<'info> {
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AuthorizeHashes<'info>>,
+    hashes: Vec<[u8; 32]>,
+) -> Result<()> {
+    if hashes.is_empty() || hashes.len() > MAX_AUTHORIZATIONS_PER_TX {
+        return err!(TruChainError::InvalidAuthorizationBatch);
+    }
+    if ctx.remaining_accounts.len() != hashes.len() {
+        return err!(TruChainError::InvalidAuthorizationBatch);
+    }
+
+    let official_key = ctx.accounts.official.key();
+    let authority_key = ctx.accounts.authority.key();
+
+    for (video_hash, target) in hashes.iter().zip(ctx.remaining_accounts.iter()) {
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"hash_auth", official_key.as_ref(), video_hash],
+            ctx.program_id,
+        );
+        if target.key() != expected {
+            return err!(TruChainError::InvalidAuthorizationBatch);
+        }
+        if is_initialized(target) {
+            return err!(TruChainError::HashAlreadyAuthorized);
+        }
+
+        let seeds: &[&[u8]] = &[b"hash_auth", official_key.as_ref(), video_hash, &[bump]];
+        create_pda_account(
+            &ctx.accounts.authority,
+            target,
+            &ctx.accounts.system_program,
+            HASH_AUTHORIZATION_SIZE,
+            seeds,
+        )?;
+
+        let authorization = HashAuthorization {
+            official: official_key,
+            video_hash: *video_hash,
+            authority: authority_key,
+            bump,
+        };
+        write_account(target, &authorization)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+use crate::errors::TruChainError;
+use crate::events::ExternalKeyBound;
+use crate::state::{ExternalKeyKind, Official};
+use crate::utils::verify_preceding_ed25519;
+
+#[derive(Accounts)]
+pub struct BindExternalKey<'info> {
+    #[account(mut, has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    pub authority: Signer<'info>,
+
+    // only needed for the proof path
+    /// CHECK: address-checked against the instructions sysvar
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(
+    ctx: Context<BindExternalKey>,
+    key_kind: u8,
+    fingerprint: [u8; 32],
+    with_proof: bool,
+) -> Result<()> {
+    let kind = ExternalKeyKind::from_u8(key_kind).ok_or(TruChainError::InvalidExternalKey)?;
+    if fingerprint == [0u8; 32] {
+        return err!(TruChainError::InvalidExternalKey);
+    }
+
+    let official_key = ctx.accounts.official.key();
+
+    // proof: the external key signed this Official's pubkey in an ed25519
+    // instruction placed right before this one
+    if with_proof {
+        if !kind.supports_onchain_proof() {
+            return err!(TruChainError::ExternalKeyProofUnsupported);
+        }
+        let sysvar = ctx
+            .accounts
+            .instructions
+            .as_ref()
+            .ok_or(TruChainError::InvalidExternalKeyProof)?;
+        if !verify_preceding_ed25519(sysvar, &fingerprint, official_key.as_ref())? {
+            return err!(TruChainError::InvalidExternalKeyProof);
+        }
+    }
+
+    let official = &mut ctx.accounts.official;
+    let old_fingerprint = official.external_key_fingerprint;
+    official.external_key_fingerprint = fingerprint;
+    official.external_key_kind = key_kind;
+
+    emit!(ExternalKeyBound {
+        official: official_key,
+        key_kind,
+        old_fingerprint,
+        new_fingerprint: fingerprint,
+        proof_verified: with_proof,
+    });
+
+    Ok(())
+}
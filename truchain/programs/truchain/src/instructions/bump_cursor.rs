@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::IndexerCursor;
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+pub struct BumpCursor<'info> {
+    #[account(
+        mut,
+        seeds = [b"cursor", cursor.label_hash.as_ref()],
+        bump = cursor.bump,
+        has_one = owner @ TruChainError::UnauthorizedCursorOwner
+    )]
+    pub cursor: Account<'info, IndexerCursor>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<BumpCursor>, new_sequence: u64) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let cursor = &mut ctx.accounts.cursor;
+
+    // replicas may race; only strictly forward moves are accepted
+    if new_sequence <= cursor.last_sequence {
+        return err!(TruChainError::NonMonotonicCursor);
+    }
+
+    cursor.last_sequence = new_sequence;
+    cursor.updated_at = now;
+
+    Ok(())
+}
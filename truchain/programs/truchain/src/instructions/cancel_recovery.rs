@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Official, RecoveryProposal};
+
+// The current authority's veto: proof it still holds the key.
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        has_one = official,
+        has_one = rent_payer @ TruChainError::RentPayerMismatch,
+        close = rent_payer,
+        seeds = [b"recovery", official.key().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, RecoveryProposal>,
+
+    /// CHECK: rent refund target, pinned to proposal.rent_payer.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<CancelRecovery>) -> Result<()> {
+    Ok(())
+}
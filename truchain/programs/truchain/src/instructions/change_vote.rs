@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::VoteChanged;
+use crate::instructions::endorse_video::{apply_status_change, reopen_finalized};
+use crate::state::{Config, Official, StatusIndex, Video, VideoStatus, VoteReceipt};
+use crate::utils::current_time;
+
+// Flips an existing vote. Works after finalization too, so a mis-click can
+// move a video between Authentic and Disputed or back to Unverified; the
+// old verdict is then fully unwound (see reopen_finalized) before the new
+// one is recorded. Refused once the bond was settled under the old verdict.
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = !video.takedown @ TruChainError::VideoTakenDown,
+        constraint = !video.frozen @ TruChainError::VideoFrozen,
+        constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked
+    )]
+    pub video: Account<'info, Video>,
+
+    // the endorser's receipt, if one was issued; rewritten to the new vote.
+    // The seeds don't include the official and hashes can repeat across
+    // officials, so pin it to this one.
+    #[account(
+        mut,
+        seeds = [b"receipt", video.video_hash.as_ref(), endorser.key().as_ref()],
+        bump = receipt.bump,
+        constraint = receipt.official == official.key() @ TruChainError::ReceiptVideoMismatch
+    )]
+    pub receipt: Option<Account<'info, VoteReceipt>>,
+
+    pub endorser: Signer<'info>,
+
+    // required when the endorser's slot has a cosigner configured
+    pub cosigner: Option<Signer<'info>>,
+
+    // same as endorse_video: required if this change finalizes the video
+    #[account(mut)]
+    pub status_index: Option<Account<'info, StatusIndex>>,
+
+    // page currently listing the video (video.status_index_page) when this
+    // change takes a finalized video off its verdict
+    #[account(mut)]
+    pub old_status_index: Option<Account<'info, StatusIndex>>,
+}
+
+pub fn handler(ctx: Context<ChangeVote>, is_authentic: bool) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let config = &ctx.accounts.config;
+    let official = &mut ctx.accounts.official;
+    let video = &mut ctx.accounts.video;
+    let endorser_key = ctx.accounts.endorser.key();
+
+    // same membership rule as endorse_video; a rotated-out endorser can't
+    // touch a vote that a finalized video keeps on record
+    let is_standby = official.has_standby()
+        && endorser_key == official.standby_endorser
+        && video.standby_active;
+    if official.endorser_slot(&endorser_key).is_none() && !is_standby {
+        return err!(TruChainError::UnauthorizedEndorser);
+    }
+    video.purge_stale_votes(official);
+
+    let vote = video
+        .votes
+        .iter_mut()
+        .find(|v| v.endorser == endorser_key)
+        .ok_or(TruChainError::VoteNotFound)?;
+    if vote.is_authentic == is_authentic {
+        return err!(TruChainError::VoteUnchanged);
+    }
+
+    if let Some(slot) = official.endorser_slot(&endorser_key) {
+        official.set_slot_inactive(slot, false);
+
+        if let Some(required) = official.endorser_cosigners[slot] {
+            match &ctx.accounts.cosigner {
+                Some(cosigner) if cosigner.key() == required => {}
+                _ => return err!(TruChainError::MissingCosigner),
+            }
+        }
+    }
+
+    vote.is_authentic = is_authentic;
+
+    emit!(VoteChanged {
+        video: video.key(),
+        endorser: endorser_key,
+        is_authentic,
+    });
+
+    let old_status = video.status;
+    let was_finalized = video.is_finalized();
+    video.recompute_status();
+
+    if was_finalized && video.status != old_status {
+        reopen_finalized(
+            config,
+            official,
+            video,
+            ctx.accounts.old_status_index.as_mut(),
+            old_status,
+        )?;
+    }
+
+    apply_status_change(
+        config,
+        official,
+        video,
+        ctx.accounts.status_index.as_mut(),
+        old_status,
+        now,
+    )?;
+
+    if let Some(receipt) = ctx.accounts.receipt.as_mut() {
+        receipt.is_authentic = is_authentic;
+        receipt.timestamp = now;
+        receipt.final_status = video.is_finalized().then_some(video.status);
+    }
+
+    Ok(())
+}
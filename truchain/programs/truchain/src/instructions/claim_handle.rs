@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::HandleClaimed;
+use crate::state::{pad_handle, validate_handle, Config, Handle, Official, HANDLE_SIZE};
+
+#[derive(Accounts)]
+#[instruction(handle: String)]
+pub struct ClaimHandle<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    // init fails if the handle is already taken
+    #[account(
+        init,
+        payer = admin,
+        space = HANDLE_SIZE,
+        seeds = [b"handle", handle.as_bytes()],
+        bump
+    )]
+    pub handle_account: Account<'info, Handle>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimHandle>, handle: String) -> Result<()> {
+    if !validate_handle(&handle) {
+        return err!(TruChainError::InvalidHandle);
+    }
+
+    let official = &mut ctx.accounts.official;
+    if official.has_handle() {
+        return err!(TruChainError::OfficialHasHandle);
+    }
+
+    let padded = pad_handle(&handle);
+    official.handle = padded;
+
+    let handle_account = &mut ctx.accounts.handle_account;
+    handle_account.handle = padded;
+    handle_account.official = official.key();
+    handle_account.bump = ctx.bumps.handle_account;
+
+    emit!(HandleClaimed {
+        handle,
+        official: official.key(),
+    });
+
+    Ok(())
+}
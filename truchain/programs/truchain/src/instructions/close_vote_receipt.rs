@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Config, VoteReceipt};
+
+#[derive(Accounts)]
+pub struct CloseVoteReceipt<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    // the endorser paid for the receipt, so the rent always goes back to them
+    #[account(
+        mut,
+        close = endorser,
+        has_one = endorser @ TruChainError::UnauthorizedEndorser,
+        seeds = [b"receipt", receipt.video_hash.as_ref(), receipt.endorser.as_ref()],
+        bump = receipt.bump
+    )]
+    pub receipt: Account<'info, VoteReceipt>,
+
+    /// CHECK: rent refund target only, pinned to the recorded endorser above.
+    #[account(mut)]
+    pub endorser: UncheckedAccount<'info>,
+
+    // the endorser themselves, the admin, or the config's close authority
+    #[account(
+        constraint = config.can_close(&closer.key(), &receipt.endorser) @ TruChainError::UnauthorizedCloser
+    )]
+    pub closer: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<CloseVoteReceipt>) -> Result<()> {
+    Ok(())
+}
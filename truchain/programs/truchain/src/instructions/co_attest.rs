@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::CoAttested;
+use crate::state::{CoAttestation, Official, Video, VideoStatus, CO_ATTESTATION_SIZE};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+pub struct CoAttest<'info> {
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub attesting_official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        constraint = video.official != attesting_official.key() @ TruChainError::SelfAttestation,
+        constraint = video.status == VideoStatus::Authentic @ TruChainError::VideoNotAuthentic,
+        constraint = !video.takedown @ TruChainError::VideoTakenDown
+    )]
+    pub video: Account<'info, Video>,
+
+    // one attestation per (video, attesting official)
+    #[account(
+        init,
+        payer = authority,
+        space = CO_ATTESTATION_SIZE,
+        seeds = [b"co_attest", video.key().as_ref(), attesting_official.key().as_ref()],
+        bump
+    )]
+    pub co_attestation: Account<'info, CoAttestation>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CoAttest>) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let video = &mut ctx.accounts.video;
+    video.co_attestation_count = video
+        .co_attestation_count
+        .checked_add(1)
+        .ok_or(TruChainError::CounterOverflow)?;
+
+    let attestation = &mut ctx.accounts.co_attestation;
+    attestation.video = video.key();
+    attestation.attesting_official = ctx.accounts.attesting_official.key();
+    attestation.authority = ctx.accounts.authority.key();
+    attestation.attested_at = now;
+    attestation.bump = ctx.bumps.co_attestation;
+
+    emit!(CoAttested {
+        video: video.key(),
+        attesting_official: attestation.attesting_official,
+        co_attestation_count: video.co_attestation_count,
+    });
+
+    Ok(())
+}
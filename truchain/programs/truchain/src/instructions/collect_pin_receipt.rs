@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{PinReceipt, Provider};
+use crate::utils::current_time;
+
+// Permissionless gc crank: closes a receipt that is no longer valid (expired,
+// or its provider was revoked), refunds the provider key that paid for it,
+// and decrements the provider's active count.
+#[derive(Accounts)]
+pub struct CollectPinReceipt<'info> {
+    #[account(
+        mut,
+        seeds = [b"provider", provider.provider_key.as_ref()],
+        bump = provider.bump
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(
+        mut,
+        close = provider_key,
+        seeds = [b"pin", pin_receipt.video.as_ref(), provider.key().as_ref()],
+        bump = pin_receipt.bump
+    )]
+    pub pin_receipt: Account<'info, PinReceipt>,
+
+    /// CHECK: rent refund target only, pinned to the provider's key.
+    #[account(mut, address = provider.provider_key @ TruChainError::UnauthorizedProvider)]
+    pub provider_key: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CollectPinReceipt>) -> Result<()> {
+    let now = current_time(&ctx)?;
+    if ctx.accounts.pin_receipt.is_valid(&ctx.accounts.provider, now) {
+        return err!(TruChainError::PinReceiptNotExpired);
+    }
+
+    let provider = &mut ctx.accounts.provider;
+    provider.active_receipts = provider.active_receipts.saturating_sub(1);
+
+    Ok(())
+}
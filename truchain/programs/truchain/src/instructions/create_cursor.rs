@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{IndexerCursor, INDEXER_CURSOR_SIZE};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+#[instruction(label_hash: [u8; 32])]
+pub struct CreateCursor<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = INDEXER_CURSOR_SIZE,
+        seeds = [b"cursor", label_hash.as_ref()],
+        bump
+    )]
+    pub cursor: Account<'info, IndexerCursor>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateCursor>, label_hash: [u8; 32]) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let cursor = &mut ctx.accounts.cursor;
+    cursor.label_hash = label_hash;
+    cursor.owner = ctx.accounts.owner.key();
+    cursor.last_sequence = 0;
+    cursor.updated_at = now;
+    cursor.bump = ctx.bumps.cursor;
+
+    Ok(())
+}
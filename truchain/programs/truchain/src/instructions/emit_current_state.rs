@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::{OfficialSnapshot, VideoSnapshot};
+use crate::state::{Official, Video};
+
+// Re-emits the current state of an Official and/or Video as synthetic
+// *Snapshot events so a fresh indexer can backfill past log retention.
+// These never accompany a state change, so indexers can apply them as
+// "set" rather than "delta" and not double count.
+#[derive(Accounts)]
+pub struct EmitCurrentState<'info> {
+    pub official: Option<Account<'info, Official>>,
+
+    pub video: Option<Account<'info, Video>>,
+}
+
+pub fn handler(ctx: Context<EmitCurrentState>) -> Result<()> {
+    if ctx.accounts.official.is_none() && ctx.accounts.video.is_none() {
+        return err!(TruChainError::NothingToEmit);
+    }
+
+    let slot = Clock::get()?.slot;
+
+    if let Some(official) = &ctx.accounts.official {
+        emit!(OfficialSnapshot {
+            official: official.key(),
+            state: (**official).clone(),
+            slot,
+        });
+    }
+
+    if let Some(video) = &ctx.accounts.video {
+        emit!(VideoSnapshot {
+            video: video.key(),
+            state: (**video).clone(),
+            slot,
+        });
+    }
+
+    Ok(())
+}
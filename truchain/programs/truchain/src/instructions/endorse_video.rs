@@ -1,37 +1,104 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::TruChainError;
-use crate::state::{Official, Video, Vote, MAX_VOTES};
+use crate::events::{IntegrityScoreChanged, StatusChanged, VideoListed, VoteCast};
+use crate::state::{
+    BondState, Classification, Config, Official, StatusIndex, Video, VideoStatus, Vote,
+    VoteReceipt, VOTE_RECEIPT_SIZE,
+};
+use crate::utils::current_time;
 
 #[derive(Accounts)]
 pub struct EndorseVideo<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    // mut: finalization updates the official's integrity score
+    #[account(mut)]
     pub official: Account<'info, Official>,
 
     #[account(
         mut,
-        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial
+        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = !video.takedown @ TruChainError::VideoTakenDown,
+        constraint = !video.frozen @ TruChainError::VideoFrozen,
+        constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked
     )]
     pub video: Account<'info, Video>,
 
+    // only passed when issue_receipt is set; paid for by the endorser
+    #[account(
+        init,
+        payer = endorser,
+        space = VOTE_RECEIPT_SIZE,
+        seeds = [b"receipt", video.video_hash.as_ref(), endorser.key().as_ref()],
+        bump
+    )]
+    pub receipt: Option<Account<'info, VoteReceipt>>,
+
     #[account(mut)]
     pub endorser: Signer<'info>,
+
+    // required when the endorser's slot has a cosigner configured
+    pub cosigner: Option<Signer<'info>>,
+
+    // tail page of the bucket for the status this vote would finalize to;
+    // required if the vote finalizes the video, ignored otherwise
+    #[account(mut)]
+    pub status_index: Option<Account<'info, StatusIndex>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
     ctx: Context<EndorseVideo>,
     is_authentic: bool,
+    issue_receipt: bool,
+    classification: Option<u8>,
 ) -> Result<()> {
-    let official = &ctx.accounts.official;
+    let now = current_time(&ctx)?;
+
+    let config = &ctx.accounts.config;
+    let official = &mut ctx.accounts.official;
     let video = &mut ctx.accounts.video;
     let endorser = &ctx.accounts.endorser;
 
     let endorser_key = endorser.key();
 
-    // ensure signer is an approved endorser
-    if !official.endorsers.contains(&endorser_key) {
-        return err!(TruChainError::UnauthorizedEndorser);
+    // the receipt account must be passed iff a receipt was requested
+    if issue_receipt != ctx.accounts.receipt.is_some() {
+        return err!(TruChainError::VoteReceiptMismatch);
+    }
+
+    if classification.is_some_and(|c| !Classification::is_valid(c)) {
+        return err!(TruChainError::InvalidClassification);
+    }
+
+    // ensure signer is an approved endorser, or the activated standby
+    match official.endorser_slot(&endorser_key) {
+        Some(slot) => {
+            // voting is proof of life
+            official.set_slot_inactive(slot, false);
+
+            // paired slots need the cosigner's signature too
+            if let Some(required) = official.endorser_cosigners[slot] {
+                match &ctx.accounts.cosigner {
+                    Some(cosigner) if cosigner.key() == required => {}
+                    _ => return err!(TruChainError::MissingCosigner),
+                }
+            }
+        }
+        None => {
+            let is_standby = official.has_standby() && endorser_key == official.standby_endorser;
+            if !(is_standby && video.standby_active) {
+                return err!(TruChainError::UnauthorizedEndorser);
+            }
+        }
     }
 
+    // votes left behind by a rotation the crank wasn't handed this video for
+    video.purge_stale_votes(official);
+
     // ensure they haven't already voted
     if video
         .votes
@@ -41,8 +108,8 @@ pub fn handler(
         return err!(TruChainError::AlreadyVoted);
     }
 
-    // ensure we don't exceed pre-allocated space
-    if video.votes.len() >= MAX_VOTES {
+    // ensure we don't exceed the space allocated at registration
+    if video.votes.len() >= video.max_votes_at_registration as usize {
         return err!(TruChainError::TooManyVotes);
     }
 
@@ -50,10 +117,171 @@ pub fn handler(
     video.votes.push(Vote {
         endorser: endorser_key,
         is_authentic,
+        classification,
+    });
+
+    emit!(VoteCast {
+        video: video.key(),
+        endorser: endorser_key,
+        is_authentic,
     });
 
-    // recompute status based on votes (2-of-3)
+    // recompute status based on votes and the registration-time threshold
+    let old_status = video.status;
     video.recompute_status();
 
+    apply_status_change(
+        config,
+        official,
+        video,
+        ctx.accounts.status_index.as_mut(),
+        old_status,
+        now,
+    )?;
+
+    if let Some(receipt) = ctx.accounts.receipt.as_mut() {
+        receipt.endorser = endorser_key;
+        receipt.official = official.key();
+        receipt.video_hash = video.video_hash;
+        receipt.is_authentic = is_authentic;
+        receipt.timestamp = now;
+        // the deciding vote can fill its own slot right away;
+        // earlier receipts are settled via finalize_vote_receipt
+        receipt.final_status = video.is_finalized().then_some(video.status);
+        receipt.bump = ctx.bumps.receipt.unwrap_or_default();
+    }
+
+    Ok(())
+}
+
+// Runs the first-finalization bookkeeping and announces any status change
+// after a vote was cast or changed. Shared by endorse_video and change_vote.
+pub fn apply_status_change(
+    config: &Config,
+    official: &mut Account<Official>,
+    video: &mut Account<Video>,
+    status_index: Option<&mut Account<StatusIndex>>,
+    old_status: VideoStatus,
+    now: i64,
+) -> Result<()> {
+    // the video reaches a verdict; finalized_at is only set again after
+    // reopen_finalized has unwound an earlier one
+    if video.finalized_at == 0 && video.is_finalized() {
+        video.finalized_at = now;
+
+        // every verdict lands in its bucket, so clients can't skip the index
+        let index = status_index.ok_or(TruChainError::StatusIndexRequired)?;
+        if index.official != official.key() || index.status != video.status {
+            return err!(TruChainError::StatusIndexMismatch);
+        }
+        // a full page means the caller should pass (or open) the next one
+        if index.is_full() {
+            return err!(TruChainError::StatusIndexFull);
+        }
+        index.videos.push(video.key());
+        video.status_index_page = Some(index.page);
+
+        // held-back CIDs go public with the verdict, unless taken down meanwhile
+        if !video.listed && !video.takedown {
+            video.listed = true;
+            emit!(VideoListed {
+                official: official.key(),
+                video: video.key(),
+                ipfs_cid: video.cid_str().to_string(),
+            });
+        }
+
+        let old_score = official.record_finalization(
+            video.status == VideoStatus::Disputed,
+            config.integrity_dispute_weight_bps,
+        )?;
+        official.record_finalize_latency(now.saturating_sub(video.timestamp).max(0) as u64)?;
+        if old_score.abs_diff(official.integrity_score) > config.integrity_alert_delta {
+            emit!(IntegrityScoreChanged {
+                official: official.key(),
+                old_score,
+                new_score: official.integrity_score,
+            });
+        }
+    }
+
+    if video.status != old_status {
+        emit!(StatusChanged {
+            video: video.key(),
+            old: old_status,
+            new: video.status,
+            classification: video.classification,
+            min_finalize_secs: official.min_finalize_secs,
+            max_finalize_secs: official.max_finalize_secs,
+            mean_finalize_millis: official.mean_finalize_millis,
+        });
+    }
+
+    Ok(())
+}
+
+// A changed vote moved a finalized video off its verdict. Unwinds what the
+// verdict recorded (integrity and latency stats, status bucket,
+// finalized_at) so apply_status_change can record the new outcome from
+// scratch. An announced CID stays listed.
+pub fn reopen_finalized(
+    config: &Config,
+    official: &mut Account<Official>,
+    video: &mut Account<Video>,
+    old_status_index: Option<&mut Account<StatusIndex>>,
+    old_status: VideoStatus,
+) -> Result<()> {
+    // a bond already paid out under the old verdict (only possible once
+    // config.bond_settle_delay_secs has passed) can't follow the new one
+    if matches!(video.bond_state, BondState::Reclaimed | BondState::Slashed) {
+        return err!(TruChainError::BondAlreadySettled);
+    }
+
+    remove_from_status_index(&official.key(), video, old_status_index, old_status)?;
+
+    let latency = video.finalized_at.saturating_sub(video.timestamp).max(0) as u64;
+    let old_score = official.revert_finalization(
+        old_status == VideoStatus::Disputed,
+        config.integrity_dispute_weight_bps,
+        latency,
+    )?;
+    video.finalized_at = 0;
+
+    if old_score.abs_diff(official.integrity_score) > config.integrity_alert_delta {
+        emit!(IntegrityScoreChanged {
+            official: official.key(),
+            old_score,
+            new_score: official.integrity_score,
+        });
+    }
+
+    Ok(())
+}
+
+// Swap-removes a video from the StatusIndex page it was pushed to while it
+// had `listed_status`. Videos finalized before pages were required have
+// none and are left alone.
+pub fn remove_from_status_index(
+    official: &Pubkey,
+    video: &mut Account<Video>,
+    status_index: Option<&mut Account<StatusIndex>>,
+    listed_status: VideoStatus,
+) -> Result<()> {
+    let Some(page) = video.status_index_page else {
+        return Ok(());
+    };
+    let index = status_index.ok_or(TruChainError::StatusIndexRequired)?;
+    if index.official != *official || index.status != listed_status || index.page != page {
+        return err!(TruChainError::StatusIndexMismatch);
+    }
+
+    let position = index
+        .videos
+        .iter()
+        .position(|key| *key == video.key())
+        .ok_or(TruChainError::StatusIndexMismatch)?;
+    index.videos.swap_remove(position);
+    video.status_index_page = None;
+
     Ok(())
 }
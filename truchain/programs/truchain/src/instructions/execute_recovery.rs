@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::AuthorityRecovered;
+use crate::state::{Official, RecoveryProposal};
+use crate::utils::current_time;
+
+// Permissionless once the quorum is in.
+#[derive(Accounts)]
+pub struct ExecuteRecovery<'info> {
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        has_one = official,
+        has_one = rent_payer @ TruChainError::RentPayerMismatch,
+        close = rent_payer,
+        seeds = [b"recovery", official.key().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, RecoveryProposal>,
+
+    /// CHECK: rent refund target, pinned to proposal.rent_payer.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteRecovery>) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let official = &mut ctx.accounts.official;
+    let proposal = &ctx.accounts.proposal;
+    if proposal.is_expired(now) {
+        return err!(TruChainError::RecoveryExpired);
+    }
+
+    // the panel may have rotated since the approvals were collected
+    let approvers: Vec<Pubkey> = proposal
+        .approvals
+        .iter()
+        .filter(|a| official.endorser_slot(a).is_some())
+        .copied()
+        .collect();
+    if approvers.len() < official.panel_majority() {
+        return err!(TruChainError::RecoveryQuorumNotMet);
+    }
+    if official.endorser_slot(&proposal.new_authority).is_some() {
+        return err!(TruChainError::RecoveryTargetIsEndorser);
+    }
+
+    let old_authority = std::mem::replace(&mut official.authority, proposal.new_authority);
+
+    emit!(AuthorityRecovered {
+        official: official.key(),
+        old_authority,
+        new_authority: official.authority,
+        approvers,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::TruChainError;
+use crate::events::CounterClaimFiled;
+use crate::state::{
+    CounterClaim, Video, VideoStatus, COUNTER_CLAIM_BOND_LAMPORTS, COUNTER_CLAIM_SIZE,
+};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+pub struct FileCounterClaim<'info> {
+    // a revoked record is terminal, so there is nothing left to freeze
+    #[account(constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked)]
+    pub video: Account<'info, Video>,
+
+    // init fails while the claimant still has an open claim on this video
+    #[account(
+        init,
+        payer = claimant,
+        space = COUNTER_CLAIM_SIZE,
+        seeds = [b"counter_claim", video.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub counter_claim: Account<'info, CounterClaim>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<FileCounterClaim>,
+    claimed_hash: [u8; 32],
+    evidence_cid: String,
+) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let cid_bytes = evidence_cid.as_bytes();
+    if cid_bytes.is_empty() || cid_bytes.len() > 64 {
+        return err!(TruChainError::InvalidIpfsCid);
+    }
+
+    let mut cid_padded = [0u8; 64];
+    cid_padded[..cid_bytes.len()].copy_from_slice(cid_bytes);
+
+    // escrow the bond in the claim account on top of its rent
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.claimant.to_account_info(),
+                to: ctx.accounts.counter_claim.to_account_info(),
+            },
+        ),
+        COUNTER_CLAIM_BOND_LAMPORTS,
+    )?;
+
+    let video_key = ctx.accounts.video.key();
+    let claimant_key = ctx.accounts.claimant.key();
+    let claim = &mut ctx.accounts.counter_claim;
+
+    claim.video = video_key;
+    claim.claimant = claimant_key;
+    claim.claimed_hash = claimed_hash;
+    claim.evidence_cid = cid_padded;
+    claim.bond_lamports = COUNTER_CLAIM_BOND_LAMPORTS;
+    claim.filed_at = now;
+    claim.bump = ctx.bumps.counter_claim;
+
+    emit!(CounterClaimFiled {
+        video: video_key,
+        claimant: claimant_key,
+        claimed_hash,
+        bond_lamports: COUNTER_CLAIM_BOND_LAMPORTS,
+    });
+
+    Ok(())
+}
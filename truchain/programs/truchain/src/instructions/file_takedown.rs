@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::TakedownFiled;
+use crate::state::{Config, Video};
+
+// Admin-only: delists a video under a legal order. The hash, votes and
+// status stay untouched; clients honoring the flag stop rendering the CID.
+#[derive(Accounts)]
+pub struct FileTakedown<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub video: Account<'info, Video>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<FileTakedown>,
+    requester_hash: [u8; 32],
+    reason_code: u16,
+) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+
+    if video.takedown {
+        return err!(TruChainError::TakedownAlreadyActive);
+    }
+
+    video.takedown = true;
+    video.takedown_requester_hash = requester_hash;
+    video.takedown_reason_code = reason_code;
+
+    emit!(TakedownFiled {
+        video: video.key(),
+        requester_hash,
+        reason_code,
+    });
+
+    Ok(())
+}
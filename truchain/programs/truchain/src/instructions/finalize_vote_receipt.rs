@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Video, VoteReceipt};
+
+// Permissionless: copies a finalized video's status into a receipt
+// issued before the deciding vote was cast.
+#[derive(Accounts)]
+pub struct FinalizeVoteReceipt<'info> {
+    #[account(
+        constraint = video.official == receipt.official @ TruChainError::ReceiptVideoMismatch,
+        constraint = video.video_hash == receipt.video_hash @ TruChainError::ReceiptVideoMismatch
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        mut,
+        seeds = [b"receipt", receipt.video_hash.as_ref(), receipt.endorser.as_ref()],
+        bump = receipt.bump
+    )]
+    pub receipt: Account<'info, VoteReceipt>,
+}
+
+pub fn handler(ctx: Context<FinalizeVoteReceipt>) -> Result<()> {
+    let video = &ctx.accounts.video;
+
+    if !video.is_finalized() {
+        return err!(TruChainError::VideoNotFinalized);
+    }
+
+    ctx.accounts.receipt.final_status = Some(video.status);
+
+    Ok(())
+}
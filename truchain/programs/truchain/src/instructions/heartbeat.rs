@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{EndorserProfile, ENDORSER_PROFILE_SIZE};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    // created on the first heartbeat, paid for by the endorser
+    #[account(
+        init_if_needed,
+        payer = endorser,
+        space = ENDORSER_PROFILE_SIZE,
+        seeds = [b"endorser", endorser.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, EndorserProfile>,
+
+    #[account(mut)]
+    pub endorser: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Heartbeat>) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let profile = &mut ctx.accounts.profile;
+    profile.endorser = ctx.accounts.endorser.key();
+    profile.last_heartbeat_at = now;
+    profile.bump = ctx.bumps.profile;
+
+    Ok(())
+}
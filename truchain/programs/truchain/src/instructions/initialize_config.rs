@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::errors::TruChainError;
+use crate::state::{
+    Config, CONFIG_SIZE, DEFAULT_BOND_SETTLE_DELAY_SECS, DEFAULT_INTEGRITY_ALERT_DELTA,
+    DEFAULT_INTEGRITY_DISPUTE_WEIGHT_BPS, DEFAULT_LIVENESS_WINDOW_SECS,
+    DEFAULT_RECOVERY_WINDOW_SECS, DEFAULT_STANDBY_ACTIVATION_SECS, DEFAULT_THRESHOLD_PROPOSAL_SECS,
+};
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    // singleton: init fails if the config PDA already exists
+    #[account(
+        init,
+        payer = admin,
+        space = CONFIG_SIZE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // only the upgrade authority may claim admin, so nobody can front-run
+    // the first initialize after a deploy
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ TruChainError::UnauthorizedAdmin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeConfig>, max_votes: u8) -> Result<()> {
+    if !Config::validate_max_votes(max_votes) {
+        return err!(TruChainError::InvalidMaxVotes);
+    }
+
+    let config = &mut ctx.accounts.config;
+
+    config.admin = ctx.accounts.admin.key();
+    config.max_votes = max_votes;
+    config.registration_bond_lamports = 0;
+    config.integrity_dispute_weight_bps = DEFAULT_INTEGRITY_DISPUTE_WEIGHT_BPS;
+    config.integrity_alert_delta = DEFAULT_INTEGRITY_ALERT_DELTA;
+    config.snapshotter = Pubkey::default();
+    config.close_authority = Pubkey::default();
+    config.standby_activation_secs = DEFAULT_STANDBY_ACTIVATION_SECS;
+    config.liveness_window_secs = DEFAULT_LIVENESS_WINDOW_SECS;
+    config.require_endorser_rationale = false;
+    config.recovery_window_secs = DEFAULT_RECOVERY_WINDOW_SECS;
+    config.threshold_proposal_secs = DEFAULT_THRESHOLD_PROPOSAL_SECS;
+    config.bond_settle_delay_secs = DEFAULT_BOND_SETTLE_DELAY_SECS;
+    config.bump = ctx.bumps.config;
+
+    Ok(())
+}
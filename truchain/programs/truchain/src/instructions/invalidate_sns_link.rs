@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::SnsDomainUnlinked;
+use crate::state::Official;
+use crate::utils::sns_name_owner;
+
+// Permissionless: anyone can clear a link whose domain no longer belongs
+// to the official's authority (transferred, expired or deleted).
+#[derive(Accounts)]
+pub struct InvalidateSnsLink<'info> {
+    #[account(
+        mut,
+        constraint = official.sns_name_account != Pubkey::default() @ TruChainError::NoSnsLink
+    )]
+    pub official: Account<'info, Official>,
+
+    /// CHECK: parsed as an SNS name registry in the handler
+    #[account(address = official.sns_name_account @ TruChainError::InvalidSnsNameAccount)]
+    pub name_account_info: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<InvalidateSnsLink>) -> Result<()> {
+    let official = &mut ctx.accounts.official;
+    if sns_name_owner(&ctx.accounts.name_account_info) == Some(official.authority) {
+        return err!(TruChainError::SnsLinkStillValid);
+    }
+
+    let name_account = official.sns_name_account;
+    official.sns_name_account = Pubkey::default();
+
+    emit!(SnsDomainUnlinked {
+        official: official.key(),
+        name_account,
+        invalidated: true,
+    });
+
+    Ok(())
+}
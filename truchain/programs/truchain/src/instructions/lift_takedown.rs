@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::TakedownLifted;
+use crate::state::{Config, Video};
+
+#[derive(Accounts)]
+pub struct LiftTakedown<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub video: Account<'info, Video>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<LiftTakedown>) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+
+    if !video.takedown {
+        return err!(TruChainError::NoActiveTakedown);
+    }
+
+    // requester hash and reason code are kept as a record of the last order
+    video.takedown = false;
+
+    emit!(TakedownLifted {
+        video: video.key(),
+        reason_code: video.takedown_reason_code,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::VideosLinked;
+use crate::state::{Official, Video, VideoStatus, MAX_RELATED_VIDEOS};
+
+// Cross-links two videos of the same moment. Both owning authorities sign
+// the same transaction, so no pending-link state is needed.
+#[derive(Accounts)]
+#[instruction(other_video: Pubkey)]
+pub struct LinkSameEvent<'info> {
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = !video.takedown @ TruChainError::VideoTakenDown,
+        constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked
+    )]
+    pub video: Box<Account<'info, Video>>,
+
+    #[account(
+        constraint = other_official.authority == other_authority.key() @ TruChainError::UnauthorizedOfficial
+    )]
+    pub other_official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        constraint = linked_video.key() == other_video @ TruChainError::UnauthorizedOfficial,
+        constraint = linked_video.official == other_official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = !linked_video.takedown @ TruChainError::VideoTakenDown,
+        constraint = linked_video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked
+    )]
+    pub linked_video: Box<Account<'info, Video>>,
+
+    pub authority: Signer<'info>,
+
+    pub other_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<LinkSameEvent>, other_video: Pubkey) -> Result<()> {
+    let video_key = ctx.accounts.video.key();
+
+    if video_key == other_video {
+        return err!(TruChainError::CannotLinkSelf);
+    }
+
+    let video = &mut ctx.accounts.video;
+    let linked = &mut ctx.accounts.linked_video;
+
+    if video.related_videos.contains(&other_video) || linked.related_videos.contains(&video_key) {
+        return err!(TruChainError::AlreadyLinked);
+    }
+    if video.related_videos.len() >= MAX_RELATED_VIDEOS
+        || linked.related_videos.len() >= MAX_RELATED_VIDEOS
+    {
+        return err!(TruChainError::TooManyRelatedVideos);
+    }
+
+    // reciprocal entries
+    video.related_videos.push(other_video);
+    linked.related_videos.push(video_key);
+
+    emit!(VideosLinked {
+        video: video_key,
+        other_video,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::SnsDomainLinked;
+use crate::state::Official;
+use crate::utils::sns_name_owner;
+
+#[derive(Accounts)]
+#[instruction(name_account: Pubkey)]
+pub struct LinkSnsDomain<'info> {
+    #[account(mut, has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: parsed as an SNS name registry in the handler
+    #[account(constraint = name_account_info.key() == name_account @ TruChainError::InvalidSnsNameAccount)]
+    pub name_account_info: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<LinkSnsDomain>, name_account: Pubkey) -> Result<()> {
+    let owner = sns_name_owner(&ctx.accounts.name_account_info)
+        .ok_or(TruChainError::InvalidSnsNameAccount)?;
+    if owner != ctx.accounts.authority.key() {
+        return err!(TruChainError::SnsOwnerMismatch);
+    }
+
+    let official = &mut ctx.accounts.official;
+    official.sns_name_account = name_account;
+
+    emit!(SnsDomainLinked {
+        official: official.key(),
+        name_account,
+    });
+
+    Ok(())
+}
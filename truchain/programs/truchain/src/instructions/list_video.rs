@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::VideoListed;
+use crate::state::{Official, Video, VideoStatus};
+
+// Publishes an unlisted video's CID before the panel has finalized it.
+#[derive(Accounts)]
+pub struct ListVideo<'info> {
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = !video.takedown @ TruChainError::VideoTakenDown,
+        constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked,
+        constraint = !video.listed @ TruChainError::VideoAlreadyListed
+    )]
+    pub video: Account<'info, Video>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ListVideo>) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    video.listed = true;
+
+    emit!(VideoListed {
+        official: ctx.accounts.official.key(),
+        video: video.key(),
+        ipfs_cid: video.cid_str().to_string(),
+    });
+
+    Ok(())
+}
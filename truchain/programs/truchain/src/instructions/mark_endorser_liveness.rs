@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::{EndorserMarkedActive, EndorserMarkedInactive};
+use crate::state::{Config, EndorserProfile, Official};
+use crate::utils::{current_time, is_initialized};
+
+// Shared by mark_endorser_inactive / mark_endorser_active, both permissionless.
+// `profile` is the slot endorser's EndorserProfile PDA, which may not exist
+// yet if they have never sent a heartbeat.
+#[derive(Accounts)]
+pub struct MarkEndorserLiveness<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    /// CHECK: address is checked against the slot's endorser in the handler
+    pub profile: UncheckedAccount<'info>,
+}
+
+// Returns (slot endorser, last heartbeat or 0 if none, heartbeat is stale).
+fn slot_liveness(ctx: &Context<MarkEndorserLiveness>, slot_index: u8) -> Result<(Pubkey, i64, bool)> {
+    let official = &ctx.accounts.official;
+    let endorser = *official
+        .endorsers
+        .get(slot_index as usize)
+        .ok_or(TruChainError::InvalidEndorserSlot)?;
+
+    let (expected, _) =
+        Pubkey::find_program_address(&[b"endorser", endorser.as_ref()], ctx.program_id);
+    let profile = &ctx.accounts.profile;
+    if profile.key() != expected {
+        return err!(TruChainError::EndorserProfileMismatch);
+    }
+
+    let last_heartbeat_at = if is_initialized(profile) {
+        EndorserProfile::try_deserialize(&mut &profile.try_borrow_data()?[..])?.last_heartbeat_at
+    } else {
+        0
+    };
+
+    let now = current_time(ctx)?;
+    let stale = now.saturating_sub(last_heartbeat_at) > ctx.accounts.config.liveness_window_secs;
+    Ok((endorser, last_heartbeat_at, stale))
+}
+
+pub fn mark_inactive(ctx: Context<MarkEndorserLiveness>, slot_index: u8) -> Result<()> {
+    let (endorser, last_heartbeat_at, stale) = slot_liveness(&ctx, slot_index)?;
+    if !stale {
+        return err!(TruChainError::EndorserStillLive);
+    }
+
+    let official = &mut ctx.accounts.official;
+    official.set_slot_inactive(slot_index as usize, true);
+
+    emit!(EndorserMarkedInactive {
+        official: official.key(),
+        slot_index,
+        endorser,
+        last_heartbeat_at,
+    });
+
+    Ok(())
+}
+
+pub fn mark_active(ctx: Context<MarkEndorserLiveness>, slot_index: u8) -> Result<()> {
+    let (endorser, _, stale) = slot_liveness(&ctx, slot_index)?;
+    if stale {
+        return err!(TruChainError::EndorserStillStale);
+    }
+
+    let official = &mut ctx.accounts.official;
+    official.set_slot_inactive(slot_index as usize, false);
+
+    emit!(EndorserMarkedActive {
+        official: official.key(),
+        slot_index,
+        endorser,
+    });
+
+    Ok(())
+}
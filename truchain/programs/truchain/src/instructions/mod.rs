@@ -1,7 +1,156 @@
+// every instruction module exposes a `handler`; lib.rs calls them by path
+#![allow(ambiguous_glob_reexports)]
+
+pub mod initialize_config;
+pub mod update_max_votes;
+pub mod update_admin;
 pub mod register_official;
 pub mod register_video;
+pub mod authorize_hashes;
+pub mod register_authorized_video;
 pub mod endorse_video;
+pub mod change_vote;
+pub mod revoke_video;
+pub mod add_mirror;
+pub mod remove_mirror;
+pub mod file_takedown;
+pub mod lift_takedown;
+pub mod file_counter_claim;
+pub mod resolve_counter_claim;
+pub mod unfreeze_video;
+pub mod link_same_event;
+pub mod set_endorser_cosigner;
+pub mod claim_handle;
+pub mod reassign_handle;
+pub mod co_attest;
+pub mod revoke_co_attestation;
+pub mod set_registration_bond;
+pub mod reclaim_bond;
+pub mod slash_bond;
+pub mod set_integrity_params;
+pub mod require_verified;
+pub mod create_cursor;
+pub mod bump_cursor;
+pub mod bind_external_key;
+pub mod attest_domain;
+pub mod revoke_domain_attestation;
+pub mod attest_social;
+pub mod revoke_social;
+pub mod schedule_rotation;
+pub mod apply_scheduled_rotation;
+pub mod link_sns_domain;
+pub mod unlink_sns_domain;
+pub mod invalidate_sns_link;
+pub mod verify_bundle;
+pub mod set_snapshotter;
+pub mod publish_snapshot;
+pub mod set_close_authority;
+pub mod set_standby_endorser;
+pub mod set_standby_activation_secs;
+pub mod activate_standby;
+pub mod emit_current_state;
+pub mod register_provider;
+pub mod revoke_provider;
+pub mod attest_pin;
+pub mod collect_pin_receipt;
+pub mod heartbeat;
+pub mod set_liveness_window;
+pub mod mark_endorser_liveness;
+pub mod register_broadcast_attestor;
+pub mod remove_broadcast_attestor;
+pub mod attest_broadcast;
+pub mod set_clock_override;
+pub mod list_video;
+pub mod set_require_endorser_rationale;
+pub mod append_correction_notice;
+pub mod set_recovery_window;
+pub mod propose_authority_recovery;
+pub mod approve_recovery;
+pub mod execute_recovery;
+pub mod cancel_recovery;
+pub mod open_status_index;
+pub mod set_threshold_proposal_window;
+pub mod propose_threshold_change;
+pub mod approve_threshold_change;
+pub mod set_program_version;
+pub mod finalize_vote_receipt;
+pub mod close_vote_receipt;
+pub mod withdraw_treasury;
+pub mod set_bond_settle_delay;
 
+pub use initialize_config::*;
+pub use update_max_votes::*;
+pub use update_admin::*;
 pub use register_official::*;
 pub use register_video::*;
+pub use authorize_hashes::*;
+pub use register_authorized_video::*;
 pub use endorse_video::*;
+pub use change_vote::*;
+pub use revoke_video::*;
+pub use add_mirror::*;
+pub use remove_mirror::*;
+pub use file_takedown::*;
+pub use lift_takedown::*;
+pub use file_counter_claim::*;
+pub use resolve_counter_claim::*;
+pub use unfreeze_video::*;
+pub use link_same_event::*;
+pub use set_endorser_cosigner::*;
+pub use claim_handle::*;
+pub use reassign_handle::*;
+pub use co_attest::*;
+pub use revoke_co_attestation::*;
+pub use set_registration_bond::*;
+pub use reclaim_bond::*;
+pub use slash_bond::*;
+pub use set_integrity_params::*;
+pub use require_verified::*;
+pub use create_cursor::*;
+pub use bump_cursor::*;
+pub use bind_external_key::*;
+pub use attest_domain::*;
+pub use revoke_domain_attestation::*;
+pub use attest_social::*;
+pub use revoke_social::*;
+pub use schedule_rotation::*;
+pub use apply_scheduled_rotation::*;
+pub use link_sns_domain::*;
+pub use unlink_sns_domain::*;
+pub use invalidate_sns_link::*;
+pub use verify_bundle::*;
+pub use set_snapshotter::*;
+pub use publish_snapshot::*;
+pub use set_close_authority::*;
+pub use set_standby_endorser::*;
+pub use set_standby_activation_secs::*;
+pub use activate_standby::*;
+pub use emit_current_state::*;
+pub use register_provider::*;
+pub use revoke_provider::*;
+pub use attest_pin::*;
+pub use collect_pin_receipt::*;
+pub use heartbeat::*;
+pub use set_liveness_window::*;
+pub use mark_endorser_liveness::*;
+pub use register_broadcast_attestor::*;
+pub use remove_broadcast_attestor::*;
+pub use attest_broadcast::*;
+pub use set_clock_override::*;
+pub use list_video::*;
+pub use set_require_endorser_rationale::*;
+pub use append_correction_notice::*;
+pub use set_recovery_window::*;
+pub use propose_authority_recovery::*;
+pub use approve_recovery::*;
+pub use execute_recovery::*;
+pub use cancel_recovery::*;
+pub use open_status_index::*;
+pub use set_threshold_proposal_window::*;
+pub use propose_threshold_change::*;
+pub use approve_threshold_change::*;
+pub use set_program_version::*;
+pub use finalize_vote_receipt::*;
+pub use close_vote_receipt::*;
+pub use withdraw_treasury::*;
+pub use set_bond_settle_delay::*;
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Official, StatusIndex, VideoStatus, STATUS_INDEX_SIZE};
+
+// Permissionless. Opens page 0 of a bucket, or the continuation of a full
+// page (passed as `previous`, which gets linked to the new page).
+#[derive(Accounts)]
+#[instruction(status: u8, page: u32)]
+pub struct OpenStatusIndex<'info> {
+    pub official: Account<'info, Official>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = STATUS_INDEX_SIZE,
+        seeds = [b"status_index", official.key().as_ref(), &[status], &page.to_le_bytes()],
+        bump
+    )]
+    pub status_index: Account<'info, StatusIndex>,
+
+    #[account(
+        mut,
+        seeds = [b"status_index", official.key().as_ref(), &[status], &page.wrapping_sub(1).to_le_bytes()],
+        bump = previous.bump
+    )]
+    pub previous: Option<Account<'info, StatusIndex>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenStatusIndex>, status: u8, page: u32) -> Result<()> {
+    let status = VideoStatus::from_u8(status).ok_or(TruChainError::InvalidStatusValue)?;
+    // buckets hold vote outcomes; revocations are announced by event only
+    if matches!(status, VideoStatus::Unverified | VideoStatus::Revoked) {
+        return err!(TruChainError::InvalidStatusValue);
+    }
+
+    // every page after the head hangs off a full predecessor
+    match (page, ctx.accounts.previous.as_mut()) {
+        (0, None) => {}
+        (0, Some(_)) | (_, None) => return err!(TruChainError::StatusIndexMismatch),
+        (_, Some(previous)) => {
+            if !previous.is_full() {
+                return err!(TruChainError::StatusIndexNotFull);
+            }
+            previous.next = ctx.accounts.status_index.key();
+        }
+    }
+
+    let index = &mut ctx.accounts.status_index;
+    index.official = ctx.accounts.official.key();
+    index.status = status;
+    index.page = page;
+    index.next = Pubkey::default();
+    index.bump = ctx.bumps.status_index;
+
+    Ok(())
+}
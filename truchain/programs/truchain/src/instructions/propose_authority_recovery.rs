@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Config, Official, RecoveryProposal, RECOVERY_PROPOSAL_SIZE};
+use crate::utils::current_time;
+
+// Any panelist can open a recovery. An expired proposal is overwritten;
+// a live one has to be executed or cancelled first.
+#[derive(Accounts)]
+pub struct ProposeAuthorityRecovery<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = official.endorser_slot(&endorser.key()).is_some() @ TruChainError::UnauthorizedEndorser
+    )]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        init_if_needed,
+        payer = endorser,
+        space = RECOVERY_PROPOSAL_SIZE,
+        seeds = [b"recovery", official.key().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, RecoveryProposal>,
+
+    #[account(mut)]
+    pub endorser: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthorityRecovery>, new_authority: Pubkey) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let official = &ctx.accounts.official;
+    if new_authority == Pubkey::default() || new_authority == official.authority {
+        return err!(TruChainError::InvalidRecoveryAuthority);
+    }
+    if official.endorser_slot(&new_authority).is_some() {
+        return err!(TruChainError::RecoveryTargetIsEndorser);
+    }
+
+    let endorser = ctx.accounts.endorser.key();
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.official == Pubkey::default() {
+        proposal.rent_payer = endorser;
+    } else if !proposal.is_expired(now) {
+        return err!(TruChainError::RecoveryPending);
+    }
+
+    proposal.official = official.key();
+    proposal.new_authority = new_authority;
+    proposal.proposer = endorser;
+    proposal.approvals = vec![endorser];
+    proposal.expires_at = now.saturating_add(ctx.accounts.config.recovery_window_secs);
+    proposal.bump = ctx.bumps.proposal;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Config, Official, ThresholdProposal};
+use crate::utils::current_time;
+
+// The authority or the admin proposes; the panel decides (see
+// approve_threshold_change). An expired proposal can be replaced.
+#[derive(Accounts)]
+pub struct ProposeThresholdChange<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = proposer.key() == official.authority || proposer.key() == config.admin
+            @ TruChainError::UnauthorizedOfficial
+    )]
+    pub official: Account<'info, Official>,
+
+    pub proposer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeThresholdChange>, new_threshold: u8) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let official = &mut ctx.accounts.official;
+    if new_threshold == 0 || new_threshold as usize > official.endorsers.len() {
+        return err!(TruChainError::InvalidThreshold);
+    }
+    if official.pending_threshold.as_ref().is_some_and(|p| now <= p.expires_at) {
+        return err!(TruChainError::ThresholdChangePending);
+    }
+
+    official.pending_threshold = Some(ThresholdProposal {
+        new_threshold,
+        proposed_by: ctx.accounts.proposer.key(),
+        expires_at: now.saturating_add(ctx.accounts.config.threshold_proposal_secs),
+        approvals: Vec::new(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::SnapshotPublished;
+use crate::state::{snapshot_ring_index, Config, Official, Snapshot, SNAPSHOT_SIZE};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+pub struct PublishSnapshot<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = snapshotter @ TruChainError::UnauthorizedSnapshotter
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    // ring slot for this epoch; reused once the ring wraps
+    #[account(
+        init_if_needed,
+        payer = snapshotter,
+        space = SNAPSHOT_SIZE,
+        seeds = [
+            b"snapshot",
+            official.key().as_ref(),
+            &[snapshot_ring_index(official.snapshot_epoch)]
+        ],
+        bump
+    )]
+    pub snapshot: Account<'info, Snapshot>,
+
+    #[account(mut)]
+    pub snapshotter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PublishSnapshot>, merkle_root: [u8; 32], video_count: u64) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let official = &mut ctx.accounts.official;
+
+    // the tree must cover exactly the videos registered on-chain
+    if video_count != official.video_count {
+        return err!(TruChainError::SnapshotCountMismatch);
+    }
+
+    let slot = Clock::get()?.slot;
+    let epoch = official.snapshot_epoch;
+    official.snapshot_epoch = epoch.checked_add(1).ok_or(TruChainError::CounterOverflow)?;
+
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.official = official.key();
+    snapshot.epoch = epoch;
+    snapshot.merkle_root = merkle_root;
+    snapshot.video_count = video_count;
+    snapshot.slot = slot;
+    snapshot.published_at = now;
+    snapshot.bump = ctx.bumps.snapshot;
+
+    emit!(SnapshotPublished {
+        official: official.key(),
+        epoch,
+        merkle_root,
+        video_count,
+        slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::HandleReassigned;
+use crate::state::{Config, Handle, Official};
+
+// The only way a handle changes hands: admin moves it between officials.
+#[derive(Accounts)]
+pub struct ReassignHandle<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"handle", handle_account.handle_str().as_bytes()],
+        bump = handle_account.bump,
+        constraint = handle_account.official == from_official.key() @ TruChainError::HandleOwnerMismatch
+    )]
+    pub handle_account: Account<'info, Handle>,
+
+    #[account(mut)]
+    pub from_official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        constraint = to_official.key() != from_official.key() @ TruChainError::HandleOwnerMismatch
+    )]
+    pub to_official: Account<'info, Official>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReassignHandle>) -> Result<()> {
+    let to_official = &mut ctx.accounts.to_official;
+    if to_official.has_handle() {
+        return err!(TruChainError::OfficialHasHandle);
+    }
+
+    let handle_account = &mut ctx.accounts.handle_account;
+    let from_official = &mut ctx.accounts.from_official;
+
+    from_official.handle = [0u8; 32];
+    to_official.handle = handle_account.handle;
+    handle_account.official = to_official.key();
+
+    emit!(HandleReassigned {
+        handle: handle_account.handle_str().to_string(),
+        from_official: from_official.key(),
+        to_official: to_official.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::BondSettled;
+use crate::state::{BondState, Config, Video, VideoStatus};
+use crate::utils::current_time;
+
+// Permissionless: once a video has been Authentic for config.bond_settle_delay_secs
+// its bond goes back to whoever posted it. Until then change_vote can still
+// reopen the verdict.
+#[derive(Accounts)]
+pub struct ReclaimBond<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = video.status == VideoStatus::Authentic @ TruChainError::VideoNotAuthentic,
+        constraint = video.bond_state == BondState::Escrowed @ TruChainError::BondNotEscrowed,
+        has_one = rent_payer @ TruChainError::BondPayerMismatch
+    )]
+    pub video: Account<'info, Video>,
+
+    /// CHECK: refund target only, pinned to video.rent_payer above (the
+    /// signer that posted the bond along with the rent).
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ReclaimBond>) -> Result<()> {
+    let now = current_time(&ctx)?;
+    let age = now.saturating_sub(ctx.accounts.video.finalized_at);
+    if age < ctx.accounts.config.bond_settle_delay_secs {
+        return err!(TruChainError::BondSettleDelayActive);
+    }
+
+    let bond = ctx.accounts.video.bond_lamports;
+
+    ctx.accounts.video.sub_lamports(bond)?;
+    ctx.accounts.rent_payer.add_lamports(bond)?;
+
+    let video = &mut ctx.accounts.video;
+    video.bond_state = BondState::Reclaimed;
+
+    emit!(BondSettled {
+        video: video.key(),
+        recipient: video.rent_payer,
+        lamports: bond,
+        slashed: false,
+    });
+
+    Ok(())
+}
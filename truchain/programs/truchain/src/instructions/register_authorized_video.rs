@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::instructions::register_video::create_video;
+use crate::state::{cid_hash, Config, HashAuthorization, Official, VideoVisibility};
+use crate::utils::current_time;
+
+// Permissionless registration of a hash the official's authority approved
+// ahead of time via authorize_hashes. The authorization is single-use, and
+// only honoured while its signer is still the official's authority, so a
+// rotated-out or recovered key can't keep feeding registrations.
+#[derive(Accounts)]
+#[instruction(video_hash: [u8; 32], ipfs_cid: String)]
+pub struct RegisterAuthorizedVideo<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    // consumed: rent goes back to the authority that approved the hash
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"hash_auth", official.key().as_ref(), &video_hash],
+        bump = authorization.bump,
+        has_one = official @ TruChainError::UnauthorizedOfficial,
+        has_one = authority @ TruChainError::UnauthorizedOfficial,
+        constraint = authorization.authority == official.authority @ TruChainError::StaleHashAuthorization
+    )]
+    pub authorization: Account<'info, HashAuthorization>,
+
+    /// CHECK: rent refund target only, pinned to the recorded authority above.
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: created in the handler, see RegisterVideo.
+    #[account(
+        mut,
+        seeds = [b"video", official.key().as_ref(), &video_hash],
+        bump
+    )]
+    pub video: UncheckedAccount<'info>,
+
+    /// CHECK: created in the handler, see RegisterVideo.
+    #[account(
+        mut,
+        seeds = [b"cid", official.key().as_ref(), &cid_hash(&ipfs_cid)],
+        bump
+    )]
+    pub cid_index: UncheckedAccount<'info>,
+
+    // anyone (e.g. a field team wallet) can submit and pay for the video
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterAuthorizedVideo>,
+    video_hash: [u8; 32],
+    ipfs_cid: String,
+) -> Result<()> {
+    let now = current_time(&ctx)?;
+    create_video(
+        &ctx.accounts.config,
+        &mut ctx.accounts.official,
+        &ctx.accounts.video,
+        &ctx.accounts.cid_index,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        video_hash,
+        ipfs_cid,
+        ctx.bumps.video,
+        ctx.bumps.cid_index,
+        VideoVisibility::Public,
+        [0u8; 32],
+        now,
+    )
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{BroadcastAttestor, Config, BROADCAST_ATTESTOR_SIZE};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+#[instruction(attestor: Pubkey)]
+pub struct RegisterBroadcastAttestor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = BROADCAST_ATTESTOR_SIZE,
+        seeds = [b"broadcast_attestor", attestor.as_ref()],
+        bump
+    )]
+    pub broadcast_attestor: Account<'info, BroadcastAttestor>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterBroadcastAttestor>, attestor: Pubkey) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let entry = &mut ctx.accounts.broadcast_attestor;
+    entry.attestor = attestor;
+    entry.added_at = now;
+    entry.bump = ctx.bumps.broadcast_attestor;
+
+    Ok(())
+}
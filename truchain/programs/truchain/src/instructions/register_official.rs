@@ -1,37 +1,64 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::TruChainError;
-use crate::state::{Official, OFFICIAL_SIZE};
+use crate::state::{
+    official_size, validate_panel, validate_rationale, Config, Official, MAX_INTEGRITY_SCORE,
+};
+use crate::utils::{create_pda_account, is_initialized, write_account};
 
 #[derive(Accounts)]
 #[instruction(official_id: u64)]
 pub struct RegisterOfficial<'info> {
     #[account(
-        init,
-        payer = admin,
-        space = OFFICIAL_SIZE,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: created in the handler rather than via `init`, so that an id
+    /// collision returns OfficialAlreadyExists instead of a system error.
+    #[account(
+        mut,
         seeds = [b"official", &official_id.to_le_bytes()],
         bump
     )]
-    pub official: Account<'info, Official>,
+    pub official: UncheckedAccount<'info>,
 
     #[account(mut)]
-    pub admin: Signer<'info>, // bootstrap authority for creating officials
+    pub admin: Signer<'info>, // must match config.admin
 
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<RegisterOfficial>,
     official_id: u64,
     name: String,
     authority: Pubkey,
-    endorsers: [Pubkey; 3],
+    endorsers: Vec<Pubkey>,
+    threshold: u8,
+    rationale_hash: [u8; 32],
+    rationale_cid: String,
 ) -> Result<()> {
-    // validate endorsers count (array always len=3, but we keep the error for clarity)
-    // if you ever change to Vec, this becomes important
-    if endorsers.len() != 3 {
-        return err!(TruChainError::InvalidEndorserCount);
+    let official_info = ctx.accounts.official.to_account_info();
+
+    // id collision: surface who already owns this id
+    if is_initialized(&official_info) {
+        let existing = Official::try_deserialize(&mut &official_info.try_borrow_data()?[..])?;
+        msg!(
+            "Official {} already registered to authority {}",
+            existing.official_id,
+            existing.authority
+        );
+        return err!(TruChainError::OfficialAlreadyExists);
+    }
+
+    // count against the deployment-wide cap, no default or repeated keys
+    validate_panel(&endorsers, ctx.accounts.config.max_votes as usize)?;
+    if threshold == 0 || threshold as usize > endorsers.len() {
+        return err!(TruChainError::InvalidThreshold);
     }
 
     // validate name length (UTF-8 already guaranteed by String)
@@ -40,19 +67,59 @@ pub fn handler(
         return err!(TruChainError::InvalidOfficialName);
     }
 
+    let rationale_cid = validate_rationale(
+        ctx.accounts.config.require_endorser_rationale,
+        &rationale_hash,
+        &rationale_cid,
+        &[0u8; 32],
+    )?;
+
     // pack name into fixed [u8; 32]
     let mut name_padded = [0u8; 32];
     name_padded[..name_bytes.len()].copy_from_slice(name_bytes);
 
-    let official = &mut ctx.accounts.official;
-
-    official.official_id = official_id;
-    official.name = name_padded;
-    official.authority = authority;
-    official.endorsers = endorsers;
-
-    // store bump
-    official.bump = ctx.bumps.official;
+    let bump = ctx.bumps.official;
+    let id_bytes = official_id.to_le_bytes();
+    let seeds: &[&[u8]] = &[b"official", &id_bytes, &[bump]];
+    create_pda_account(
+        &ctx.accounts.admin,
+        &official_info,
+        &ctx.accounts.system_program,
+        official_size(endorsers.len()),
+        seeds,
+    )?;
 
-    Ok(())
+    let official = Official {
+        official_id,
+        name: name_padded,
+        authority,
+        endorser_cosigners: vec![None; endorsers.len()],
+        endorsers,
+        handle: [0u8; 32],
+        finalized_count: 0,
+        disputed_count: 0,
+        integrity_score: MAX_INTEGRITY_SCORE,
+        external_key_fingerprint: [0u8; 32],
+        external_key_kind: 0,
+        domain: [0u8; 64],
+        domain_attested_at: 0,
+        domain_attested_by: Pubkey::default(),
+        social_attestations: Vec::new(),
+        scheduled_rotation: None,
+        sns_name_account: Pubkey::default(),
+        video_count: 0,
+        snapshot_epoch: 0,
+        standby_endorser: Pubkey::default(),
+        inactive_slots: 0,
+        endorser_rationale_hash: rationale_hash,
+        endorser_rationale_cid: rationale_cid,
+        min_finalize_secs: 0,
+        max_finalize_secs: 0,
+        mean_finalize_millis: 0,
+        total_finalize_secs: 0,
+        threshold,
+        pending_threshold: None,
+        bump,
+    };
+    write_account(&official_info, &official)
 }
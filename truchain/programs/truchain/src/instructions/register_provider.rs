@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::ProviderRegistered;
+use crate::state::{Config, Provider, PROVIDER_SIZE};
+use crate::utils::current_time;
+
+#[derive(Accounts)]
+#[instruction(name: String, provider_key: Pubkey)]
+pub struct RegisterProvider<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = PROVIDER_SIZE,
+        seeds = [b"provider", provider_key.as_ref()],
+        bump
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterProvider>,
+    name: String,
+    provider_key: Pubkey,
+    endpoint_hash: [u8; 32],
+) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let name_bytes = name.as_bytes();
+    if name_bytes.is_empty() || name_bytes.len() > 32 {
+        return err!(TruChainError::InvalidProviderName);
+    }
+
+    let mut name_padded = [0u8; 32];
+    name_padded[..name_bytes.len()].copy_from_slice(name_bytes);
+
+    let provider = &mut ctx.accounts.provider;
+    provider.provider_key = provider_key;
+    provider.name = name_padded;
+    provider.endpoint_hash = endpoint_hash;
+    provider.revoked = false;
+    provider.active_receipts = 0;
+    provider.registered_at = now;
+    provider.bump = ctx.bumps.provider;
+
+    emit!(ProviderRegistered {
+        provider: provider.key(),
+        provider_key,
+        name,
+    });
+
+    Ok(())
+}
@@ -1,25 +1,48 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
 
 use crate::errors::TruChainError;
-use crate::state::{Official, Video, VideoStatus, VIDEO_SIZE};
+use crate::events::VideoRegistered;
+use crate::state::{
+    cid_hash, external_ref_hash, validate_external_ref, video_size, BondState, CidIndex, Config,
+    Official, RefIndex, Video, VideoStatus, VideoVisibility, CID_INDEX_SIZE, REF_INDEX_SIZE,
+};
+use crate::utils::{create_pda_account, current_time, is_initialized, write_account};
 
 #[derive(Accounts)]
-#[instruction(video_hash: [u8; 32])]
+#[instruction(video_hash: [u8; 32], ipfs_cid: String)]
 pub struct RegisterVideo<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         has_one = authority @ TruChainError::UnauthorizedOfficial
     )]
     pub official: Account<'info, Official>,
 
+    /// CHECK: created in the handler rather than via `init`, so that a
+    /// duplicate registration returns VideoAlreadyExists instead of the
+    /// system program's generic "account already in use" error.
     #[account(
-        init,
-        payer = authority,
-        space = VIDEO_SIZE,
+        mut,
         seeds = [b"video", official.key().as_ref(), &video_hash],
         bump
     )]
-    pub video: Account<'info, Video>,
+    pub video: UncheckedAccount<'info>,
+
+    /// CHECK: created in the handler alongside the video; see create_video.
+    #[account(
+        mut,
+        seeds = [b"cid", official.key().as_ref(), &cid_hash(&ipfs_cid)],
+        bump
+    )]
+    pub cid_index: UncheckedAccount<'info>,
+
+    /// CHECK: RefIndex PDA for `external_ref`; required iff a ref is given.
+    /// Address checked and account created in the handler.
+    #[account(mut)]
+    pub ref_index: Option<UncheckedAccount<'info>>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -31,7 +54,101 @@ pub fn handler(
     ctx: Context<RegisterVideo>,
     video_hash: [u8; 32],
     ipfs_cid: String,
+    visibility: u8,
+    external_ref: Option<String>,
 ) -> Result<()> {
+    let visibility = VideoVisibility::from_u8(visibility).ok_or(TruChainError::InvalidVisibility)?;
+    let now = current_time(&ctx)?;
+
+    let mut ref_padded = [0u8; 32];
+    if let Some(external_ref) = &external_ref {
+        if !validate_external_ref(external_ref) {
+            return err!(TruChainError::InvalidExternalRef);
+        }
+        ref_padded[..external_ref.len()].copy_from_slice(external_ref.as_bytes());
+    }
+    if external_ref.is_some() != ctx.accounts.ref_index.is_some() {
+        return err!(TruChainError::RefIndexMismatch);
+    }
+
+    create_video(
+        &ctx.accounts.config,
+        &mut ctx.accounts.official,
+        &ctx.accounts.video,
+        &ctx.accounts.cid_index,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        video_hash,
+        ipfs_cid,
+        ctx.bumps.video,
+        ctx.bumps.cid_index,
+        visibility,
+        ref_padded,
+        now,
+    )?;
+
+    if let (Some(external_ref), Some(ref_index)) = (external_ref, &ctx.accounts.ref_index) {
+        let official_key = ctx.accounts.official.key();
+        let ref_hash = external_ref_hash(&external_ref);
+        let (expected, bump) =
+            Pubkey::find_program_address(&[b"ref", official_key.as_ref(), &ref_hash], ctx.program_id);
+        if ref_index.key() != expected {
+            return err!(TruChainError::RefIndexMismatch);
+        }
+        if is_initialized(ref_index) {
+            return err!(TruChainError::ExternalRefTaken);
+        }
+
+        let seeds: &[&[u8]] = &[b"ref", official_key.as_ref(), &ref_hash, &[bump]];
+        create_pda_account(
+            &ctx.accounts.authority,
+            ref_index,
+            &ctx.accounts.system_program,
+            REF_INDEX_SIZE,
+            seeds,
+        )?;
+        let index = RefIndex {
+            official: official_key,
+            video: ctx.accounts.video.key(),
+            bump,
+        };
+        write_account(ref_index, &index)?;
+    }
+
+    Ok(())
+}
+
+// Vote capacity for a new video: the official's current panel size,
+// clamped to the deployment-wide cap.
+pub fn max_votes_for(config: &Config, official: &Official) -> usize {
+    official.endorsers.len().min(config.max_votes as usize)
+}
+
+// Validates and creates a Video PDA. Shared by every registration path so
+// that videos look the same regardless of how they were registered.
+#[allow(clippy::too_many_arguments)]
+pub fn create_video<'info>(
+    config: &Account<'info, Config>,
+    official: &mut Account<'info, Official>,
+    video_account: &AccountInfo<'info>,
+    cid_index_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    video_hash: [u8; 32],
+    ipfs_cid: String,
+    bump: u8,
+    cid_index_bump: u8,
+    visibility: VideoVisibility,
+    external_ref: [u8; 32],
+    now: i64,
+) -> Result<()> {
+    // duplicate registration: surface the existing record's status
+    if is_initialized(video_account) {
+        let existing = Video::try_deserialize(&mut &video_account.try_borrow_data()?[..])?;
+        msg!("Video already registered with status {:?}", existing.status);
+        return err!(TruChainError::VideoAlreadyExists);
+    }
+
     // basic CID validation
     let cid_bytes = ipfs_cid.as_bytes();
     if cid_bytes.is_empty() || cid_bytes.len() > 64 {
@@ -41,22 +158,91 @@ pub fn handler(
     let mut cid_padded = [0u8; 64];
     cid_padded[..cid_bytes.len()].copy_from_slice(cid_bytes);
 
-    let official = &ctx.accounts.official;
-    let video = &mut ctx.accounts.video;
+    let official_key = official.key();
+
+    // a CID this official already used must keep pointing at the same content
+    let cid_hash = cid_hash(&ipfs_cid);
+    if is_initialized(cid_index_account) {
+        let existing = CidIndex::try_deserialize(&mut &cid_index_account.try_borrow_data()?[..])?;
+        if existing.video_hash != video_hash {
+            return err!(TruChainError::CidHashMismatch);
+        }
+    } else {
+        let cid_seeds: &[&[u8]] = &[b"cid", official_key.as_ref(), &cid_hash, &[cid_index_bump]];
+        create_pda_account(payer, cid_index_account, system_program, CID_INDEX_SIZE, cid_seeds)?;
+        let index = CidIndex {
+            official: official_key,
+            cid_hash,
+            video_hash,
+            bump: cid_index_bump,
+        };
+        write_account(cid_index_account, &index)?;
+    }
+
+    // a cap lowered below the panel's threshold would leave the video unable
+    // to reach Authentic while a single Disputed vote still settles it
+    let max_votes = max_votes_for(config, official);
+    if official.threshold as usize > max_votes {
+        return err!(TruChainError::ThresholdExceedsVoteCap);
+    }
+
+    let seeds: &[&[u8]] = &[b"video", official_key.as_ref(), &video_hash, &[bump]];
+    create_pda_account(payer, video_account, system_program, video_size(max_votes), seeds)?;
 
-    video.official = official.key();
-    video.video_hash = video_hash;
-    video.ipfs_cid = cid_padded;
+    // escrow the registration bond on top of the rent
+    let bond = config.registration_bond_lamports;
+    if bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: video_account.clone() },
+            ),
+            bond,
+        )?;
+    }
 
-    // timestamp
-    let clock = Clock::get()?;
-    video.timestamp = clock.unix_timestamp;
+    official.video_count = official
+        .video_count
+        .checked_add(1)
+        .ok_or(TruChainError::CounterOverflow)?;
 
-    // initial state
-    video.votes = Vec::new();
-    video.status = VideoStatus::Unverified;
+    let video = Video {
+        official: official_key,
+        video_hash,
+        ipfs_cid: cid_padded,
+        timestamp: now,
+        // initial state
+        max_votes_at_registration: max_votes as u8,
+        votes: Vec::new(),
+        status: VideoStatus::Unverified,
+        takedown: false,
+        takedown_requester_hash: [0u8; 32],
+        takedown_reason_code: 0,
+        frozen: false,
+        related_videos: Vec::new(),
+        classification: None,
+        co_attestation_count: 0,
+        bond_lamports: bond,
+        bond_state: if bond > 0 { BondState::Escrowed } else { BondState::None },
+        finalized_at: 0,
+        standby_active: false,
+        rent_payer: payer.key(),
+        listed: visibility == VideoVisibility::Public,
+        has_notice: false,
+        external_ref,
+        threshold_at_registration: official.threshold,
+        status_index_page: None,
+        bump,
+    };
+    write_account(video_account, &video)?;
 
-    video.bump = ctx.bumps.video;
+    // unlisted videos only reveal the CID once finalized (or listed early)
+    emit!(VideoRegistered {
+        official: official_key,
+        video: video_account.key(),
+        video_hash,
+        ipfs_cid: video.listed.then_some(ipfs_cid),
+    });
 
     Ok(())
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{BroadcastAttestor, Config};
+
+// Existing attestations stay; the key just can't write new ones.
+#[derive(Accounts)]
+pub struct RemoveBroadcastAttestor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"broadcast_attestor", broadcast_attestor.attestor.as_ref()],
+        bump = broadcast_attestor.bump
+    )]
+    pub broadcast_attestor: Account<'info, BroadcastAttestor>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<RemoveBroadcastAttestor>) -> Result<()> {
+    Ok(())
+}
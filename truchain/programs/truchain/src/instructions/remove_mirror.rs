@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::MirrorRemoved;
+use crate::state::{Mirrors, Official, Video};
+
+#[derive(Accounts)]
+pub struct RemoveMirror<'info> {
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = !video.takedown @ TruChainError::VideoTakenDown
+    )]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        mut,
+        seeds = [b"mirrors", video.key().as_ref()],
+        bump = mirrors.bump
+    )]
+    pub mirrors: Account<'info, Mirrors>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveMirror>, index: u8) -> Result<()> {
+    let mirrors = &mut ctx.accounts.mirrors;
+
+    let index = index as usize;
+    if index >= mirrors.mirrors.len() {
+        return err!(TruChainError::MirrorIndexOutOfBounds);
+    }
+
+    // keep the remaining mirrors in insertion order
+    let removed = mirrors.mirrors.remove(index);
+
+    let uri_len = removed.uri.iter().position(|&b| b == 0).unwrap_or(removed.uri.len());
+    emit!(MirrorRemoved {
+        video: mirrors.video,
+        index: index as u8,
+        kind: removed.kind,
+        uri: String::from_utf8_lossy(&removed.uri[..uri_len]).into_owned(),
+    });
+
+    Ok(())
+}
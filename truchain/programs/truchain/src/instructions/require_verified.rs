@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Video, VideoStatus};
+use crate::utils::current_time;
+
+// Read-only gate for other programs: fails unless the video currently has
+// `required_status` and finalized no more than `max_age_secs` ago
+// (max_age_secs <= 0 disables the age check). A CPI caller just propagates
+// the error. Emits nothing on success to stay cheap.
+#[derive(Accounts)]
+pub struct RequireVerified<'info> {
+    pub video: Account<'info, Video>,
+}
+
+pub fn handler(ctx: Context<RequireVerified>, max_age_secs: i64, required_status: u8) -> Result<()> {
+    let now = current_time(&ctx)?;
+
+    let video = &ctx.accounts.video;
+
+    // Revoked is not a verdict anyone can gate on
+    let required = VideoStatus::from_u8(required_status)
+        .filter(|status| *status != VideoStatus::Revoked)
+        .ok_or(TruChainError::InvalidStatusValue)?;
+
+    // a video under review or takedown never passes the gate
+    if video.frozen || video.takedown {
+        return err!(TruChainError::VerificationRequirementNotMet);
+    }
+
+    if video.status != required {
+        return err!(TruChainError::VerificationRequirementNotMet);
+    }
+
+    if max_age_secs > 0 && required != VideoStatus::Unverified {
+        let age = now.saturating_sub(video.finalized_at);
+        if age > max_age_secs {
+            return err!(TruChainError::VerificationTooOld);
+        }
+    }
+
+    Ok(())
+}
+
+// Convenience wrapper for consumer programs built with the `cpi` feature:
+//
+//     truchain::instructions::require_verified::require_verified_cpi(
+//         ctx.accounts.truchain_program.to_account_info(),
+//         ctx.accounts.video.to_account_info(),
+//         30 * 24 * 60 * 60,
+//         VideoStatus::Authentic,
+//     )?;
+#[cfg(feature = "cpi")]
+pub fn require_verified_cpi<'info>(
+    truchain_program: AccountInfo<'info>,
+    video: AccountInfo<'info>,
+    max_age_secs: i64,
+    required_status: VideoStatus,
+) -> Result<()> {
+    crate::cpi::require_verified(
+        CpiContext::new(truchain_program, crate::cpi::accounts::RequireVerified { video }),
+        max_age_secs,
+        required_status as u8,
+    )
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::{CounterClaimResolved, VideoFrozen};
+use crate::state::{Config, CounterClaim, Video};
+
+// Admin acts as arbitrator. Valid claims get their bond back and freeze the
+// video for review; invalid claims forfeit the bond to the config treasury
+// (withdrawable by the admin through withdraw_treasury).
+// Either way the claim account is closed and its rent returned to the claimant.
+#[derive(Accounts)]
+pub struct ResolveCounterClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub video: Account<'info, Video>,
+
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [b"counter_claim", video.key().as_ref(), claimant.key().as_ref()],
+        bump = counter_claim.bump,
+        has_one = video @ TruChainError::CounterClaimMismatch,
+        has_one = claimant @ TruChainError::CounterClaimMismatch
+    )]
+    pub counter_claim: Account<'info, CounterClaim>,
+
+    /// CHECK: refund target only, pinned to counter_claim.claimant above.
+    #[account(mut)]
+    pub claimant: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ResolveCounterClaim>, valid: bool) -> Result<()> {
+    let video_key = ctx.accounts.video.key();
+    let claimant_key = ctx.accounts.claimant.key();
+
+    if valid {
+        let video = &mut ctx.accounts.video;
+        if !video.frozen {
+            video.frozen = true;
+            emit!(VideoFrozen {
+                video: video_key,
+                frozen: true,
+            });
+        }
+    } else {
+        let bond = ctx.accounts.counter_claim.bond_lamports;
+        ctx.accounts.counter_claim.sub_lamports(bond)?;
+        ctx.accounts.config.add_lamports(bond)?;
+    }
+
+    emit!(CounterClaimResolved {
+        video: video_key,
+        claimant: claimant_key,
+        valid,
+    });
+
+    Ok(())
+}
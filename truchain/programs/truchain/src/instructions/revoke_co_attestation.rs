@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::CoAttestationRevoked;
+use crate::state::{CoAttestation, Official, Video};
+
+#[derive(Accounts)]
+pub struct RevokeCoAttestation<'info> {
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub attesting_official: Account<'info, Official>,
+
+    #[account(mut)]
+    pub video: Account<'info, Video>,
+
+    // rent goes back to whoever funded the attestation, not the current signer
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"co_attest", video.key().as_ref(), attesting_official.key().as_ref()],
+        bump = co_attestation.bump
+    )]
+    pub co_attestation: Account<'info, CoAttestation>,
+
+    /// CHECK: rent refund target only, pinned to the authority that attested
+    /// (and paid); it stays the target after the official's authority rotates.
+    #[account(mut, address = co_attestation.authority @ TruChainError::RentPayerMismatch)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeCoAttestation>) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    video.co_attestation_count = video.co_attestation_count.saturating_sub(1);
+
+    emit!(CoAttestationRevoked {
+        video: video.key(),
+        attesting_official: ctx.accounts.attesting_official.key(),
+        co_attestation_count: video.co_attestation_count,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::DomainAttestationRevoked;
+use crate::state::{Config, DomainIndex, Official};
+
+#[derive(Accounts)]
+pub struct RevokeDomainAttestation<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    // frees the domain for another official
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"domain", domain_index.domain_hash.as_ref()],
+        bump = domain_index.bump,
+        constraint = domain_index.official == official.key() @ TruChainError::DomainOwnerMismatch
+    )]
+    pub domain_index: Account<'info, DomainIndex>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeDomainAttestation>) -> Result<()> {
+    let official = &mut ctx.accounts.official;
+    let domain = official.domain;
+
+    official.domain = [0u8; 64];
+    official.domain_attested_at = 0;
+    official.domain_attested_by = Pubkey::default();
+
+    let len = domain.iter().position(|&b| b == 0).unwrap_or(domain.len());
+    emit!(DomainAttestationRevoked {
+        official: official.key(),
+        // only ever written from a validated ASCII domain
+        domain: String::from_utf8_lossy(&domain[..len]).into_owned(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::ProviderRevoked;
+use crate::state::{Config, Provider};
+
+#[derive(Accounts)]
+pub struct RevokeProvider<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"provider", provider.provider_key.as_ref()],
+        bump = provider.bump,
+        constraint = !provider.revoked @ TruChainError::ProviderRevoked
+    )]
+    pub provider: Account<'info, Provider>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeProvider>) -> Result<()> {
+    // existing receipts stay on-chain but fail PinReceipt::is_valid
+    let provider = &mut ctx.accounts.provider;
+    provider.revoked = true;
+
+    emit!(ProviderRevoked {
+        provider: provider.key(),
+        active_receipts: provider.active_receipts,
+    });
+
+    Ok(())
+}
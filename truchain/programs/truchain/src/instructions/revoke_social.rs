@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::SocialRevoked;
+use crate::state::{Config, Official, SocialIndex};
+
+#[derive(Accounts)]
+pub struct RevokeSocial<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"social", &[social_index.platform], social_index.handle_bytes()],
+        bump = social_index.bump,
+        constraint = social_index.official == official.key() @ TruChainError::SocialIndexMismatch
+    )]
+    pub social_index: Account<'info, SocialIndex>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeSocial>) -> Result<()> {
+    let index = &ctx.accounts.social_index;
+    let official = &mut ctx.accounts.official;
+
+    let slot = official
+        .social_slot(index.platform)
+        .filter(|&slot| official.social_attestations[slot].handle == index.handle)
+        .ok_or(TruChainError::SocialIndexMismatch)?;
+    official.social_attestations.remove(slot);
+
+    emit!(SocialRevoked {
+        official: official.key(),
+        platform: index.platform,
+        handle: String::from_utf8_lossy(index.handle_bytes()).into_owned(),
+    });
+
+    Ok(())
+}
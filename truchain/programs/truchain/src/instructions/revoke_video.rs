@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::{BondSettled, StatusChanged, VideoRevoked};
+use crate::instructions::endorse_video::remove_from_status_index;
+use crate::state::{BondState, Config, Official, StatusIndex, Video, VideoStatus};
+
+// Pulls a video for good, e.g. when the wrong file was registered. Settles
+// an escrowed bond on the spot (refunded only if the panel had ruled the
+// video Authentic, forfeited otherwise, so revoking ahead of a Disputed
+// verdict can't rescue it) and drops the video from its status bucket.
+// The official's integrity stats keep any verdict already reached.
+#[derive(Accounts)]
+pub struct RevokeVideo<'info> {
+    // receives a forfeited bond
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(has_one = authority @ TruChainError::UnauthorizedOfficial)]
+    pub official: Account<'info, Official>,
+
+    // Marked Revoked rather than closed: the PDA stays allocated so the same
+    // hash can't be re-registered under this official, and receipts, CID
+    // index and notices keep resolving to a terminal status.
+    #[account(
+        mut,
+        constraint = video.official == official.key() @ TruChainError::UnauthorizedOfficial,
+        constraint = video.status != VideoStatus::Revoked @ TruChainError::VideoRevoked,
+        constraint = !video.frozen @ TruChainError::VideoFrozen
+    )]
+    pub video: Account<'info, Video>,
+
+    /// CHECK: bond refund target only, pinned to video.rent_payer; needed
+    /// when a bond is escrowed and the video is Authentic.
+    #[account(mut, address = video.rent_payer @ TruChainError::BondPayerMismatch)]
+    pub rent_payer: Option<UncheckedAccount<'info>>,
+
+    // page listing the video (video.status_index_page), if it has one
+    #[account(mut)]
+    pub status_index: Option<Account<'info, StatusIndex>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeVideo>) -> Result<()> {
+    let official_key = ctx.accounts.official.key();
+    let old_status = ctx.accounts.video.status;
+
+    if ctx.accounts.video.bond_state == BondState::Escrowed {
+        let bond = ctx.accounts.video.bond_lamports;
+        let slashed = old_status != VideoStatus::Authentic;
+        let recipient = if slashed {
+            ctx.accounts.config.add_lamports(bond)?;
+            ctx.accounts.config.key()
+        } else {
+            let rent_payer = ctx.accounts.rent_payer.as_ref().ok_or(TruChainError::BondPayerMismatch)?;
+            rent_payer.add_lamports(bond)?;
+            rent_payer.key()
+        };
+        ctx.accounts.video.sub_lamports(bond)?;
+        ctx.accounts.video.bond_state = if slashed { BondState::Slashed } else { BondState::Reclaimed };
+
+        emit!(BondSettled {
+            video: ctx.accounts.video.key(),
+            recipient,
+            lamports: bond,
+            slashed,
+        });
+    }
+
+    let video = &mut ctx.accounts.video;
+    remove_from_status_index(&official_key, video, ctx.accounts.status_index.as_mut(), old_status)?;
+
+    video.status = VideoStatus::Revoked;
+    video.classification = None;
+
+    let official = &ctx.accounts.official;
+    emit!(VideoRevoked {
+        official: official_key,
+        video: video.key(),
+        old_status,
+    });
+    emit!(StatusChanged {
+        video: video.key(),
+        old: old_status,
+        new: video.status,
+        classification: None,
+        min_finalize_secs: official.min_finalize_secs,
+        max_finalize_secs: official.max_finalize_secs,
+        mean_finalize_millis: official.mean_finalize_millis,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{validate_panel, validate_rationale, Config, Official, ScheduledRotation};
+use crate::utils::current_time;
+
+// Admin queues a full panel swap for a future date (e.g. a new term).
+// Scheduling again replaces any pending rotation.
+#[derive(Accounts)]
+pub struct ScheduleRotation<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ScheduleRotation>,
+    effective_at: i64,
+    new_endorsers: Vec<Pubkey>,
+    rationale_hash: [u8; 32],
+    rationale_cid: String,
+) -> Result<()> {
+    let now = current_time(&ctx)?;
+    if effective_at <= now {
+        return err!(TruChainError::InvalidRotationTime);
+    }
+
+    validate_panel(&new_endorsers, ctx.accounts.config.max_votes as usize)?;
+
+    // the account was sized for the current panel
+    let official = &mut ctx.accounts.official;
+    if new_endorsers.len() != official.endorsers.len() {
+        return err!(TruChainError::InvalidEndorserCount);
+    }
+    if official.has_standby() && new_endorsers.contains(&official.standby_endorser) {
+        return err!(TruChainError::DuplicateEndorsers);
+    }
+
+    // the new panel needs its own rationale; the old one no longer applies
+    let rationale_cid = validate_rationale(
+        ctx.accounts.config.require_endorser_rationale,
+        &rationale_hash,
+        &rationale_cid,
+        &official.endorser_rationale_hash,
+    )?;
+
+    official.scheduled_rotation = Some(ScheduledRotation {
+        effective_at,
+        new_endorsers,
+        rationale_hash,
+        rationale_cid,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+// Zero lets bonds settle as soon as a verdict lands, at the cost of locking
+// that verdict against change_vote from then on.
+#[derive(Accounts)]
+pub struct SetBondSettleDelay<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetBondSettleDelay>, secs: i64) -> Result<()> {
+    if secs < 0 {
+        return err!(TruChainError::InvalidBondSettleDelay);
+    }
+
+    ctx.accounts.config.bond_settle_delay_secs = secs;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{ClockOverride, Config, CLOCK_OVERRIDE_SIZE};
+
+// Anchor's #[program] can't cfg-gate a single instruction, so it always
+// exists but refuses to run unless built with `test-clock`. Passing 0
+// switches the override off.
+#[derive(Accounts)]
+pub struct SetClockOverride<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CLOCK_OVERRIDE_SIZE,
+        seeds = [b"clock_override"],
+        bump
+    )]
+    pub clock_override: Account<'info, ClockOverride>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetClockOverride>, timestamp: i64) -> Result<()> {
+    if !cfg!(feature = "test-clock") {
+        return err!(TruChainError::ClockOverrideDisabled);
+    }
+    if timestamp < 0 {
+        return err!(TruChainError::InvalidClockOverride);
+    }
+
+    let clock_override = &mut ctx.accounts.clock_override;
+    clock_override.unix_timestamp = timestamp;
+    clock_override.bump = ctx.bumps.clock_override;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetCloseAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetCloseAuthority>, close_authority: Pubkey) -> Result<()> {
+    // Pubkey::default() leaves closing to payers and the admin
+    ctx.accounts.config.close_authority = close_authority;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Config, Official};
+
+// Admin pairs (or unpairs) an endorser slot with a second required signer.
+#[derive(Accounts)]
+pub struct SetEndorserCosigner<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetEndorserCosigner>,
+    slot_index: u8,
+    cosigner: Option<Pubkey>,
+) -> Result<()> {
+    let official = &mut ctx.accounts.official;
+    let slot = slot_index as usize;
+
+    if slot >= official.endorsers.len() {
+        return err!(TruChainError::InvalidEndorserSlot);
+    }
+
+    if let Some(key) = cosigner {
+        // a cosigner must be a distinct person: not the primary, not the
+        // authority, not anyone else on the panel
+        if key == Pubkey::default()
+            || key == official.authority
+            || official.endorsers.contains(&key)
+        {
+            return err!(TruChainError::InvalidCosigner);
+        }
+    }
+
+    official.endorser_cosigners[slot] = cosigner;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetIntegrityParams<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetIntegrityParams>,
+    dispute_weight_bps: u16,
+    alert_delta: u8,
+) -> Result<()> {
+    if dispute_weight_bps > 10_000 {
+        return err!(TruChainError::InvalidIntegrityParams);
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.integrity_dispute_weight_bps = dispute_weight_bps;
+    config.integrity_alert_delta = alert_delta;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Config, ProgramVersion, PROGRAM_VERSION_SIZE};
+
+// Called by the admin immediately after each upgrade. Every call must move
+// the version forward; re-publishing the same numbers counts as a regression.
+#[derive(Accounts)]
+pub struct SetProgramVersion<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PROGRAM_VERSION_SIZE,
+        seeds = [b"version"],
+        bump
+    )]
+    pub program_version: Account<'info, ProgramVersion>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetProgramVersion>,
+    major: u16,
+    minor: u16,
+    patch: u16,
+    min_supported_account_version: u8,
+    changelog_hash: [u8; 32],
+) -> Result<()> {
+    let version = &mut ctx.accounts.program_version;
+
+    // bump is 0 only on the first write
+    if version.bump != 0 && (major, minor, patch) <= version.semver() {
+        return err!(TruChainError::VersionRegression);
+    }
+
+    version.major = major;
+    version.minor = minor;
+    version.patch = patch;
+    version.min_supported_account_version = min_supported_account_version;
+    version.upgrade_slot = Clock::get()?.slot;
+    version.changelog_hash = changelog_hash;
+    version.bump = ctx.bumps.program_version;
+
+    Ok(())
+}
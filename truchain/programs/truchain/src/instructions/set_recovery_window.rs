@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetRecoveryWindow<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetRecoveryWindow>, secs: i64) -> Result<()> {
+    if secs <= 0 {
+        return err!(TruChainError::InvalidRecoveryWindow);
+    }
+
+    ctx.accounts.config.recovery_window_secs = secs;
+
+    Ok(())
+}
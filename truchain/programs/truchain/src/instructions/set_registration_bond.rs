@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetRegistrationBond<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetRegistrationBond>, lamports: u64) -> Result<()> {
+    // applies to videos registered from now on; escrowed bonds keep their amount
+    ctx.accounts.config.registration_bond_lamports = lamports;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetSnapshotter<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetSnapshotter>, snapshotter: Pubkey) -> Result<()> {
+    // Pubkey::default() disables publishing
+    ctx.accounts.config.snapshotter = snapshotter;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetStandbyActivationSecs<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetStandbyActivationSecs>, secs: i64) -> Result<()> {
+    if secs <= 0 {
+        return err!(TruChainError::InvalidStandbyActivation);
+    }
+
+    ctx.accounts.config.standby_activation_secs = secs;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Config, Official};
+
+#[derive(Accounts)]
+pub struct SetStandbyEndorser<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub official: Account<'info, Official>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetStandbyEndorser>, standby: Pubkey) -> Result<()> {
+    let official = &mut ctx.accounts.official;
+
+    // Pubkey::default() removes the standby; otherwise it must be distinct
+    // from the main panel
+    if official.endorsers.contains(&standby) {
+        return err!(TruChainError::DuplicateEndorsers);
+    }
+    official.standby_endorser = standby;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetThresholdProposalWindow<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetThresholdProposalWindow>, secs: i64) -> Result<()> {
+    if secs <= 0 {
+        return err!(TruChainError::InvalidThresholdProposalWindow);
+    }
+
+    ctx.accounts.config.threshold_proposal_secs = secs;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::BondSettled;
+use crate::state::{BondState, Config, Video, VideoStatus};
+use crate::utils::current_time;
+
+// Permissionless: a Disputed video's bond is forfeited to the config treasury,
+// which the admin drains through withdraw_treasury. Waits out
+// config.bond_settle_delay_secs like reclaim_bond so the verdict can still move.
+#[derive(Accounts)]
+pub struct SlashBond<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = video.status == VideoStatus::Disputed @ TruChainError::VideoNotDisputed,
+        constraint = video.bond_state == BondState::Escrowed @ TruChainError::BondNotEscrowed
+    )]
+    pub video: Account<'info, Video>,
+}
+
+pub fn handler(ctx: Context<SlashBond>) -> Result<()> {
+    let now = current_time(&ctx)?;
+    let age = now.saturating_sub(ctx.accounts.video.finalized_at);
+    if age < ctx.accounts.config.bond_settle_delay_secs {
+        return err!(TruChainError::BondSettleDelayActive);
+    }
+
+    let bond = ctx.accounts.video.bond_lamports;
+
+    ctx.accounts.video.sub_lamports(bond)?;
+    ctx.accounts.config.add_lamports(bond)?;
+
+    let video = &mut ctx.accounts.video;
+    video.bond_state = BondState::Slashed;
+
+    emit!(BondSettled {
+        video: video.key(),
+        recipient: ctx.accounts.config.key(),
+        lamports: bond,
+        slashed: true,
+    });
+
+    Ok(())
+}
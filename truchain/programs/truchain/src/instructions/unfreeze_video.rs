@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::VideoFrozen;
+use crate::state::{Config, Video};
+
+// Admin closes the review opened by an upheld counter-claim.
+#[derive(Accounts)]
+pub struct UnfreezeVideo<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, constraint = video.frozen @ TruChainError::VideoNotFrozen)]
+    pub video: Account<'info, Video>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnfreezeVideo>) -> Result<()> {
+    let video = &mut ctx.accounts.video;
+    video.frozen = false;
+
+    emit!(VideoFrozen {
+        video: video.key(),
+        frozen: false,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::SnsDomainUnlinked;
+use crate::state::Official;
+
+#[derive(Accounts)]
+pub struct UnlinkSnsDomain<'info> {
+    #[account(
+        mut,
+        has_one = authority @ TruChainError::UnauthorizedOfficial,
+        constraint = official.sns_name_account != Pubkey::default() @ TruChainError::NoSnsLink
+    )]
+    pub official: Account<'info, Official>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnlinkSnsDomain>) -> Result<()> {
+    let official = &mut ctx.accounts.official;
+    let name_account = official.sns_name_account;
+    official.sns_name_account = Pubkey::default();
+
+    emit!(SnsDomainUnlinked {
+        official: official.key(),
+        name_account,
+        invalidated: false,
+    });
+
+    Ok(())
+}
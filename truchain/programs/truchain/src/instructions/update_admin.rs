@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+// Hands the config to a new admin. There is no two-step accept, so the
+// current admin must get `new_admin` right.
+#[derive(Accounts)]
+pub struct UpdateAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateAdmin>, new_admin: Pubkey) -> Result<()> {
+    if new_admin == Pubkey::default() {
+        return err!(TruChainError::InvalidAdmin);
+    }
+
+    ctx.accounts.config.admin = new_admin;
+
+    Ok(())
+}
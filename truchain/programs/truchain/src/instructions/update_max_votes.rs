@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct UpdateMaxVotes<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateMaxVotes>, max_votes: u8) -> Result<()> {
+    if !Config::validate_max_votes(max_votes) {
+        return err!(TruChainError::InvalidMaxVotes);
+    }
+
+    // only affects officials and videos registered from now on;
+    // existing videos keep their max_votes_at_registration
+    ctx.accounts.config.max_votes = max_votes;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::state::{Official, Video, VideoStatus};
+use crate::utils::is_initialized;
+
+// Keeps a full bundle (one find_program_address per unregistered hash)
+// inside the default compute budget.
+pub const MAX_BUNDLE_SIZE: usize = 50;
+
+// Bit i of `authentic` is set iff hash i is registered and Authentic; bit i
+// of `not_authentic` is set iff it is registered but Unverified, Disputed or
+// Revoked.
+// Both are little-endian bit order, ceil(n / 8) bytes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BundleResult {
+    pub authentic: Vec<u8>,
+    pub not_authentic: Vec<u8>,
+}
+
+// remaining_accounts: the Video PDA for each hash under `official`, same
+// order, whether or not it has been registered.
+#[derive(Accounts)]
+pub struct VerifyBundle<'info> {
+    pub official: Account<'info, Official>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VerifyBundle<'info>>,
+    hashes: Vec<[u8; 32]>,
+) -> Result<BundleResult> {
+    if hashes.is_empty() || hashes.len() > MAX_BUNDLE_SIZE {
+        return err!(TruChainError::InvalidBundle);
+    }
+    if ctx.remaining_accounts.len() != hashes.len() {
+        return err!(TruChainError::InvalidBundle);
+    }
+
+    let official_key = ctx.accounts.official.key();
+    let bitmap_len = hashes.len().div_ceil(8);
+    let mut result = BundleResult {
+        authentic: vec![0u8; bitmap_len],
+        not_authentic: vec![0u8; bitmap_len],
+    };
+
+    for (i, (video_hash, info)) in hashes.iter().zip(ctx.remaining_accounts.iter()).enumerate() {
+        let mask = 1u8 << (i % 8);
+
+        if !is_initialized(info) {
+            // must still be the right address, or "not registered" could be faked
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"video", official_key.as_ref(), video_hash],
+                ctx.program_id,
+            );
+            if info.key() != expected {
+                return err!(TruChainError::BundleOrderMismatch);
+            }
+            continue;
+        }
+
+        let video: Account<Video> = Account::try_from(info)?;
+        if video.video_hash != *video_hash || video.official != official_key {
+            return err!(TruChainError::BundleOrderMismatch);
+        }
+
+        if video.status == VideoStatus::Authentic {
+            result.authentic[i / 8] |= mask;
+        } else {
+            result.not_authentic[i / 8] |= mask;
+        }
+    }
+
+    Ok(result)
+}
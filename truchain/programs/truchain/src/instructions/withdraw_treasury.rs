@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TruChainError;
+use crate::events::TreasuryWithdrawn;
+use crate::state::Config;
+
+// Slashed registration bonds and forfeited counter-claim bonds accumulate
+// as Config lamports; the admin can sweep anything above rent exemption.
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TruChainError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: payout target chosen by the admin; only receives lamports.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, lamports: u64) -> Result<()> {
+    let info = ctx.accounts.config.to_account_info();
+    let floor = Rent::get()?.minimum_balance(info.data_len());
+    let available = info.lamports().saturating_sub(floor);
+    if lamports == 0 || lamports > available {
+        return err!(TruChainError::InsufficientTreasury);
+    }
+
+    ctx.accounts.config.sub_lamports(lamports)?;
+    ctx.accounts.recipient.add_lamports(lamports)?;
+
+    emit!(TreasuryWithdrawn {
+        recipient: ctx.accounts.recipient.key(),
+        lamports,
+    });
+
+    Ok(())
+}
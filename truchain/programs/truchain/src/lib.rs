@@ -1,39 +1,463 @@
+// register_official takes eight arguments, and the `cpi` client that
+// #[program] generates for it can't carry a per-function allow.
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod events;
+pub mod utils;
+
+// The clock override must never ship; only localnet builds may enable it.
+#[cfg(all(feature = "test-clock", not(feature = "localnet")))]
+compile_error!("the `test-clock` feature is only allowed together with `localnet`");
 
 use instructions::*;
 
+// One program ID per cluster, picked by cargo feature (`localnet` by default;
+// deploys build with `--no-default-features --features devnet`). Everything
+// that derives PDAs goes through crate::ID, so it follows along.
+#[cfg(any(
+    all(feature = "localnet", feature = "devnet"),
+    all(feature = "localnet", feature = "mainnet"),
+    all(feature = "devnet", feature = "mainnet"),
+))]
+compile_error!("enable exactly one of the `localnet`, `devnet`, `mainnet` features");
+
+#[cfg(not(any(feature = "localnet", feature = "devnet", feature = "mainnet")))]
+compile_error!("a cluster feature is required: `localnet`, `devnet` or `mainnet`");
+
+// keypair: tests/fixtures/truchain-localnet-keypair.json
+#[cfg(feature = "localnet")]
+declare_id!("2cpJRJjGuSC7DTVM1mAbPRjc79whRBiNqnBSCFz69qWh");
+
+#[cfg(feature = "devnet")]
 declare_id!("FGkp4CpRBNDQz2h5idbhbX7cHkbggpsUsF7enLiQE2nT");
 
+// No mainnet keypair exists yet. Refuse to build rather than ship a binary
+// that carries another cluster's ID; add its declare_id! here once it does.
+#[cfg(feature = "mainnet")]
+compile_error!("no mainnet program ID has been assigned yet");
+
 #[program]
 pub mod truchain {
     use super::*;
 
+    pub fn initialize_config(ctx: Context<InitializeConfig>, max_votes: u8) -> Result<()> {
+        initialize_config::handler(ctx, max_votes)
+    }
+
+    pub fn update_max_votes(ctx: Context<UpdateMaxVotes>, max_votes: u8) -> Result<()> {
+        update_max_votes::handler(ctx, max_votes)
+    }
+
+    pub fn update_admin(ctx: Context<UpdateAdmin>, new_admin: Pubkey) -> Result<()> {
+        update_admin::handler(ctx, new_admin)
+    }
+
     pub fn register_official(
         ctx: Context<RegisterOfficial>,
         official_id: u64,
         name: String,
         authority: Pubkey,
-        endorsers: [Pubkey; 3],
+        endorsers: Vec<Pubkey>,
+        threshold: u8,
+        rationale_hash: [u8; 32],
+        rationale_cid: String,
     ) -> Result<()> {
-        register_official::handler(ctx, official_id, name, authority, endorsers)
+        register_official::handler(
+            ctx,
+            official_id,
+            name,
+            authority,
+            endorsers,
+            threshold,
+            rationale_hash,
+            rationale_cid,
+        )
     }
 
     pub fn register_video(
         ctx: Context<RegisterVideo>,
         video_hash: [u8; 32],
         ipfs_cid: String,
+        visibility: u8,
+        external_ref: Option<String>,
+    ) -> Result<()> {
+        register_video::handler(ctx, video_hash, ipfs_cid, visibility, external_ref)
+    }
+
+    pub fn authorize_hashes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AuthorizeHashes<'info>>,
+        hashes: Vec<[u8; 32]>,
     ) -> Result<()> {
-        register_video::handler(ctx, video_hash, ipfs_cid)
+        authorize_hashes::handler(ctx, hashes)
+    }
+
+    pub fn register_authorized_video(
+        ctx: Context<RegisterAuthorizedVideo>,
+        video_hash: [u8; 32],
+        ipfs_cid: String,
+    ) -> Result<()> {
+        register_authorized_video::handler(ctx, video_hash, ipfs_cid)
     }
 
     pub fn endorse_video(
         ctx: Context<EndorseVideo>,
         is_authentic: bool,
+        issue_receipt: bool,
+        classification: Option<u8>,
+    ) -> Result<()> {
+        endorse_video::handler(ctx, is_authentic, issue_receipt, classification)
+    }
+
+    pub fn change_vote(ctx: Context<ChangeVote>, is_authentic: bool) -> Result<()> {
+        change_vote::handler(ctx, is_authentic)
+    }
+
+    pub fn revoke_video(ctx: Context<RevokeVideo>) -> Result<()> {
+        revoke_video::handler(ctx)
+    }
+
+    pub fn finalize_vote_receipt(ctx: Context<FinalizeVoteReceipt>) -> Result<()> {
+        finalize_vote_receipt::handler(ctx)
+    }
+
+    pub fn close_vote_receipt(ctx: Context<CloseVoteReceipt>) -> Result<()> {
+        close_vote_receipt::handler(ctx)
+    }
+
+    pub fn add_mirror(ctx: Context<AddMirror>, pointer_kind: u8, uri: String) -> Result<()> {
+        add_mirror::handler(ctx, pointer_kind, uri)
+    }
+
+    pub fn remove_mirror(ctx: Context<RemoveMirror>, index: u8) -> Result<()> {
+        remove_mirror::handler(ctx, index)
+    }
+
+    pub fn file_takedown(
+        ctx: Context<FileTakedown>,
+        requester_hash: [u8; 32],
+        reason_code: u16,
+    ) -> Result<()> {
+        file_takedown::handler(ctx, requester_hash, reason_code)
+    }
+
+    pub fn lift_takedown(ctx: Context<LiftTakedown>) -> Result<()> {
+        lift_takedown::handler(ctx)
+    }
+
+    pub fn file_counter_claim(
+        ctx: Context<FileCounterClaim>,
+        claimed_hash: [u8; 32],
+        evidence_cid: String,
+    ) -> Result<()> {
+        file_counter_claim::handler(ctx, claimed_hash, evidence_cid)
+    }
+
+    pub fn resolve_counter_claim(ctx: Context<ResolveCounterClaim>, valid: bool) -> Result<()> {
+        resolve_counter_claim::handler(ctx, valid)
+    }
+
+    pub fn unfreeze_video(ctx: Context<UnfreezeVideo>) -> Result<()> {
+        unfreeze_video::handler(ctx)
+    }
+
+    pub fn link_same_event(ctx: Context<LinkSameEvent>, other_video: Pubkey) -> Result<()> {
+        link_same_event::handler(ctx, other_video)
+    }
+
+    pub fn set_endorser_cosigner(
+        ctx: Context<SetEndorserCosigner>,
+        slot_index: u8,
+        cosigner: Option<Pubkey>,
+    ) -> Result<()> {
+        set_endorser_cosigner::handler(ctx, slot_index, cosigner)
+    }
+
+    pub fn claim_handle(ctx: Context<ClaimHandle>, handle: String) -> Result<()> {
+        claim_handle::handler(ctx, handle)
+    }
+
+    pub fn reassign_handle(ctx: Context<ReassignHandle>) -> Result<()> {
+        reassign_handle::handler(ctx)
+    }
+
+    pub fn co_attest(ctx: Context<CoAttest>) -> Result<()> {
+        co_attest::handler(ctx)
+    }
+
+    pub fn revoke_co_attestation(ctx: Context<RevokeCoAttestation>) -> Result<()> {
+        revoke_co_attestation::handler(ctx)
+    }
+
+    pub fn set_registration_bond(ctx: Context<SetRegistrationBond>, lamports: u64) -> Result<()> {
+        set_registration_bond::handler(ctx, lamports)
+    }
+
+    pub fn reclaim_bond(ctx: Context<ReclaimBond>) -> Result<()> {
+        reclaim_bond::handler(ctx)
+    }
+
+    pub fn slash_bond(ctx: Context<SlashBond>) -> Result<()> {
+        slash_bond::handler(ctx)
+    }
+
+    pub fn set_integrity_params(
+        ctx: Context<SetIntegrityParams>,
+        dispute_weight_bps: u16,
+        alert_delta: u8,
+    ) -> Result<()> {
+        set_integrity_params::handler(ctx, dispute_weight_bps, alert_delta)
+    }
+
+    pub fn require_verified(
+        ctx: Context<RequireVerified>,
+        max_age_secs: i64,
+        required_status: u8,
+    ) -> Result<()> {
+        require_verified::handler(ctx, max_age_secs, required_status)
+    }
+
+    pub fn create_cursor(ctx: Context<CreateCursor>, label_hash: [u8; 32]) -> Result<()> {
+        create_cursor::handler(ctx, label_hash)
+    }
+
+    pub fn bump_cursor(ctx: Context<BumpCursor>, new_sequence: u64) -> Result<()> {
+        bump_cursor::handler(ctx, new_sequence)
+    }
+
+    pub fn bind_external_key(
+        ctx: Context<BindExternalKey>,
+        key_kind: u8,
+        fingerprint: [u8; 32],
+        with_proof: bool,
+    ) -> Result<()> {
+        bind_external_key::handler(ctx, key_kind, fingerprint, with_proof)
+    }
+
+    pub fn attest_domain(ctx: Context<AttestDomain>, domain: String) -> Result<()> {
+        attest_domain::handler(ctx, domain)
+    }
+
+    pub fn revoke_domain_attestation(ctx: Context<RevokeDomainAttestation>) -> Result<()> {
+        revoke_domain_attestation::handler(ctx)
+    }
+
+    pub fn attest_social(ctx: Context<AttestSocial>, platform: u8, handle: String) -> Result<()> {
+        attest_social::handler(ctx, platform, handle)
+    }
+
+    pub fn revoke_social(ctx: Context<RevokeSocial>) -> Result<()> {
+        revoke_social::handler(ctx)
+    }
+
+    pub fn schedule_rotation(
+        ctx: Context<ScheduleRotation>,
+        effective_at: i64,
+        new_endorsers: Vec<Pubkey>,
+        rationale_hash: [u8; 32],
+        rationale_cid: String,
+    ) -> Result<()> {
+        schedule_rotation::handler(ctx, effective_at, new_endorsers, rationale_hash, rationale_cid)
+    }
+
+    pub fn apply_scheduled_rotation<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplyScheduledRotation<'info>>,
+    ) -> Result<()> {
+        apply_scheduled_rotation::handler(ctx)
+    }
+
+    pub fn link_sns_domain(ctx: Context<LinkSnsDomain>, name_account: Pubkey) -> Result<()> {
+        link_sns_domain::handler(ctx, name_account)
+    }
+
+    pub fn unlink_sns_domain(ctx: Context<UnlinkSnsDomain>) -> Result<()> {
+        unlink_sns_domain::handler(ctx)
+    }
+
+    pub fn invalidate_sns_link(ctx: Context<InvalidateSnsLink>) -> Result<()> {
+        invalidate_sns_link::handler(ctx)
+    }
+
+    pub fn verify_bundle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyBundle<'info>>,
+        hashes: Vec<[u8; 32]>,
+    ) -> Result<BundleResult> {
+        verify_bundle::handler(ctx, hashes)
+    }
+
+    pub fn set_snapshotter(ctx: Context<SetSnapshotter>, snapshotter: Pubkey) -> Result<()> {
+        set_snapshotter::handler(ctx, snapshotter)
+    }
+
+    pub fn publish_snapshot(
+        ctx: Context<PublishSnapshot>,
+        merkle_root: [u8; 32],
+        video_count: u64,
+    ) -> Result<()> {
+        publish_snapshot::handler(ctx, merkle_root, video_count)
+    }
+
+    pub fn set_close_authority(ctx: Context<SetCloseAuthority>, close_authority: Pubkey) -> Result<()> {
+        set_close_authority::handler(ctx, close_authority)
+    }
+
+    pub fn set_standby_endorser(ctx: Context<SetStandbyEndorser>, standby: Pubkey) -> Result<()> {
+        set_standby_endorser::handler(ctx, standby)
+    }
+
+    pub fn set_standby_activation_secs(ctx: Context<SetStandbyActivationSecs>, secs: i64) -> Result<()> {
+        set_standby_activation_secs::handler(ctx, secs)
+    }
+
+    pub fn activate_standby(ctx: Context<ActivateStandby>) -> Result<()> {
+        activate_standby::handler(ctx)
+    }
+
+    pub fn emit_current_state(ctx: Context<EmitCurrentState>) -> Result<()> {
+        emit_current_state::handler(ctx)
+    }
+
+    pub fn register_provider(
+        ctx: Context<RegisterProvider>,
+        name: String,
+        provider_key: Pubkey,
+        endpoint_hash: [u8; 32],
+    ) -> Result<()> {
+        register_provider::handler(ctx, name, provider_key, endpoint_hash)
+    }
+
+    pub fn revoke_provider(ctx: Context<RevokeProvider>) -> Result<()> {
+        revoke_provider::handler(ctx)
+    }
+
+    pub fn attest_pin(ctx: Context<AttestPin>, expires_at: i64) -> Result<()> {
+        attest_pin::handler(ctx, expires_at)
+    }
+
+    pub fn collect_pin_receipt(ctx: Context<CollectPinReceipt>) -> Result<()> {
+        collect_pin_receipt::handler(ctx)
+    }
+
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        heartbeat::handler(ctx)
+    }
+
+    pub fn set_liveness_window(ctx: Context<SetLivenessWindow>, secs: i64) -> Result<()> {
+        set_liveness_window::handler(ctx, secs)
+    }
+
+    pub fn mark_endorser_inactive(ctx: Context<MarkEndorserLiveness>, slot_index: u8) -> Result<()> {
+        mark_endorser_liveness::mark_inactive(ctx, slot_index)
+    }
+
+    pub fn mark_endorser_active(ctx: Context<MarkEndorserLiveness>, slot_index: u8) -> Result<()> {
+        mark_endorser_liveness::mark_active(ctx, slot_index)
+    }
+
+    pub fn register_broadcast_attestor(
+        ctx: Context<RegisterBroadcastAttestor>,
+        attestor: Pubkey,
+    ) -> Result<()> {
+        register_broadcast_attestor::handler(ctx, attestor)
+    }
+
+    pub fn remove_broadcast_attestor(ctx: Context<RemoveBroadcastAttestor>) -> Result<()> {
+        remove_broadcast_attestor::handler(ctx)
+    }
+
+    pub fn attest_broadcast(
+        ctx: Context<AttestBroadcast>,
+        broadcast_time: i64,
+        channel_hash: [u8; 32],
+    ) -> Result<()> {
+        attest_broadcast::handler(ctx, broadcast_time, channel_hash)
+    }
+
+    pub fn set_clock_override(ctx: Context<SetClockOverride>, timestamp: i64) -> Result<()> {
+        set_clock_override::handler(ctx, timestamp)
+    }
+
+    pub fn list_video(ctx: Context<ListVideo>) -> Result<()> {
+        list_video::handler(ctx)
+    }
+
+    pub fn set_require_endorser_rationale(
+        ctx: Context<SetRequireEndorserRationale>,
+        required: bool,
+    ) -> Result<()> {
+        set_require_endorser_rationale::handler(ctx, required)
+    }
+
+    pub fn append_correction_notice(
+        ctx: Context<AppendCorrectionNotice>,
+        notice_hash: [u8; 32],
+        notice_cid: String,
+    ) -> Result<()> {
+        append_correction_notice::handler(ctx, notice_hash, notice_cid)
+    }
+
+    pub fn set_recovery_window(ctx: Context<SetRecoveryWindow>, secs: i64) -> Result<()> {
+        set_recovery_window::handler(ctx, secs)
+    }
+
+    pub fn propose_authority_recovery(
+        ctx: Context<ProposeAuthorityRecovery>,
+        new_authority: Pubkey,
     ) -> Result<()> {
-        endorse_video::handler(ctx, is_authentic)
+        propose_authority_recovery::handler(ctx, new_authority)
+    }
+
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+        approve_recovery::handler(ctx)
+    }
+
+    pub fn execute_recovery(ctx: Context<ExecuteRecovery>) -> Result<()> {
+        execute_recovery::handler(ctx)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        cancel_recovery::handler(ctx)
+    }
+
+    pub fn open_status_index(ctx: Context<OpenStatusIndex>, status: u8, page: u32) -> Result<()> {
+        open_status_index::handler(ctx, status, page)
+    }
+
+    pub fn set_threshold_proposal_window(
+        ctx: Context<SetThresholdProposalWindow>,
+        secs: i64,
+    ) -> Result<()> {
+        set_threshold_proposal_window::handler(ctx, secs)
+    }
+
+    pub fn propose_threshold_change(ctx: Context<ProposeThresholdChange>, new_threshold: u8) -> Result<()> {
+        propose_threshold_change::handler(ctx, new_threshold)
+    }
+
+    pub fn approve_threshold_change(ctx: Context<ApproveThresholdChange>) -> Result<()> {
+        approve_threshold_change::handler(ctx)
+    }
+
+    pub fn set_program_version(
+        ctx: Context<SetProgramVersion>,
+        major: u16,
+        minor: u16,
+        patch: u16,
+        min_supported_account_version: u8,
+        changelog_hash: [u8; 32],
+    ) -> Result<()> {
+        set_program_version::handler(ctx, major, minor, patch, min_supported_account_version, changelog_hash)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, lamports: u64) -> Result<()> {
+        withdraw_treasury::handler(ctx, lamports)
+    }
+
+    pub fn set_bond_settle_delay(ctx: Context<SetBondSettleDelay>, secs: i64) -> Result<()> {
+        set_bond_settle_delay::handler(ctx, secs)
     }
 }
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+// Max broadcast attestations per video.
+pub const MAX_BROADCAST_ATTESTATIONS: usize = 2;
+
+// Accredited key allowed to attest broadcast times, maintained by the admin.
+// PDA seeded by (b"broadcast_attestor", attestor).
+#[account]
+pub struct BroadcastAttestor {
+    pub attestor: Pubkey,   // attesting key
+    pub added_at: i64,      // unix timestamp
+    pub bump: u8,           // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 attestor
+// 8  added_at
+// 1  bump
+pub const BROADCAST_ATTESTOR_SIZE: usize = 8 + 32 + 8 + 1;
+
+// When and where a video aired, per accredited attestors. Entries are
+// append-only. Sidecar PDA seeded by (b"broadcast", video).
+#[account]
+pub struct BroadcastAttestations {
+    pub video: Pubkey,                        // Video these attestations belong to
+    pub entries: Vec<BroadcastAttestation>,   // up to MAX_BROADCAST_ATTESTATIONS
+    pub bump: u8,                             // PDA bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BroadcastAttestation {
+    pub attestor: Pubkey,         // BroadcastAttestor key that signed
+    pub broadcast_time: i64,      // unix timestamp the footage aired
+    pub channel_hash: [u8; 32],   // hash of the channel identifier
+    pub attested_at: i64,         // unix timestamp
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 video
+// 4  entries vec length prefix (u32)
+// 80*MAX_BROADCAST_ATTESTATIONS entries (32 attestor + 8 broadcast_time + 32 channel_hash + 8 attested_at)
+// 1  bump
+pub const BROADCAST_ATTESTATIONS_SIZE: usize = 8 + 32 + 4 + 80 * MAX_BROADCAST_ATTESTATIONS + 1;
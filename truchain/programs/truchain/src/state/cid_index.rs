@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+// Per-official record of which video hash a CID was registered with, so
+// the same CID can't later be claimed for different content.
+// PDA seeded by (b"cid", official, sha256(cid)).
+#[account]
+pub struct CidIndex {
+    pub official: Pubkey,        // Official that registered the CID
+    pub cid_hash: [u8; 32],      // sha256 of the CID string
+    pub video_hash: [u8; 32],    // content hash the CID was registered with
+    pub bump: u8,                // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 official
+// 32 cid_hash
+// 32 video_hash
+// 1  bump
+pub const CID_INDEX_SIZE: usize = 8 + 32 + 32 + 32 + 1;
+
+pub fn cid_hash(ipfs_cid: &str) -> [u8; 32] {
+    hash(ipfs_cid.as_bytes()).to_bytes()
+}
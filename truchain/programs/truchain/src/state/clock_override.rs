@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+// Localnet-only stand-in for the Clock sysvar's unix_timestamp; only read
+// (or creatable) in `test-clock` builds. PDA seeded by (b"clock_override").
+#[account]
+pub struct ClockOverride {
+    pub unix_timestamp: i64,   // 0 = disabled, fall back to the sysvar
+    pub bump: u8,              // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8 discriminator
+// 8 unix_timestamp
+// 1 bump
+pub const CLOCK_OVERRIDE_SIZE: usize = 8 + 8 + 1;
+
+pub fn clock_override_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"clock_override"], &crate::ID).0
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+// Another official publicly vouching for a peer's Authentic video.
+// PDA seeded by (b"co_attest", video, attesting official). Never affects status.
+#[account]
+pub struct CoAttestation {
+    pub video: Pubkey,                // attested Video
+    pub attesting_official: Pubkey,   // Official doing the vouching
+    pub authority: Pubkey,            // that official's authority at the time; paid the rent, refunds go here
+    pub attested_at: i64,             // unix timestamp
+    pub bump: u8,                     // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 video
+// 32 attesting_official
+// 32 authority
+// 8  attested_at
+// 1  bump
+pub const CO_ATTESTATION_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1;
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+// Upper bound for config.max_votes (and therefore for any endorser panel).
+pub const MAX_VOTES_LIMIT: u8 = 10;
+
+// Global, per-deployment settings. Singleton PDA seeded by b"config".
+#[account]
+pub struct Config {
+    pub admin: Pubkey,                      // bootstrap authority for the deployment
+    pub max_votes: u8,                      // cap on endorsers per official / votes per video
+    pub registration_bond_lamports: u64,    // escrowed per video, lost if Disputed
+    pub integrity_dispute_weight_bps: u16,  // score cost of a 100% dispute rate (10_000 = 100 pts)
+    pub integrity_alert_delta: u8,          // score moves larger than this emit an alert event
+    pub snapshotter: Pubkey,                // key allowed to publish per-official snapshots
+    pub close_authority: Pubkey,            // ops key that may run close/GC instructions
+    pub standby_activation_secs: i64,       // video age after which a standby can be activated
+    pub liveness_window_secs: i64,          // max heartbeat age before a panelist can be marked inactive
+    pub require_endorser_rationale: bool,   // officials must record why their panel was chosen
+    pub recovery_window_secs: i64,          // how long an authority-recovery proposal stays open
+    pub threshold_proposal_secs: i64,       // how long a threshold change stays open for approvals
+    pub bond_settle_delay_secs: i64,        // time after finalization before a bond can be reclaimed/slashed
+    pub bump: u8,                           // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 admin
+// 1  max_votes
+// 8  registration_bond_lamports
+// 2  integrity_dispute_weight_bps
+// 1  integrity_alert_delta
+// 32 snapshotter
+// 32 close_authority
+// 8  standby_activation_secs
+// 8  liveness_window_secs
+// 1  require_endorser_rationale
+// 8  recovery_window_secs
+// 8  threshold_proposal_secs
+// 8  bond_settle_delay_secs
+// 1  bump
+pub const CONFIG_SIZE: usize = 8 + 32 + 1 + 8 + 2 + 1 + 32 + 32 + 8 + 8 + 1 + 8 + 8 + 8 + 1;
+
+// Defaults written by initialize_config.
+pub const DEFAULT_INTEGRITY_DISPUTE_WEIGHT_BPS: u16 = 10_000;
+pub const DEFAULT_INTEGRITY_ALERT_DELTA: u8 = 10;
+pub const DEFAULT_STANDBY_ACTIVATION_SECS: i64 = 3 * 24 * 60 * 60;
+pub const DEFAULT_LIVENESS_WINDOW_SECS: i64 = 7 * 24 * 60 * 60;
+pub const DEFAULT_RECOVERY_WINDOW_SECS: i64 = 3 * 24 * 60 * 60;
+pub const DEFAULT_THRESHOLD_PROPOSAL_SECS: i64 = 7 * 24 * 60 * 60;
+pub const DEFAULT_BOND_SETTLE_DELAY_SECS: i64 = 3 * 24 * 60 * 60;
+
+impl Config {
+    pub fn validate_max_votes(max_votes: u8) -> bool {
+        (1..=MAX_VOTES_LIMIT).contains(&max_votes)
+    }
+
+    // Who may close a rent-bearing account: its original payer, the admin,
+    // or the delegated close authority. Rent always goes back to the payer.
+    pub fn can_close(&self, closer: &Pubkey, rent_payer: &Pubkey) -> bool {
+        closer == rent_payer || *closer == self.admin || *closer == self.close_authority
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+// Max correction notices per video.
+pub const MAX_CORRECTION_NOTICES: usize = 2;
+
+// Context the official appended after an Authentic verdict, without touching
+// the verified record. Entries are append-only. Sidecar PDA seeded by
+// (b"notices", video).
+#[account]
+pub struct CorrectionNotices {
+    pub video: Pubkey,                     // Video these notices belong to
+    pub notices: Vec<CorrectionNotice>,    // up to MAX_CORRECTION_NOTICES
+    pub bump: u8,                          // PDA bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CorrectionNotice {
+    pub notice_hash: [u8; 32],  // hash of the notice text
+    pub notice_cid: [u8; 64],   // IPFS CID of the notice, padded
+    pub appended_at: i64,       // unix timestamp
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 video
+// 4  notices vec length prefix (u32)
+// 104*MAX_CORRECTION_NOTICES notices (32 notice_hash + 64 notice_cid + 8 appended_at)
+// 1  bump
+pub const CORRECTION_NOTICES_SIZE: usize = 8 + 32 + 4 + 104 * MAX_CORRECTION_NOTICES + 1;
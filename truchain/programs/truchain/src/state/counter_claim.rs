@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+// Bond posted with every counter-claim; returned if upheld, forfeited otherwise.
+pub const COUNTER_CLAIM_BOND_LAMPORTS: u64 = 10_000_000; // 0.01 SOL
+
+// A competing hash claim against a registered video.
+// PDA seeded by (b"counter_claim", video, claimant): one open claim per
+// claimant per video. Closed back to the claimant on resolution.
+#[account]
+pub struct CounterClaim {
+    pub video: Pubkey,            // Video being challenged
+    pub claimant: Pubkey,         // who filed (and posted the bond)
+    pub claimed_hash: [u8; 32],   // hash the claimant believes is correct
+    pub evidence_cid: [u8; 64],   // IPFS CID of supporting evidence, padded
+    pub bond_lamports: u64,       // bond held on top of rent
+    pub filed_at: i64,            // unix timestamp
+    pub bump: u8,                 // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 video
+// 32 claimant
+// 32 claimed_hash
+// 64 evidence_cid
+// 8  bond_lamports
+// 8  filed_at
+// 1  bump
+pub const COUNTER_CLAIM_SIZE: usize = 8 + 32 + 32 + 32 + 64 + 8 + 8 + 1;
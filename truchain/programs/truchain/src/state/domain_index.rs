@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+pub const MAX_DOMAIN_LEN: usize = 64;
+
+// Reverse lookup from an attested DNS name to the one Official it belongs to.
+// PDA seeded by (b"domain", sha256(domain)) since domains can exceed the
+// 32-byte seed limit.
+#[account]
+pub struct DomainIndex {
+    pub domain_hash: [u8; 32],   // sha256 of the normalized domain
+    pub official: Pubkey,        // Official the domain is attested for
+    pub bump: u8,                // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 domain_hash
+// 32 official
+// 1  bump
+pub const DOMAIN_INDEX_SIZE: usize = 8 + 32 + 32 + 1;
+
+pub fn domain_hash(domain: &str) -> [u8; 32] {
+    hash(domain.as_bytes()).to_bytes()
+}
+
+// Bare lowercase hostnames only: no scheme, port or path. Labels are
+// a-z, 0-9 and inner hyphens, at least two labels, at most 64 bytes.
+pub fn validate_domain(domain: &str) -> bool {
+    if domain.is_empty() || domain.len() > MAX_DOMAIN_LEN {
+        return false;
+    }
+    let labels: Vec<&str> = domain.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        })
+}
+
+pub fn pad_domain(domain: &str) -> [u8; MAX_DOMAIN_LEN] {
+    let mut padded = [0u8; MAX_DOMAIN_LEN];
+    padded[..domain.len()].copy_from_slice(domain.as_bytes());
+    padded
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+// Per-endorser liveness record, shared across every panel the key sits on.
+// PDA seeded by (b"endorser", endorser).
+#[account]
+pub struct EndorserProfile {
+    pub endorser: Pubkey,          // endorser key
+    pub last_heartbeat_at: i64,    // unix timestamp of the latest heartbeat
+    pub bump: u8,                  // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 endorser
+// 8  last_heartbeat_at
+// 1  bump
+pub const ENDORSER_PROFILE_SIZE: usize = 8 + 32 + 8 + 1;
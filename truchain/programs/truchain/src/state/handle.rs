@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+pub const MIN_HANDLE_LEN: usize = 3;
+pub const MAX_HANDLE_LEN: usize = 32;
+
+// Handles nobody can claim.
+pub const RESERVED_HANDLES: [&str; 6] = ["admin", "official", "root", "support", "system", "truchain"];
+
+// Human-readable name for an official (e.g. "health-ministry-ke").
+// PDA seeded by (b"handle", handle bytes), so each handle exists at most once.
+#[account]
+pub struct Handle {
+    pub handle: [u8; 32],   // normalized handle, padded
+    pub official: Pubkey,   // Official the handle points to
+    pub bump: u8,           // PDA bump
+}
+
+impl Handle {
+    pub fn handle_str(&self) -> &str {
+        let len = self.handle.iter().position(|&b| b == 0).unwrap_or(self.handle.len());
+        // only ever written from a validated ASCII handle
+        core::str::from_utf8(&self.handle[..len]).unwrap_or_default()
+    }
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 handle
+// 32 official
+// 1  bump
+pub const HANDLE_SIZE: usize = 8 + 32 + 32 + 1;
+
+// Handles must already be in normalized form (clients lowercase before
+// deriving the PDA): a-z, 0-9 and inner hyphens, 3..=32 chars, not reserved.
+pub fn validate_handle(handle: &str) -> bool {
+    let bytes = handle.as_bytes();
+    (MIN_HANDLE_LEN..=MAX_HANDLE_LEN).contains(&bytes.len())
+        && bytes
+            .iter()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'-')
+        && !handle.starts_with('-')
+        && !handle.ends_with('-')
+        && !RESERVED_HANDLES.contains(&handle)
+}
+
+pub fn pad_handle(handle: &str) -> [u8; 32] {
+    let mut padded = [0u8; 32];
+    padded[..handle.len()].copy_from_slice(handle.as_bytes());
+    padded
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+// Max hashes authorized per authorize_hashes call (one PDA each).
+pub const MAX_AUTHORIZATIONS_PER_TX: usize = 10;
+
+// Single-use permission for anyone to register `video_hash` under `official`.
+// PDA seeded by (official, video_hash); closed back to the authority on use.
+#[account]
+pub struct HashAuthorization {
+    pub official: Pubkey,       // official the hash may be registered under
+    pub video_hash: [u8; 32],   // pre-approved SHA-256
+    pub authority: Pubkey,      // authority that approved it (rent refund target)
+    pub bump: u8,               // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 official
+// 32 video_hash
+// 32 authority
+// 1  bump
+pub const HASH_AUTHORIZATION_SIZE: usize = 8 + 32 + 32 + 32 + 1;
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+// Shared "processed up to sequence N" marker for off-chain indexers.
+// PDA seeded by (b"cursor", label_hash) where label_hash is the hash of an
+// indexer-chosen label; only the creating key may advance it.
+#[account]
+pub struct IndexerCursor {
+    pub label_hash: [u8; 32],   // hash of the indexer's label
+    pub owner: Pubkey,          // key allowed to advance the cursor
+    pub last_sequence: u64,     // highest sequence processed so far
+    pub updated_at: i64,        // unix timestamp of the last update
+    pub bump: u8,               // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 label_hash
+// 32 owner
+// 8  last_sequence
+// 8  updated_at
+// 1  bump
+pub const INDEXER_CURSOR_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
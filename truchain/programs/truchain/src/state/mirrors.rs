@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+// Max alternate storage pointers per video.
+pub const MAX_MIRRORS: usize = 3;
+
+// Max bytes in a single mirror URI.
+pub const MAX_MIRROR_URI_LEN: usize = 128;
+
+// Alternate retrieval locations for a video's bytes. The Video's ipfs_cid
+// stays authoritative for the hash commitment; these are just fallbacks.
+// Sidecar PDA seeded by (b"mirrors", video) so the Video layout is unchanged.
+#[account]
+pub struct Mirrors {
+    pub video: Pubkey,            // Video these mirrors belong to
+    pub mirrors: Vec<Mirror>,     // up to MAX_MIRRORS entries
+    pub bump: u8,                 // PDA bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Mirror {
+    pub kind: u8,                          // MirrorKind discriminant
+    pub uri: [u8; MAX_MIRROR_URI_LEN],     // UTF-8 bytes, padded
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MirrorKind {
+    Ipfs = 0,     // CID pinned on another IPFS service
+    Arweave = 1,  // Arweave transaction id
+    Https = 2,    // plain https:// URL
+}
+
+impl MirrorKind {
+    pub fn from_u8(kind: u8) -> Option<Self> {
+        match kind {
+            0 => Some(Self::Ipfs),
+            1 => Some(Self::Arweave),
+            2 => Some(Self::Https),
+            _ => None,
+        }
+    }
+
+    // Per-kind format check on the pointer string.
+    pub fn validate(&self, uri: &str) -> bool {
+        match self {
+            Self::Ipfs => {
+                !uri.is_empty() && uri.len() <= 64 && uri.bytes().all(|b| b.is_ascii_alphanumeric())
+            }
+            // Arweave tx ids are 43 chars of base64url
+            Self::Arweave => {
+                uri.len() == 43
+                    && uri.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+            }
+            Self::Https => {
+                uri.len() > "https://".len()
+                    && uri.len() <= MAX_MIRROR_URI_LEN
+                    && uri.starts_with("https://")
+                    && uri.bytes().all(|b| b.is_ascii_graphic())
+            }
+        }
+    }
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 video
+// 4  mirrors vec length prefix (u32)
+// 3 * (1 + 128) mirrors (kind + uri)
+// 1  bump
+pub const MIRRORS_SIZE: usize = 8 + 32 + 4 + MAX_MIRRORS * (1 + MAX_MIRROR_URI_LEN) + 1;
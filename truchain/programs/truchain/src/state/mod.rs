@@ -1,5 +1,47 @@
+pub mod config;
+pub mod broadcast;
+pub mod co_attestation;
+pub mod correction_notice;
+pub mod cid_index;
+pub mod clock_override;
+pub mod counter_claim;
+pub mod endorser_profile;
+pub mod domain_index;
+pub mod hash_authorization;
+pub mod handle;
+pub mod indexer_cursor;
+pub mod mirrors;
 pub mod official;
+pub mod pin_provider;
+pub mod program_version;
+pub mod recovery;
+pub mod ref_index;
+pub mod social_index;
+pub mod snapshot;
+pub mod status_index;
 pub mod video;
+pub mod vote_receipt;
 
+pub use config::*;
+pub use broadcast::*;
+pub use co_attestation::*;
+pub use correction_notice::*;
+pub use cid_index::*;
+pub use clock_override::*;
+pub use counter_claim::*;
+pub use endorser_profile::*;
+pub use domain_index::*;
+pub use hash_authorization::*;
+pub use handle::*;
+pub use indexer_cursor::*;
+pub use mirrors::*;
 pub use official::*;
+pub use pin_provider::*;
+pub use program_version::*;
+pub use recovery::*;
+pub use ref_index::*;
+pub use social_index::*;
+pub use snapshot::*;
+pub use status_index::*;
 pub use video::*;
+pub use vote_receipt::*;
@@ -1,13 +1,41 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::TruChainError;
+use crate::state::{SocialAttestation, MAX_SOCIAL_ATTESTATIONS, SOCIAL_ATTESTATION_SIZE};
+
 // The on-chain identity for a single official/source.
 #[account]
 pub struct Official {
-    pub official_id: u64,        // e.g. 1, 2, 3...
-    pub name: [u8; 32],          // UTF-8 bytes, padded/truncated
-    pub authority: Pubkey,       // wallet that can register videos
-    pub endorsers: [Pubkey; 3],  // exactly 3 endorsers
-    pub bump: u8,                // PDA bump
+    pub official_id: u64,                               // e.g. 1, 2, 3...
+    pub name: [u8; 32],                                 // UTF-8 bytes, padded/truncated
+    pub authority: Pubkey,                              // wallet that can register videos
+    pub endorsers: Vec<Pubkey>,                         // 1..=config.max_votes endorsers
+    pub endorser_cosigners: Vec<Option<Pubkey>>,        // per-slot second signer, parallel to endorsers
+    pub handle: [u8; 32],                               // claimed Handle, zeroed when none
+    pub finalized_count: u32,                           // videos that reached Authentic/Disputed
+    pub disputed_count: u32,                            // of which Disputed
+    pub integrity_score: u8,                            // 0..=100, recomputed at each finalization
+    pub external_key_fingerprint: [u8; 32],             // off-chain signing key, zeroed when unbound
+    pub external_key_kind: u8,                          // ExternalKeyKind discriminant, 0 when unbound
+    pub domain: [u8; 64],                               // admin-attested DNS name, zeroed when none
+    pub domain_attested_at: i64,                        // unix timestamp of the attestation
+    pub domain_attested_by: Pubkey,                     // admin that verified the TXT record
+    pub social_attestations: Vec<SocialAttestation>,    // up to MAX_SOCIAL_ATTESTATIONS, one per platform
+    pub scheduled_rotation: Option<ScheduledRotation>,  // pending panel swap, applied by crank
+    pub sns_name_account: Pubkey,                       // linked SNS name account, default when none
+    pub video_count: u64,                               // videos ever registered under this official
+    pub snapshot_epoch: u64,                            // epoch number the next published snapshot gets
+    pub standby_endorser: Pubkey,                       // fills in for a silent panelist, default when none
+    pub inactive_slots: u16,                            // bit i set when panel slot i missed the liveness window
+    pub endorser_rationale_hash: [u8; 32],              // hash of the document explaining the panel choice, zeroed when none
+    pub endorser_rationale_cid: [u8; 64],               // IPFS CID of that document, padded
+    pub min_finalize_secs: u64,                         // fastest registration-to-verdict time, 0 before the first
+    pub max_finalize_secs: u64,                         // slowest registration-to-verdict time
+    pub mean_finalize_millis: u64,                      // mean registration-to-verdict time in milliseconds
+    pub total_finalize_secs: u64,                       // sum behind the mean, over finalized_count videos
+    pub threshold: u8,                                  // matching votes that finalize a video, 1..=endorsers.len()
+    pub pending_threshold: Option<ThresholdProposal>,   // endorser-approved threshold change in flight
+    pub bump: u8,                                       // PDA bump
 }
 
 // Account size calculation (bytes)
@@ -15,6 +43,526 @@ pub struct Official {
 // 8  official_id
 // 32 name
 // 32 authority
-// 32*3 endorsers
+// 4  endorsers vec length prefix (u32)
+// 32*n endorsers
+// 4  endorser_cosigners vec length prefix (u32)
+// 33*n endorser_cosigners (option tag + Pubkey)
+// 32 handle
+// 4  finalized_count
+// 4  disputed_count
+// 1  integrity_score
+// 32 external_key_fingerprint
+// 1  external_key_kind
+// 64 domain
+// 8  domain_attested_at
+// 32 domain_attested_by
+// 4  social_attestations vec length prefix (u32)
+// 41*MAX_SOCIAL_ATTESTATIONS social_attestations
+// 1  scheduled_rotation option tag
+// 8  scheduled_rotation.effective_at
+// 4  scheduled_rotation.new_endorsers vec length prefix (u32)
+// 32*n scheduled_rotation.new_endorsers
+// 32 scheduled_rotation.rationale_hash
+// 64 scheduled_rotation.rationale_cid
+// 32 sns_name_account
+// 8  video_count
+// 8  snapshot_epoch
+// 32 standby_endorser
+// 2  inactive_slots
+// 32 endorser_rationale_hash
+// 64 endorser_rationale_cid
+// 8  min_finalize_secs
+// 8  max_finalize_secs
+// 8  mean_finalize_millis
+// 8  total_finalize_secs
+// 1  threshold
+// 1  pending_threshold option tag
+// 1  pending_threshold.new_threshold
+// 32 pending_threshold.proposed_by
+// 8  pending_threshold.expires_at
+// 4  pending_threshold.approvals vec length prefix (u32)
+// 32*n pending_threshold.approvals
 // 1  bump
-pub const OFFICIAL_SIZE: usize = 8 + 8 + 32 + 32 + 32 * 3 + 1;
+pub const fn official_size(endorser_count: usize) -> usize {
+    8       // disc
+    + 8     // official_id
+    + 32    // name
+    + 32    // authority
+    + 4 + 32 * endorser_count // endorsers
+    + 4 + 33 * endorser_count // endorser_cosigners
+    + 32    // handle
+    + 4     // finalized_count
+    + 4     // disputed_count
+    + 1     // integrity_score
+    + 32    // external_key_fingerprint
+    + 1     // external_key_kind
+    + 64    // domain
+    + 8     // domain_attested_at
+    + 32    // domain_attested_by
+    + 4 + SOCIAL_ATTESTATION_SIZE * MAX_SOCIAL_ATTESTATIONS // social_attestations
+    + 1 + 8 + 4 + 32 * endorser_count + 32 + 64 // scheduled_rotation
+    + 32    // sns_name_account
+    + 8     // video_count
+    + 8     // snapshot_epoch
+    + 32    // standby_endorser
+    + 2     // inactive_slots
+    + 32    // endorser_rationale_hash
+    + 64    // endorser_rationale_cid
+    + 8     // min_finalize_secs
+    + 8     // max_finalize_secs
+    + 8     // mean_finalize_millis
+    + 8     // total_finalize_secs
+    + 1     // threshold
+    + 1 + 1 + 32 + 8 + 4 + 32 * endorser_count // pending_threshold
+    + 1     // bump
+}
+
+pub const MAX_INTEGRITY_SCORE: u8 = 100;
+
+// Threshold change waiting for a majority of the panel to approve it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ThresholdProposal {
+    pub new_threshold: u8,        // 1..=endorsers.len()
+    pub proposed_by: Pubkey,      // authority or admin
+    pub expires_at: i64,          // unix timestamp after which it can't apply
+    pub approvals: Vec<Pubkey>,   // endorsers in favour (at most the panel size)
+}
+
+// Panel that replaces the official's endorsers once effective_at passes.
+// Same length as the current panel so the account never needs a realloc.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ScheduledRotation {
+    pub effective_at: i64,           // unix timestamp the new panel takes over
+    pub new_endorsers: Vec<Pubkey>,  // replacement panel
+    pub rationale_hash: [u8; 32],    // rationale for the new panel, installed with it
+    pub rationale_cid: [u8; 64],     // padded IPFS CID of the rationale
+}
+
+// Panel rules shared by every path that installs endorsers.
+pub fn validate_panel(endorsers: &[Pubkey], max_votes: usize) -> Result<()> {
+    if endorsers.is_empty() || endorsers.len() > max_votes {
+        return err!(TruChainError::InvalidEndorserCount);
+    }
+    if endorsers.iter().any(|e| *e == Pubkey::default()) {
+        return err!(TruChainError::InvalidEndorser);
+    }
+    for (i, endorser) in endorsers.iter().enumerate() {
+        if endorsers[i + 1..].contains(endorser) {
+            return err!(TruChainError::DuplicateEndorsers);
+        }
+    }
+    Ok(())
+}
+
+// Checks an endorser-selection rationale and pads its CID. Hash and CID
+// come as a pair. When the config requires a rationale, it must be present
+// and differ from `previous`, the one recorded for the outgoing panel.
+pub fn validate_rationale(
+    required: bool,
+    hash: &[u8; 32],
+    cid: &str,
+    previous: &[u8; 32],
+) -> Result<[u8; 64]> {
+    let has_hash = *hash != [0u8; 32];
+    if cid.len() > 64 || has_hash == cid.is_empty() {
+        return err!(TruChainError::InvalidEndorserRationale);
+    }
+    if required {
+        if !has_hash {
+            return err!(TruChainError::MissingEndorserRationale);
+        }
+        if hash == previous {
+            return err!(TruChainError::StaleEndorserRationale);
+        }
+    }
+
+    let mut padded = [0u8; 64];
+    padded[..cid.len()].copy_from_slice(cid.as_bytes());
+    Ok(padded)
+}
+
+// Off-chain key an official already signs releases with. The fingerprint
+// field holds the PGP v4 fingerprint (left-aligned, zero padded), the age
+// X25519 recipient key, or the raw minisign Ed25519 public key.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ExternalKeyKind {
+    Pgp = 1,
+    Age = 2,
+    Minisign = 3,
+}
+
+impl ExternalKeyKind {
+    pub fn from_u8(kind: u8) -> Option<Self> {
+        match kind {
+            1 => Some(Self::Pgp),
+            2 => Some(Self::Age),
+            3 => Some(Self::Minisign),
+            _ => None,
+        }
+    }
+
+    // Only minisign keys are plain Ed25519, so only they can be checked
+    // on-chain through the ed25519 program.
+    pub fn supports_onchain_proof(&self) -> bool {
+        matches!(self, Self::Minisign)
+    }
+}
+
+impl Official {
+    pub fn has_handle(&self) -> bool {
+        self.handle != [0u8; 32]
+    }
+
+    // Records a video reaching a terminal vote outcome and recomputes the
+    // integrity score. Returns the previous score.
+    //
+    // score = 100 - dispute_rate * weight, in fixed point:
+    //   dispute_rate_bps = disputed * 10_000 / finalized
+    //   penalty          = dispute_rate_bps * weight_bps / 10_000 / 100  (points)
+    pub fn record_finalization(&mut self, disputed: bool, dispute_weight_bps: u16) -> Result<u8> {
+        self.finalized_count = self
+            .finalized_count
+            .checked_add(1)
+            .ok_or(TruChainError::CounterOverflow)?;
+        if disputed {
+            self.disputed_count = self
+                .disputed_count
+                .checked_add(1)
+                .ok_or(TruChainError::CounterOverflow)?;
+        }
+
+        let old_score = self.integrity_score;
+        self.rescore(dispute_weight_bps);
+
+        Ok(old_score)
+    }
+
+    // Undoes record_finalization and record_finalize_latency for a video
+    // that left its verdict (a changed vote reopened or flipped it).
+    // min/max_finalize_secs are running extremes and can't be unwound;
+    // they keep covering the reverted time. Returns the previous score.
+    pub fn revert_finalization(&mut self, disputed: bool, dispute_weight_bps: u16, latency_secs: u64) -> Result<u8> {
+        self.finalized_count = self
+            .finalized_count
+            .checked_sub(1)
+            .ok_or(TruChainError::CounterOverflow)?;
+        if disputed {
+            self.disputed_count = self
+                .disputed_count
+                .checked_sub(1)
+                .ok_or(TruChainError::CounterOverflow)?;
+        }
+        self.total_finalize_secs = self.total_finalize_secs.saturating_sub(latency_secs);
+        self.mean_finalize_millis = match self.finalized_count {
+            0 => 0,
+            count => self.total_finalize_secs.saturating_mul(1000) / count as u64,
+        };
+
+        let old_score = self.integrity_score;
+        self.rescore(dispute_weight_bps);
+
+        Ok(old_score)
+    }
+
+    fn rescore(&mut self, dispute_weight_bps: u16) {
+        if self.finalized_count == 0 {
+            self.integrity_score = MAX_INTEGRITY_SCORE;
+            return;
+        }
+        let dispute_rate_bps = self.disputed_count as u64 * 10_000 / self.finalized_count as u64;
+        let penalty = dispute_rate_bps * dispute_weight_bps as u64 / 10_000 / 100;
+        self.integrity_score = (MAX_INTEGRITY_SCORE as u64).saturating_sub(penalty) as u8;
+    }
+
+    // Folds one registration-to-verdict time into the latency stats. Call
+    // after record_finalization so finalized_count already includes it.
+    pub fn record_finalize_latency(&mut self, secs: u64) -> Result<()> {
+        self.total_finalize_secs = self
+            .total_finalize_secs
+            .checked_add(secs)
+            .ok_or(TruChainError::CounterOverflow)?;
+        self.min_finalize_secs = if self.finalized_count == 1 {
+            secs
+        } else {
+            self.min_finalize_secs.min(secs)
+        };
+        self.max_finalize_secs = self.max_finalize_secs.max(secs);
+        self.mean_finalize_millis = self
+            .total_finalize_secs
+            .checked_mul(1000)
+            .ok_or(TruChainError::CounterOverflow)?
+            / self.finalized_count as u64;
+
+        Ok(())
+    }
+
+    pub fn has_domain(&self) -> bool {
+        self.domain != [0u8; 64]
+    }
+
+    pub fn social_slot(&self, platform: u8) -> Option<usize> {
+        self.social_attestations.iter().position(|s| s.platform == platform)
+    }
+
+    pub fn has_standby(&self) -> bool {
+        self.standby_endorser != Pubkey::default()
+    }
+
+    pub fn is_slot_inactive(&self, slot: usize) -> bool {
+        self.inactive_slots & (1 << slot) != 0
+    }
+
+    pub fn set_slot_inactive(&mut self, slot: usize, inactive: bool) {
+        if inactive {
+            self.inactive_slots |= 1 << slot;
+        } else {
+            self.inactive_slots &= !(1 << slot);
+        }
+    }
+
+    pub fn endorser_slot(&self, endorser: &Pubkey) -> Option<usize> {
+        self.endorsers.iter().position(|e| e == endorser)
+    }
+
+    // Strict majority of the current panel (2-of-3). Needed for authority
+    // recovery and threshold changes.
+    pub fn panel_majority(&self) -> usize {
+        self.endorsers.len() / 2 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL_WEIGHT: u16 = 10_000;
+
+    fn official() -> Official {
+        Official {
+            official_id: 1,
+            name: [0u8; 32],
+            authority: Pubkey::default(),
+            endorsers: vec![],
+            endorser_cosigners: vec![],
+            handle: [0u8; 32],
+            finalized_count: 0,
+            disputed_count: 0,
+            integrity_score: MAX_INTEGRITY_SCORE,
+            external_key_fingerprint: [0u8; 32],
+            external_key_kind: 0,
+            domain: [0u8; 64],
+            domain_attested_at: 0,
+            domain_attested_by: Pubkey::default(),
+            social_attestations: vec![],
+            scheduled_rotation: None,
+            sns_name_account: Pubkey::default(),
+            video_count: 0,
+            snapshot_epoch: 0,
+            standby_endorser: Pubkey::default(),
+            inactive_slots: 0,
+            endorser_rationale_hash: [0u8; 32],
+            endorser_rationale_cid: [0u8; 64],
+            min_finalize_secs: 0,
+            max_finalize_secs: 0,
+            mean_finalize_millis: 0,
+            total_finalize_secs: 0,
+            threshold: 1,
+            pending_threshold: None,
+            bump: 0,
+        }
+    }
+
+    // the counters and score a verdict touches
+    fn integrity(o: &Official) -> (u32, u32, u8) {
+        (o.finalized_count, o.disputed_count, o.integrity_score)
+    }
+
+    #[test]
+    fn score_rounds_the_penalty_down() {
+        let mut o = official();
+        o.record_finalization(true, FULL_WEIGHT).unwrap();
+        o.record_finalization(false, FULL_WEIGHT).unwrap();
+        o.record_finalization(false, FULL_WEIGHT).unwrap();
+        // 1/3 disputed: 3_333 bps -> 33.33 points -> 33
+        assert_eq!(o.integrity_score, 67);
+
+        o.record_finalization(true, FULL_WEIGHT).unwrap();
+        // 2/4 disputed: 5_000 bps -> exactly 50 points
+        assert_eq!(o.integrity_score, 50);
+
+        let mut o = official();
+        o.record_finalization(true, 5_000).unwrap();
+        o.record_finalization(false, 5_000).unwrap();
+        o.record_finalization(false, 5_000).unwrap();
+        // half weight: 3_333 * 0.5 = 1_666 -> 16.66 points -> 16
+        assert_eq!(o.integrity_score, 84);
+    }
+
+    #[test]
+    fn record_returns_the_previous_score() {
+        let mut o = official();
+        assert_eq!(o.record_finalization(true, FULL_WEIGHT).unwrap(), MAX_INTEGRITY_SCORE);
+        assert_eq!(o.integrity_score, 0);
+        assert_eq!(o.record_finalization(false, FULL_WEIGHT).unwrap(), 0);
+        assert_eq!(o.integrity_score, 50);
+    }
+
+    #[test]
+    fn score_stays_within_bounds_for_any_weight() {
+        let mut o = official();
+        o.record_finalization(true, 0).unwrap();
+        o.record_finalization(true, 0).unwrap();
+        assert_eq!(o.integrity_score, MAX_INTEGRITY_SCORE);
+
+        // a weight above 100% pushes the penalty past 100 points; it saturates
+        let mut o = official();
+        o.record_finalization(true, u16::MAX).unwrap();
+        assert_eq!(o.integrity_score, 0);
+        o.record_finalization(false, u16::MAX).unwrap();
+        // 5_000 bps * 6.5535 = 32_767 -> 327 points -> still 0
+        assert_eq!(o.integrity_score, 0);
+
+        let mut o = official();
+        o.record_finalization(false, u16::MAX).unwrap();
+        assert_eq!(o.integrity_score, MAX_INTEGRITY_SCORE);
+    }
+
+    #[test]
+    fn revert_undoes_record_exactly() {
+        let mut o = official();
+        o.record_finalization(true, FULL_WEIGHT).unwrap();
+        o.record_finalization(false, FULL_WEIGHT).unwrap();
+        o.record_finalization(false, FULL_WEIGHT).unwrap();
+        let before = integrity(&o);
+
+        for disputed in [true, false] {
+            o.record_finalization(disputed, FULL_WEIGHT).unwrap();
+            let recorded = o.integrity_score;
+            assert_eq!(o.revert_finalization(disputed, FULL_WEIGHT, 0).unwrap(), recorded);
+            assert_eq!(integrity(&o), before);
+        }
+    }
+
+    #[test]
+    fn revert_to_no_verdicts_restores_the_full_score() {
+        let mut o = official();
+        o.record_finalization(true, FULL_WEIGHT).unwrap();
+        assert_eq!(o.integrity_score, 0);
+
+        o.revert_finalization(true, FULL_WEIGHT, 0).unwrap();
+        assert_eq!(integrity(&o), (0, 0, MAX_INTEGRITY_SCORE));
+    }
+
+    #[test]
+    fn revert_without_a_recorded_verdict_fails() {
+        let mut o = official();
+        assert_eq!(
+            o.revert_finalization(false, FULL_WEIGHT, 0).unwrap_err(),
+            TruChainError::CounterOverflow.into()
+        );
+
+        // a disputed revert needs a disputed verdict, not just any verdict
+        o.record_finalization(false, FULL_WEIGHT).unwrap();
+        assert_eq!(
+            o.revert_finalization(true, FULL_WEIGHT, 0).unwrap_err(),
+            TruChainError::CounterOverflow.into()
+        );
+    }
+
+    #[test]
+    fn record_fails_instead_of_wrapping_the_counters() {
+        let mut o = official();
+        o.finalized_count = u32::MAX;
+        assert_eq!(
+            o.record_finalization(false, FULL_WEIGHT).unwrap_err(),
+            TruChainError::CounterOverflow.into()
+        );
+    }
+    // record_finalization followed by record_finalize_latency, as
+    // apply_status_change runs them
+    fn finalize(o: &mut Official, secs: u64) {
+        o.record_finalization(false, FULL_WEIGHT).unwrap();
+        o.record_finalize_latency(secs).unwrap();
+    }
+
+    #[test]
+    fn latency_tracks_min_max_and_truncated_mean() {
+        let mut o = official();
+        finalize(&mut o, 10);
+        assert_eq!((o.min_finalize_secs, o.max_finalize_secs, o.mean_finalize_millis), (10, 10, 10_000));
+
+        finalize(&mut o, 20);
+        finalize(&mut o, 1);
+        // 31s over 3 verdicts: 10_333.33ms -> 10_333
+        assert_eq!((o.min_finalize_secs, o.max_finalize_secs, o.mean_finalize_millis), (1, 20, 10_333));
+        assert_eq!(o.total_finalize_secs, 31);
+    }
+
+    #[test]
+    fn first_latency_replaces_the_zero_minimum() {
+        let mut o = official();
+        finalize(&mut o, 0);
+        finalize(&mut o, 5);
+        assert_eq!(o.min_finalize_secs, 0);
+
+        let mut o = official();
+        finalize(&mut o, 5);
+        assert_eq!(o.min_finalize_secs, 5);
+    }
+
+    #[test]
+    fn revert_drops_the_latency_from_the_mean() {
+        let mut o = official();
+        finalize(&mut o, 10);
+        finalize(&mut o, 20);
+        finalize(&mut o, 40);
+
+        o.revert_finalization(false, FULL_WEIGHT, 40).unwrap();
+        assert_eq!((o.total_finalize_secs, o.mean_finalize_millis), (30, 15_000));
+        // extremes can't be unwound
+        assert_eq!((o.min_finalize_secs, o.max_finalize_secs), (10, 40));
+
+        o.revert_finalization(false, FULL_WEIGHT, 20).unwrap();
+        o.revert_finalization(false, FULL_WEIGHT, 10).unwrap();
+        assert_eq!((o.total_finalize_secs, o.mean_finalize_millis), (0, 0));
+    }
+
+    #[test]
+    fn revert_saturates_a_latency_larger_than_the_total() {
+        let mut o = official();
+        finalize(&mut o, 10);
+        finalize(&mut o, 20);
+
+        o.revert_finalization(false, FULL_WEIGHT, 1_000).unwrap();
+        assert_eq!((o.total_finalize_secs, o.mean_finalize_millis), (0, 0));
+    }
+
+    #[test]
+    fn latency_fails_instead_of_overflowing() {
+        let mut o = official();
+        o.record_finalization(false, FULL_WEIGHT).unwrap();
+        o.total_finalize_secs = u64::MAX;
+        assert_eq!(o.record_finalize_latency(1).unwrap_err(), TruChainError::CounterOverflow.into());
+
+        // the sum fits, but not once scaled to milliseconds
+        let mut o = official();
+        o.record_finalization(false, FULL_WEIGHT).unwrap();
+        assert_eq!(
+            o.record_finalize_latency(u64::MAX / 1000 + 1).unwrap_err(),
+            TruChainError::CounterOverflow.into()
+        );
+    }
+
+    #[test]
+    fn revert_saturates_the_mean_instead_of_overflowing() {
+        let mut o = official();
+        finalize(&mut o, 1);
+        finalize(&mut o, 1);
+        // a total too large to scale (only reachable through a corrupt
+        // account) clamps the mean rather than failing the revert
+        o.total_finalize_secs = u64::MAX;
+        o.revert_finalization(false, FULL_WEIGHT, 0).unwrap();
+        assert_eq!(o.mean_finalize_millis, u64::MAX);
+    }
+}
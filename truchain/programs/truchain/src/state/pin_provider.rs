@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+// A pinning service allowed to attest that it holds a video's bytes.
+// PDA seeded by (b"provider", provider_key).
+#[account]
+pub struct Provider {
+    pub provider_key: Pubkey,     // key the provider signs attestations with
+    pub name: [u8; 32],           // UTF-8 bytes, padded
+    pub endpoint_hash: [u8; 32],  // hash of the provider's public endpoint
+    pub revoked: bool,            // revoked providers' receipts are all invalid
+    pub active_receipts: u32,     // receipts created and not yet expired/collected
+    pub registered_at: i64,       // unix timestamp
+    pub bump: u8,                 // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 provider_key
+// 32 name
+// 32 endpoint_hash
+// 1  revoked
+// 4  active_receipts
+// 8  registered_at
+// 1  bump
+pub const PROVIDER_SIZE: usize = 8 + 32 + 32 + 32 + 1 + 4 + 8 + 1;
+
+// A provider's claim that it pins a video until `expires_at`.
+// PDA seeded by (b"pin", video, provider PDA).
+#[account]
+pub struct PinReceipt {
+    pub provider: Pubkey,     // Provider PDA that attested
+    pub video: Pubkey,        // pinned Video
+    pub attested_at: i64,     // unix timestamp
+    pub expires_at: i64,      // unix timestamp the pin commitment ends
+    pub bump: u8,             // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 provider
+// 32 video
+// 8  attested_at
+// 8  expires_at
+// 1  bump
+pub const PIN_RECEIPT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+
+impl PinReceipt {
+    // Read-time validity: revoking a provider voids all of its receipts
+    // without touching them. Clients reading a receipt must apply the same
+    // rule; collect_pin_receipt uses it to decide what can be closed.
+    pub fn is_valid(&self, provider: &Provider, now: i64) -> bool {
+        !provider.revoked && now < self.expires_at
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+// Deployed program version, written by the admin right after each upgrade
+// so clients can tell which instructions exist without probing. PDA seeded
+// by (b"version").
+#[account]
+pub struct ProgramVersion {
+    pub major: u16,                            // semver major
+    pub minor: u16,                            // semver minor
+    pub patch: u16,                            // semver patch
+    pub min_supported_account_version: u8,     // oldest account layout the program still reads
+    pub upgrade_slot: u64,                     // slot set_program_version last ran in
+    pub changelog_hash: [u8; 32],              // sha256 of the release notes
+    pub bump: u8,                              // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 2  major
+// 2  minor
+// 2  patch
+// 1  min_supported_account_version
+// 8  upgrade_slot
+// 32 changelog_hash
+// 1  bump
+pub const PROGRAM_VERSION_SIZE: usize = 8 + 2 + 2 + 2 + 1 + 8 + 32 + 1;
+
+impl ProgramVersion {
+    pub fn semver(&self) -> (u16, u16, u16) {
+        (self.major, self.minor, self.patch)
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::MAX_VOTES_LIMIT;
+
+// Endorser-driven handover of an official to a new authority, for when the
+// old key is lost. One open proposal per official.
+// PDA seeded by (b"recovery", official).
+#[account]
+pub struct RecoveryProposal {
+    pub official: Pubkey,        // Official being recovered
+    pub new_authority: Pubkey,   // key that takes over on execution
+    pub proposer: Pubkey,        // endorser that opened it
+    pub approvals: Vec<Pubkey>,  // endorsers in favour, proposer included
+    pub expires_at: i64,         // unix timestamp after which it can't execute
+    pub rent_payer: Pubkey,      // first proposer; kept when an expired proposal is reused
+    pub bump: u8,                // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 official
+// 32 new_authority
+// 32 proposer
+// 4  approvals vec length prefix (u32)
+// 32*MAX_VOTES_LIMIT approvals
+// 8  expires_at
+// 32 rent_payer
+// 1  bump
+pub const RECOVERY_PROPOSAL_SIZE: usize =
+    8 + 32 + 32 + 32 + 4 + 32 * MAX_VOTES_LIMIT as usize + 8 + 32 + 1;
+
+impl RecoveryProposal {
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expires_at
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+// Max length of an institution's internal archive reference.
+pub const MAX_EXTERNAL_REF_LEN: usize = 32;
+
+// Resolves an official's internal archive number (e.g. "PRESS-2024-0117")
+// to its Video. PDA seeded by (b"ref", official, sha256(external_ref)).
+#[account]
+pub struct RefIndex {
+    pub official: Pubkey,    // Official that registered the ref
+    pub video: Pubkey,       // Video the ref points at
+    pub bump: u8,            // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 official
+// 32 video
+// 1  bump
+pub const REF_INDEX_SIZE: usize = 8 + 32 + 32 + 1;
+
+// Printable ASCII, 1..=MAX_EXTERNAL_REF_LEN bytes.
+pub fn validate_external_ref(external_ref: &str) -> bool {
+    !external_ref.is_empty()
+        && external_ref.len() <= MAX_EXTERNAL_REF_LEN
+        && external_ref.bytes().all(|b| (0x20..=0x7e).contains(&b))
+}
+
+pub fn external_ref_hash(external_ref: &str) -> [u8; 32] {
+    hash(external_ref.as_bytes()).to_bytes()
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+// Snapshots kept per official; older epochs are overwritten in place.
+pub const SNAPSHOT_RING_SIZE: u64 = 4;
+
+// Merkle commitment over all of an official's video statuses at one point
+// in time, published by the config's snapshotter. PDA seeded by
+// (b"snapshot", official, [epoch % SNAPSHOT_RING_SIZE]).
+#[account]
+pub struct Snapshot {
+    pub official: Pubkey,        // Official the snapshot covers
+    pub epoch: u64,              // monotonically increasing per official
+    pub merkle_root: [u8; 32],   // root over (video, status) leaves, built off-chain
+    pub video_count: u64,        // leaves in the tree
+    pub slot: u64,               // slot the snapshot was published in
+    pub published_at: i64,       // unix timestamp
+    pub bump: u8,                // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 official
+// 8  epoch
+// 32 merkle_root
+// 8  video_count
+// 8  slot
+// 8  published_at
+// 1  bump
+pub const SNAPSHOT_SIZE: usize = 8 + 32 + 8 + 32 + 8 + 8 + 8 + 1;
+
+pub fn snapshot_ring_index(epoch: u64) -> u8 {
+    (epoch % SNAPSHOT_RING_SIZE) as u8
+}
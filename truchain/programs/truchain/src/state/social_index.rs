@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_SOCIAL_ATTESTATIONS: usize = 3;
+pub const MAX_SOCIAL_HANDLE_LEN: usize = 32;
+
+// Admin-verified account on a platform where the official publishes.
+// Stored inline on the Official, at most one per platform.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SocialAttestation {
+    pub platform: u8,                         // SocialPlatform discriminant
+    pub handle: [u8; MAX_SOCIAL_HANDLE_LEN],  // normalized handle, padded
+    pub attested_at: i64,                     // unix timestamp
+}
+
+// 1 platform + 32 handle + 8 attested_at
+pub const SOCIAL_ATTESTATION_SIZE: usize = 1 + MAX_SOCIAL_HANDLE_LEN + 8;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SocialPlatform {
+    X = 0,
+    YouTube = 1,
+    Telegram = 2,
+}
+
+impl SocialPlatform {
+    pub fn from_u8(platform: u8) -> Option<Self> {
+        match platform {
+            0 => Some(Self::X),
+            1 => Some(Self::YouTube),
+            2 => Some(Self::Telegram),
+            _ => None,
+        }
+    }
+
+    // Handles must already be normalized (lowercase, no leading '@') since
+    // the uniqueness index is derived from the exact bytes.
+    pub fn validate_handle(&self, handle: &str) -> bool {
+        let (min, max, extra): (usize, usize, &[u8]) = match self {
+            Self::X => (1, 15, b"_"),
+            Self::YouTube => (3, 30, b"_-."),
+            Self::Telegram => (5, 32, b"_"),
+        };
+        (min..=max).contains(&handle.len())
+            && handle
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || extra.contains(&b))
+    }
+}
+
+pub fn pad_social_handle(handle: &str) -> [u8; MAX_SOCIAL_HANDLE_LEN] {
+    let mut padded = [0u8; MAX_SOCIAL_HANDLE_LEN];
+    padded[..handle.len()].copy_from_slice(handle.as_bytes());
+    padded
+}
+
+// Cross-official uniqueness for a (platform, handle) pair.
+// PDA seeded by (b"social", [platform], handle bytes).
+#[account]
+pub struct SocialIndex {
+    pub platform: u8,                         // SocialPlatform discriminant
+    pub handle: [u8; MAX_SOCIAL_HANDLE_LEN],  // normalized handle, padded
+    pub official: Pubkey,                     // Official the account is attested for
+    pub bump: u8,                             // PDA bump
+}
+
+impl SocialIndex {
+    pub fn handle_bytes(&self) -> &[u8] {
+        let len = self.handle.iter().position(|&b| b == 0).unwrap_or(self.handle.len());
+        &self.handle[..len]
+    }
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 1  platform
+// 32 handle
+// 32 official
+// 1  bump
+pub const SOCIAL_INDEX_SIZE: usize = 8 + 1 + 32 + 32 + 1;
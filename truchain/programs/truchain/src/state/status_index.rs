@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VideoStatus;
+
+// Video keys per page of a status bucket.
+pub const STATUS_INDEX_CAPACITY: usize = 64;
+
+// One page of an official's list of videos that finalized with `status`.
+// Pages chain through `next` so clients can walk a bucket without an
+// indexer. PDA seeded by (b"status_index", official, [status], page as u32 LE).
+#[account]
+pub struct StatusIndex {
+    pub official: Pubkey,      // Official whose videos are listed
+    pub status: VideoStatus,   // terminal status of every listed video
+    pub page: u32,             // 0 for the head of the bucket
+    pub videos: Vec<Pubkey>,   // up to STATUS_INDEX_CAPACITY video keys
+    pub next: Pubkey,          // continuation page, default until opened
+    pub bump: u8,              // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 official
+// 1  status enum tag
+// 4  page
+// 4  videos vec length prefix (u32)
+// 32*STATUS_INDEX_CAPACITY videos
+// 32 next
+// 1  bump
+pub const STATUS_INDEX_SIZE: usize = 8 + 32 + 1 + 4 + 4 + 32 * STATUS_INDEX_CAPACITY + 32 + 1;
+
+impl StatusIndex {
+    pub fn is_full(&self) -> bool {
+        self.videos.len() >= STATUS_INDEX_CAPACITY
+    }
+}
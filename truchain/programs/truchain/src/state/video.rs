@@ -1,31 +1,113 @@
 use anchor_lang::prelude::*;
 
-// Max votes = 3 endorsers
-pub const MAX_VOTES: usize = 3;
+use super::Official;
 
+// Max same-event links per video.
+pub const MAX_RELATED_VIDEOS: usize = 4;
+
+// Not versioned: fields like rent_payer were added by growing the layout,
+// so videos written before them don't decode at all. The "fall back to the
+// official's authority, flagged in the close event" rule for such v1
+// accounts belongs to the migration that makes them readable, not here.
 #[account]
 pub struct Video {
-    pub official: Pubkey,           // link to Official account
-    pub video_hash: [u8; 32],       // SHA-256 of full video file
-    pub ipfs_cid: [u8; 64],         // IPFS CID bytes, padded
-    pub timestamp: i64,             // unix timestamp
-    pub votes: Vec<Vote>,           // up to 3 votes
-    pub status: VideoStatus,        // Unverified / Authentic / Disputed
-    pub bump: u8,                   // PDA bump
+    pub official: Pubkey,                   // link to Official account
+    pub video_hash: [u8; 32],               // SHA-256 of full video file
+    pub ipfs_cid: [u8; 64],                 // IPFS CID bytes, padded
+    pub timestamp: i64,                     // unix timestamp
+    pub max_votes_at_registration: u8,      // vote capacity allocated at registration
+    pub votes: Vec<Vote>,                   // up to max_votes_at_registration votes
+    pub status: VideoStatus,                // Unverified / Authentic / Disputed
+    pub takedown: bool,                     // delisted by legal order; record stays intact
+    pub takedown_requester_hash: [u8; 32],  // hash identifying the requesting party/order
+    pub takedown_reason_code: u16,          // reason code of the active/last takedown
+    pub frozen: bool,                       // under review after an upheld counter-claim
+    pub related_videos: Vec<Pubkey>,        // same event, other sources (max 4)
+    pub classification: Option<u8>,         // majority label written at finalization
+    pub co_attestation_count: u16,          // live CoAttestations from other officials
+    pub bond_lamports: u64,                 // registration bond held in this account
+    pub bond_state: BondState,              // None / Escrowed / Reclaimed / Slashed
+    pub finalized_at: i64,                  // unix timestamp of first finalization, 0 if never
+    pub standby_active: bool,               // official's standby endorser may vote on this video
+    pub rent_payer: Pubkey,                 // signer that funded the account and posted any bond; refunds go here
+    pub listed: bool,                       // CID announced publicly; unlisted videos flip at finalization
+    pub has_notice: bool,                   // official appended a CorrectionNotice after finalization
+    pub external_ref: [u8; 32],             // official's internal archive number, zeroed when none; see RefIndex
+    pub threshold_at_registration: u8,      // official.threshold when registered; later changes don't apply
+    pub status_index_page: Option<u32>,     // StatusIndex page listing this video under its status, if any
+    pub bump: u8,                           // PDA bump
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct Vote {
     pub endorser: Pubkey,
     pub is_authentic: bool,
+    pub classification: Option<u8>,  // optional content label, see Classification
+}
+
+impl VideoStatus {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Unverified),
+            1 => Some(Self::Authentic),
+            2 => Some(Self::Disputed),
+            3 => Some(Self::Revoked),
+            _ => None,
+        }
+    }
+}
+
+// Whether the CID is announced at registration or held back until the
+// panel has ruled.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum VideoVisibility {
+    Public = 0,
+    UnlistedUntilFinal = 1,
+}
+
+impl VideoVisibility {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Public),
+            1 => Some(Self::UnlistedUntilFinal),
+            _ => None,
+        }
+    }
+}
+
+// Lifecycle of the registration bond; each video settles it at most once.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum BondState {
+    None = 0,       // no bond was required at registration
+    Escrowed = 1,   // held in the Video account
+    Reclaimed = 2,  // returned to rent_payer after Authentic
+    Slashed = 3,    // sent to the treasury after Disputed
+}
+
+// Content labels endorsers can attach to their vote.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Classification {
+    GraphicContent = 0,
+    StatisticalClaims = 1,
+    Satire = 2,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+impl Classification {
+    pub fn is_valid(value: u8) -> bool {
+        value <= Classification::Satire as u8
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(u8)]
 pub enum VideoStatus {
     Unverified = 0,
     Authentic = 1,
     Disputed = 2,
+    Revoked = 3,    // pulled by the official's authority; terminal
 }
 
 // Account size calculation:
@@ -34,33 +116,122 @@ pub enum VideoStatus {
 // 32 video_hash
 // 64 ipfs_cid
 // 8  timestamp
+// 1  max_votes_at_registration
 // 4  votes vec length prefix (u32)
-// 3 * (32 + 1) votes (Pubkey + bool)
+// n * (32 + 1 + 2) votes (Pubkey + bool + Option<u8>)
 // 1  status enum tag
+// 1  takedown
+// 32 takedown_requester_hash
+// 2  takedown_reason_code
+// 1  frozen
+// 4 + 4 * 32 related_videos (vec prefix + keys)
+// 2  classification (option tag + u8)
+// 2  co_attestation_count
+// 8  bond_lamports
+// 1  bond_state enum tag
+// 8  finalized_at
+// 1  standby_active
+// 32 rent_payer
+// 1  listed
+// 1  has_notice
+// 32 external_ref
+// 1  threshold_at_registration
+// 5  status_index_page (option tag + u32)
 // 1  bump
-pub const VIDEO_SIZE: usize =
+pub const fn video_size(max_votes: usize) -> usize {
     8       // disc
     + 32    // official
     + 32    // video_hash
     + 64    // ipfs_cid
     + 8     // timestamp
+    + 1     // max_votes_at_registration
     + 4     // votes vec length prefix
-    + MAX_VOTES * (32 + 1) // 3 votes max
+    + max_votes * (32 + 1 + 2) // votes
     + 1     // status
-    + 1;    // bump
+    + 1     // takedown
+    + 32    // takedown_requester_hash
+    + 2     // takedown_reason_code
+    + 1     // frozen
+    + 4 + MAX_RELATED_VIDEOS * 32 // related_videos
+    + 2     // classification
+    + 2     // co_attestation_count
+    + 8     // bond_lamports
+    + 1     // bond_state
+    + 8     // finalized_at
+    + 1     // standby_active
+    + 32    // rent_payer
+    + 1     // listed
+    + 1     // has_notice
+    + 32    // external_ref
+    + 1     // threshold_at_registration
+    + 5     // status_index_page
+    + 1     // bump
+}
 
 impl Video {
-    // Recompute status based on current votes using 2-of-3 rule
+    pub fn cid_str(&self) -> &str {
+        let len = self.ipfs_cid.iter().position(|&b| b == 0).unwrap_or(self.ipfs_cid.len());
+        // only ever written from a validated String
+        core::str::from_utf8(&self.ipfs_cid[..len]).unwrap_or_default()
+    }
+
+    // Drops votes from endorsers no longer on the official's panel (e.g.
+    // rotated out), keeping the standby's once it is active on this video.
+    // Finalized videos keep their votes as the record of the verdict.
+    // Returns how many were removed.
+    pub fn purge_stale_votes(&mut self, official: &Official) -> usize {
+        if self.status != VideoStatus::Unverified {
+            return 0;
+        }
+        let standby = (self.standby_active && official.has_standby()).then_some(official.standby_endorser);
+        let before = self.votes.len();
+        self.votes
+            .retain(|vote| official.endorsers.contains(&vote.endorser) || Some(vote.endorser) == standby);
+        before - self.votes.len()
+    }
+
+    // A panel verdict. Revoked is terminal too, but it is the official
+    // withdrawing the record, not an outcome: it gates nothing that expects
+    // a verdict (receipts, standby, require_verified).
+    pub fn is_finalized(&self) -> bool {
+        matches!(self.status, VideoStatus::Authentic | VideoStatus::Disputed)
+    }
+
+    // Recompute status against the threshold the video was registered with.
+    // Disputed as soon as enough fake votes make the threshold unreachable
+    // (for 2-of-3 that is 2 fake votes, as before).
     pub fn recompute_status(&mut self) {
         let authentic = self.votes.iter().filter(|v| v.is_authentic).count();
         let fake = self.votes.len().saturating_sub(authentic);
+        let threshold = self.threshold_at_registration as usize;
+        let panel = self.max_votes_at_registration as usize;
 
-        self.status = if authentic >= 2 {
+        self.status = if authentic >= threshold {
             VideoStatus::Authentic
-        } else if fake >= 2 {
+        } else if fake > panel.saturating_sub(threshold) {
             VideoStatus::Disputed
         } else {
             VideoStatus::Unverified
         };
+
+        self.classification = if self.is_finalized() {
+            self.majority_classification()
+        } else {
+            None
+        };
+    }
+
+    // Most common label among the votes; ties (and no labels) give None.
+    pub fn majority_classification(&self) -> Option<u8> {
+        let mut counts = [0usize; Classification::Satire as usize + 1];
+        for class in self.votes.iter().filter_map(|v| v.classification) {
+            counts[class as usize] += 1;
+        }
+
+        let best = *counts.iter().max()?;
+        if best == 0 || counts.iter().filter(|&&c| c == best).count() > 1 {
+            return None;
+        }
+        counts.iter().position(|&c| c == best).map(|i| i as u8)
     }
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use super::VideoStatus;
+
+// Proof that an endorser voted on a video.
+// PDA seeded by (video_hash, endorser) rather than the video account key,
+// so the receipt still resolves after the video account is closed.
+#[account]
+pub struct VoteReceipt {
+    pub endorser: Pubkey,                   // voter, the only key that can close it
+    pub official: Pubkey,                   // official the video belonged to
+    pub video_hash: [u8; 32],               // SHA-256 of the video voted on
+    pub is_authentic: bool,                 // the decision
+    pub timestamp: i64,                     // unix timestamp of the vote
+    pub final_status: Option<VideoStatus>,  // filled once the video finalizes
+    pub bump: u8,                           // PDA bump
+}
+
+// Account size calculation (bytes)
+// 8  discriminator
+// 32 endorser
+// 32 official
+// 32 video_hash
+// 1  is_authentic
+// 8  timestamp
+// 2  final_status (option tag + enum tag)
+// 1  bump
+pub const VOTE_RECEIPT_SIZE: usize = 8 + 32 + 32 + 32 + 1 + 8 + 2 + 1;
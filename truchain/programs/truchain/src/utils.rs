@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+// True when the account has already been created and is owned by this program.
+pub fn is_initialized(account: &AccountInfo) -> bool {
+    account.owner == &crate::ID && !account.data_is_empty()
+}
+
+// Creates a program-owned PDA the same way Anchor's `init` constraint does.
+// Used where the handler needs to inspect the address before creating it
+// (e.g. to return a domain error instead of "account already in use").
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current = target.lamports();
+
+    if current == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: target.clone() },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+        return Ok(());
+    }
+
+    // someone already sent lamports to the address: top up, allocate, assign
+    let top_up = rent.saturating_sub(current);
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: target.clone() },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate { account_to_allocate: target.clone() },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign { account_to_assign: target.clone() },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )?;
+
+    Ok(())
+}
+
+// Writes discriminator + data into a freshly created account.
+pub fn write_account<T: AccountSerialize>(target: &AccountInfo, account: &T) -> Result<()> {
+    let mut data = target.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])
+}
+
+// Checks that the instruction immediately before the current one is an
+// ed25519 program verification of `signature(message)` by `pubkey`. The
+// ed25519 program itself fails the transaction on a bad signature, so all
+// that's left is making sure it verified the key and message we expect.
+pub fn verify_preceding_ed25519(
+    instructions_sysvar: &AccountInfo,
+    pubkey: &[u8; 32],
+    message: &[u8],
+) -> Result<bool> {
+    use anchor_lang::solana_program::ed25519_program;
+    use anchor_lang::solana_program::sysvar::instructions::{
+        load_current_index_checked, load_instruction_at_checked,
+    };
+
+    let current = load_current_index_checked(instructions_sysvar)?;
+    if current == 0 {
+        return Ok(false);
+    }
+    let ix = load_instruction_at_checked(current as usize - 1, instructions_sysvar)?;
+    if ix.program_id != ed25519_program::ID {
+        return Ok(false);
+    }
+
+    // [num_signatures u8][padding u8][offsets: 7 x u16] ...
+    let data = &ix.data;
+    if data.len() < 16 || data[0] != 1 {
+        return Ok(false);
+    }
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    let signature_ix = read_u16(4);
+    let pubkey_offset = read_u16(6);
+    let pubkey_ix = read_u16(8);
+    let message_offset = read_u16(10);
+    let message_len = read_u16(12);
+    let message_ix = read_u16(14);
+
+    // everything must live inside the ed25519 instruction's own data
+    let own = u16::MAX as usize;
+    if signature_ix != own || pubkey_ix != own || message_ix != own {
+        return Ok(false);
+    }
+
+    let signed_pubkey = data.get(pubkey_offset..pubkey_offset + 32);
+    let signed_message = data.get(message_offset..message_offset + message_len);
+    Ok(signed_pubkey == Some(&pubkey[..]) && signed_message == Some(message))
+}
+
+// SPL Name Service program. Name registry accounts start with a 96-byte
+// header: parent_name (32) | owner (32) | class (32).
+pub const SNS_NAME_PROGRAM_ID: Pubkey = pubkey!("namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX");
+
+// Current owner of an SNS name account, or None if it isn't one.
+pub fn sns_name_owner(name_account: &AccountInfo) -> Option<Pubkey> {
+    if name_account.owner != &SNS_NAME_PROGRAM_ID {
+        return None;
+    }
+    let data = name_account.try_borrow_data().ok()?;
+    let owner: [u8; 32] = data.get(32..64)?.try_into().ok()?;
+    Some(Pubkey::new_from_array(owner))
+}
+
+// Unix time as handlers should see it. With the `test-clock` feature, a
+// ClockOverride PDA passed in remaining_accounts takes precedence over the
+// sysvar so localnet tests can move past deadlines.
+pub fn current_time<T: anchor_lang::Bumps>(ctx: &Context<'_, '_, '_, '_, T>) -> Result<i64> {
+    #[cfg(feature = "test-clock")]
+    if let Some(info) = ctx.remaining_accounts.iter().find(|info| is_clock_override(info)) {
+        let clock_override = crate::state::ClockOverride::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        if clock_override.unix_timestamp != 0 {
+            return Ok(clock_override.unix_timestamp);
+        }
+    }
+    #[cfg(not(feature = "test-clock"))]
+    let _ = ctx;
+
+    Ok(Clock::get()?.unix_timestamp)
+}
+
+// True for the ClockOverride PDA. Handlers that walk remaining_accounts skip
+// it; always false without `test-clock`.
+pub fn is_clock_override(info: &AccountInfo) -> bool {
+    #[cfg(feature = "test-clock")]
+    return info.owner == &crate::ID && info.key() == crate::state::clock_override_address();
+    #[cfg(not(feature = "test-clock"))]
+    {
+        let _ = info;
+        false
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use truchain::program::Truchain;
+
+// Minimal stand-in for a third-party program that only acts on verified
+// videos. It exists so the suite exercises require_verified through a real
+// CPI, including the error propagating back out. Localnet only.
+
+// keypair: tests/fixtures/verified-consumer-keypair.json
+declare_id!("AVRRi6T9nztPfXfZjVEnNzcpLVAXxiCRhqfVytJVszMo");
+
+#[program]
+pub mod verified_consumer {
+    use super::*;
+
+    // Remaining accounts (the clock override in tests) are forwarded to
+    // truchain untouched.
+    pub fn gated_action<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GatedAction<'info>>,
+        max_age_secs: i64,
+        required_status: u8,
+    ) -> Result<()> {
+        truchain::cpi::require_verified(
+            CpiContext::new(
+                ctx.accounts.truchain_program.to_account_info(),
+                truchain::cpi::accounts::RequireVerified {
+                    video: ctx.accounts.video.to_account_info(),
+                },
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            max_age_secs,
+            required_status,
+        )?;
+
+        emit!(GatedActionPerformed {
+            video: ctx.accounts.video.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct GatedAction<'info> {
+    /// CHECK: validated by truchain's require_verified.
+    pub video: UncheckedAccount<'info>,
+
+    pub truchain_program: Program<'info, Truchain>,
+}
+
+#[event]
+pub struct GatedActionPerformed {
+    pub video: Pubkey,
+}
@@ -0,0 +1,117 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  airdrop,
+  configPda,
+  ensureConfig,
+  expectError,
+  NO_RATIONALE,
+  officialPda,
+  program,
+  programDataPda,
+  uniqueOfficialId,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+function registerOfficial(signer: anchor.web3.Keypair | null, endorsers: anchor.web3.PublicKey[]) {
+  const officialId = uniqueOfficialId();
+  const builder = program.methods
+    .registerOfficial(
+      officialId,
+      "Admin Test",
+      Keypair.generate().publicKey,
+      endorsers,
+      Math.min(2, endorsers.length),
+      NO_RATIONALE.hash,
+      NO_RATIONALE.cid
+    )
+    .accountsPartial({
+      config: configPda(),
+      official: officialPda(officialId),
+      admin: signer ? signer.publicKey : admin.publicKey,
+    });
+  return (signer ? builder.signers([signer]) : builder).rpc();
+}
+
+function updateAdmin(current: anchor.web3.Keypair | null, newAdmin: anchor.web3.PublicKey) {
+  const builder = program.methods
+    .updateAdmin(newAdmin)
+    .accountsPartial({ config: configPda(), admin: current ? current.publicKey : admin.publicKey });
+  return (current ? builder.signers([current]) : builder).rpc();
+}
+
+function initializeConfig(signer: anchor.web3.Keypair | null) {
+  const builder = program.methods
+    .initializeConfig(10)
+    .accountsPartial({
+      config: configPda(),
+      admin: signer ? signer.publicKey : admin.publicKey,
+      programData: programDataPda(),
+    });
+  return (signer ? builder.signers([signer]) : builder).rpc();
+}
+
+function panel(count: number): anchor.web3.PublicKey[] {
+  return Array.from({ length: count }, () => Keypair.generate().publicKey);
+}
+
+// Runs first (files load alphabetically) so the config does not exist yet;
+// skipped when another suite has already created it.
+describe("admin config bootstrap", () => {
+  it("only lets the upgrade authority initialize", async function () {
+    if (await program.account.config.fetchNullable(configPda())) {
+      this.skip();
+    }
+    const outsider = Keypair.generate();
+    await airdrop(outsider.publicKey);
+
+    await expectError(initializeConfig(outsider), "UnauthorizedAdmin");
+    expect(await program.account.config.fetchNullable(configPda())).to.equal(null);
+  });
+});
+
+describe("admin config", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("stores the admin and refuses a second initialize", async () => {
+    const config = await program.account.config.fetch(configPda());
+    expect(config.admin.toBase58()).to.equal(admin.publicKey.toBase58());
+
+    await expectError(initializeConfig(null), "already in use");
+  });
+
+  it("only lets the admin register officials", async () => {
+    const outsider = Keypair.generate();
+    await airdrop(outsider.publicKey);
+
+    await expectError(registerOfficial(outsider, panel(3)), "UnauthorizedAdmin");
+    await registerOfficial(null, panel(3));
+  });
+
+  it("rotates the admin", async () => {
+    const next = Keypair.generate();
+    await airdrop(next.publicKey);
+
+    await expectError(updateAdmin(null, PublicKey.default), "InvalidAdmin");
+    await updateAdmin(null, next.publicKey);
+    try {
+      expect((await program.account.config.fetch(configPda())).admin.toBase58()).to.equal(next.publicKey.toBase58());
+      await expectError(registerOfficial(null, panel(3)), "UnauthorizedAdmin");
+      await expectError(updateAdmin(null, admin.publicKey), "UnauthorizedAdmin");
+      await registerOfficial(next, panel(3));
+    } finally {
+      // the rest of the suite signs as the provider wallet
+      await updateAdmin(next, admin.publicKey);
+    }
+  });
+
+  it("rejects default and repeated endorser keys", async () => {
+    const [first, second] = panel(2);
+    await expectError(registerOfficial(null, [first, PublicKey.default, second]), "InvalidEndorser");
+    await expectError(registerOfficial(null, [first, second, first]), "DuplicateEndorsers");
+  });
+});
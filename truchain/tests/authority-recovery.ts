@@ -0,0 +1,180 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  airdrop,
+  cidPda,
+  clockOverrideMeta,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  nowSecs,
+  OfficialFixture,
+  program,
+  randomHash,
+  requireClockOverride,
+  setClock,
+  videoPda,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+function recoveryPda(official: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("recovery"), official.toBuffer()], program.programId)[0];
+}
+
+function setWindow(secs: number) {
+  return program.methods
+    .setRecoveryWindow(new BN(secs))
+    .accountsPartial({ config: configPda(), admin: admin.publicKey })
+    .rpc();
+}
+
+function propose(fixture: OfficialFixture, endorser: anchor.web3.Keypair, newAuthority: anchor.web3.PublicKey) {
+  return program.methods
+    .proposeAuthorityRecovery(newAuthority)
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      proposal: recoveryPda(fixture.official),
+      endorser: endorser.publicKey,
+    })
+    .remainingAccounts([clockOverrideMeta()])
+    .signers([endorser])
+    .rpc();
+}
+
+function approve(fixture: OfficialFixture, endorser: anchor.web3.Keypair) {
+  return program.methods
+    .approveRecovery()
+    .accountsPartial({ official: fixture.official, proposal: recoveryPda(fixture.official), endorser: endorser.publicKey })
+    .remainingAccounts([clockOverrideMeta()])
+    .signers([endorser])
+    .rpc();
+}
+
+function execute(fixture: OfficialFixture, rentPayer: anchor.web3.PublicKey) {
+  return program.methods
+    .executeRecovery()
+    .accountsPartial({ official: fixture.official, proposal: recoveryPda(fixture.official), rentPayer })
+    .remainingAccounts([clockOverrideMeta()])
+    .rpc();
+}
+
+describe("authority recovery", () => {
+  before(async function () {
+    await ensureConfig();
+    await requireClockOverride(this);
+    await setWindow(3600);
+  });
+
+  afterEach(async () => {
+    await setClock(0);
+  });
+
+  it("hands the official over once 2 of 3 endorsers approve", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second] = fixture.endorsers;
+    await airdrop(first.publicKey);
+    const newAuthority = Keypair.generate().publicKey;
+
+    await expectError(propose(fixture, first, second.publicKey), "RecoveryTargetIsEndorser");
+
+    await propose(fixture, first, newAuthority);
+    await expectError(propose(fixture, first, newAuthority), "RecoveryPending");
+    await expectError(execute(fixture, first.publicKey), "RecoveryQuorumNotMet");
+    await expectError(approve(fixture, first), "RecoveryAlreadyApproved");
+
+    await approve(fixture, second);
+    const events = await eventsFor(await execute(fixture, first.publicKey));
+
+    const official = await program.account.official.fetch(fixture.official);
+    expect(official.authority.toBase58()).to.equal(newAuthority.toBase58());
+    expect(await program.account.recoveryProposal.fetchNullable(recoveryPda(fixture.official))).to.equal(null);
+
+    const recovered = events.find((e) => e.name === "authorityRecovered")!;
+    expect(recovered.data.approvers.map((a: anchor.web3.PublicKey) => a.toBase58())).to.deep.equal([
+      first.publicKey.toBase58(),
+      second.publicKey.toBase58(),
+    ]);
+  });
+
+  it("retires hash authorizations signed by the replaced authority", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second] = fixture.endorsers;
+    await airdrop(first.publicKey);
+    const hash = randomHash();
+    const authorization = PublicKey.findProgramAddressSync(
+      [Buffer.from("hash_auth"), fixture.official.toBuffer(), Buffer.from(hash)],
+      program.programId
+    )[0];
+    await program.methods
+      .authorizeHashes([hash])
+      .accountsPartial({ official: fixture.official, authority: fixture.authority.publicKey })
+      .remainingAccounts([{ pubkey: authorization, isWritable: true, isSigner: false }])
+      .signers([fixture.authority])
+      .rpc();
+
+    await propose(fixture, first, Keypair.generate().publicKey);
+    await approve(fixture, second);
+    await execute(fixture, first.publicKey);
+
+    await expectError(
+      program.methods
+        .registerAuthorizedVideo(hash, "bafy-stale-approval")
+        .accountsPartial({
+          config: configPda(),
+          official: fixture.official,
+          authorization,
+          authority: fixture.authority.publicKey,
+          video: videoPda(fixture.official, hash),
+          cidIndex: cidPda(fixture.official, "bafy-stale-approval"),
+          payer: first.publicKey,
+        })
+        .signers([first])
+        .rpc(),
+      "StaleHashAuthorization"
+    );
+  });
+
+  it("lets the current authority veto", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second] = fixture.endorsers;
+    await airdrop(first.publicKey);
+    await propose(fixture, first, Keypair.generate().publicKey);
+
+    await program.methods
+      .cancelRecovery()
+      .accountsPartial({
+        official: fixture.official,
+        proposal: recoveryPda(fixture.official),
+        rentPayer: first.publicKey,
+        authority: fixture.authority.publicKey,
+      })
+      .signers([fixture.authority])
+      .rpc();
+
+    expect(await program.account.recoveryProposal.fetchNullable(recoveryPda(fixture.official))).to.equal(null);
+    await expectError(approve(fixture, second), "AccountNotInitialized");
+  });
+
+  it("rejects approvals and execution after the window", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second] = fixture.endorsers;
+    await airdrop(first.publicKey);
+    const base = nowSecs();
+    await setClock(base);
+    await propose(fixture, first, Keypair.generate().publicKey);
+
+    await setClock(base + 3601);
+    await expectError(approve(fixture, second), "RecoveryExpired");
+    await expectError(execute(fixture, first.publicKey), "RecoveryExpired");
+
+    // an expired proposal doesn't block a fresh one
+    await airdrop(second.publicKey);
+    await propose(fixture, second, Keypair.generate().publicKey);
+  });
+});
@@ -0,0 +1,128 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  airdrop,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  program,
+  randomHash,
+  registerVideo,
+  revokeVideo,
+  takeDown,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+function attestorPda(attestor: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("broadcast_attestor"), attestor.toBuffer()],
+    program.programId
+  )[0];
+}
+
+function broadcastPda(video: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("broadcast"), video.toBuffer()], program.programId)[0];
+}
+
+describe("broadcast attestations", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  async function registerAttestor(): Promise<anchor.web3.Keypair> {
+    const attestor = Keypair.generate();
+    await airdrop(attestor.publicKey);
+    await program.methods
+      .registerBroadcastAttestor(attestor.publicKey)
+      .accountsPartial({
+        config: configPda(),
+        broadcastAttestor: attestorPda(attestor.publicKey),
+        admin: admin.publicKey,
+      })
+      .rpc();
+    return attestor;
+  }
+
+  function attest(video: anchor.web3.PublicKey, attestor: anchor.web3.Keypair, time: number) {
+    return program.methods
+      .attestBroadcast(new BN(time), randomHash())
+      .accountsPartial({
+        broadcastAttestor: attestorPda(attestor.publicKey),
+        video,
+        broadcastAttestations: broadcastPda(video),
+        attestor: attestor.publicKey,
+      })
+      .signers([attestor])
+      .rpc();
+  }
+
+  it("records attestations from registered attestors only", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const now = Math.floor(Date.now() / 1000);
+
+    const outsider = Keypair.generate();
+    await airdrop(outsider.publicKey);
+    await expectError(attest(video, outsider, now - 60), "AccountNotInitialized");
+
+    const first = await registerAttestor();
+    const second = await registerAttestor();
+    const third = await registerAttestor();
+    await attest(video, first, now - 60);
+    await expectError(attest(video, first, now - 30), "BroadcastAlreadyAttested");
+    await attest(video, second, now - 30);
+    await expectError(attest(video, third, now - 10), "TooManyBroadcastAttestations");
+
+    const decoded = await program.account.broadcastAttestations.fetch(broadcastPda(video));
+    expect(decoded.entries.length).to.equal(2);
+    expect(decoded.entries[0].attestor.toBase58()).to.equal(first.publicKey.toBase58());
+    expect(decoded.entries[0].broadcastTime.toNumber()).to.equal(now - 60);
+  });
+
+  it("stops accepting attestations once an attestor is removed", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const attestor = await registerAttestor();
+
+    await program.methods
+      .removeBroadcastAttestor()
+      .accountsPartial({
+        config: configPda(),
+        broadcastAttestor: attestorPda(attestor.publicKey),
+        admin: admin.publicKey,
+      })
+      .rpc();
+
+    await expectError(attest(video, attestor, Math.floor(Date.now() / 1000) - 60), "AccountNotInitialized");
+  });
+
+  it("rejects broadcast times in the future", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const attestor = await registerAttestor();
+
+    await expectError(attest(video, attestor, Math.floor(Date.now() / 1000) + 3600), "InvalidBroadcastTime");
+  });
+
+  it("rejects attestations on taken-down videos", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const attestor = await registerAttestor();
+    await takeDown(video);
+
+    await expectError(attest(video, attestor, Math.floor(Date.now() / 1000) - 60), "VideoTakenDown");
+  });
+
+  it("rejects attestations on revoked videos", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const attestor = await registerAttestor();
+    await revokeVideo(fixture, video);
+
+    await expectError(attest(video, attestor, Math.floor(Date.now() / 1000) - 60), "VideoRevoked");
+  });
+});
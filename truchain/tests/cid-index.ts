@@ -0,0 +1,52 @@
+import { expect } from "chai";
+import {
+  cidPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  program,
+  randomCid,
+  randomHash,
+  registerVideo,
+} from "./helpers";
+
+describe("cid index", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("records the hash each CID was registered with", async () => {
+    const fixture = await createOfficial(3);
+    const cid = randomCid();
+    const hash = randomHash();
+    await registerVideo(fixture, hash, cid);
+
+    const index = await program.account.cidIndex.fetch(cidPda(fixture.official, cid));
+    expect(index.videoHash).to.deep.equal(hash);
+  });
+
+  it("rejects the same CID with a different hash", async () => {
+    const fixture = await createOfficial(3);
+    const cid = randomCid();
+    await registerVideo(fixture, randomHash(), cid);
+
+    await expectError(registerVideo(fixture, randomHash(), cid), "CidHashMismatch");
+  });
+
+  it("keeps the normal duplicate error for an identical pair", async () => {
+    const fixture = await createOfficial(3);
+    const cid = randomCid();
+    const hash = randomHash();
+    await registerVideo(fixture, hash, cid);
+
+    await expectError(registerVideo(fixture, hash, cid), "VideoAlreadyExists");
+  });
+
+  it("allows another official to reuse the CID", async () => {
+    const first = await createOfficial(3);
+    const second = await createOfficial(3);
+    const cid = randomCid();
+    await registerVideo(first, randomHash(), cid);
+    await registerVideo(second, randomHash(), cid);
+  });
+});
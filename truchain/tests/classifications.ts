@@ -0,0 +1,57 @@
+import { expect } from "chai";
+import {
+  createOfficial,
+  endorse,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  OfficialFixture,
+  program,
+  registerVideo,
+} from "./helpers";
+
+const GRAPHIC = 0;
+const STATISTICAL = 1;
+const SATIRE = 2;
+
+describe("classifications", () => {
+  let fixture: OfficialFixture;
+
+  before(async () => {
+    await ensureConfig();
+    fixture = await createOfficial(3);
+  });
+
+  it("writes the majority label on a 2-1 split", async () => {
+    const video = await registerVideo(fixture);
+    const [a, b, c] = fixture.endorsers;
+
+    await endorse(fixture, video, a, true, null, SATIRE);
+    const sig = await endorse(fixture, video, b, true, null, SATIRE);
+    await endorse(fixture, video, c, false, null, STATISTICAL);
+
+    const changed = (await eventsFor(sig)).find((e) => e.name === "statusChanged");
+    expect(changed!.data.classification).to.equal(SATIRE);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.classification).to.equal(SATIRE);
+  });
+
+  it("yields no label on a three-way disagreement", async () => {
+    const video = await registerVideo(fixture);
+    const [a, b, c] = fixture.endorsers;
+
+    await endorse(fixture, video, a, true, null, GRAPHIC);
+    await endorse(fixture, video, b, false, null, STATISTICAL);
+    await endorse(fixture, video, c, true, null, SATIRE);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.status).to.deep.equal({ authentic: {} });
+    expect(account.classification).to.equal(null);
+  });
+
+  it("rejects unknown classification values", async () => {
+    const video = await registerVideo(fixture);
+    await expectError(endorse(fixture, video, fixture.endorsers[0], true, null, 42), "InvalidClassification");
+  });
+});
@@ -0,0 +1,78 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  clockOverrideMeta,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  nowSecs,
+  program,
+  registerVideo,
+  requireClockOverride,
+  setClock,
+} from "./helpers";
+
+const { Keypair } = anchor.web3;
+
+// Needs a `test-clock` build (`yarn test`); skipped against any other.
+describe("clock override", () => {
+  before(async function () {
+    await ensureConfig();
+    await requireClockOverride(this);
+  });
+
+  after(async () => {
+    await setClock(0).catch(() => undefined);
+  });
+
+  it("expires the standby deadline without waiting", async () => {
+    const days = 3 * 24 * 60 * 60;
+    await program.methods
+      .setStandbyActivationSecs(new BN(days))
+      .accountsPartial({ config: configPda(), admin: admin.publicKey })
+      .rpc();
+
+    const fixture = await createOfficial(3);
+    await program.methods
+      .setStandbyEndorser(Keypair.generate().publicKey)
+      .accountsPartial({ config: configPda(), official: fixture.official, admin: admin.publicKey })
+      .rpc();
+    const video = await registerVideo(fixture);
+    const registeredAt = (await program.account.video.fetch(video)).timestamp.toNumber();
+
+    const activate = () =>
+      program.methods
+        .activateStandby()
+        .accountsPartial({ config: configPda(), official: fixture.official, video })
+        .remainingAccounts([clockOverrideMeta()])
+        .rpc();
+
+    await setClock(registeredAt + days - 60);
+    await expectError(activate(), "StandbyNotDue");
+
+    await setClock(registeredAt + days + 60);
+    await activate();
+    expect((await program.account.video.fetch(video)).standbyActive).to.equal(true);
+  });
+
+  it("is ignored unless the override account is passed", async () => {
+    const fixture = await createOfficial(3);
+    await program.methods
+      .setStandbyEndorser(Keypair.generate().publicKey)
+      .accountsPartial({ config: configPda(), official: fixture.official, admin: admin.publicKey })
+      .rpc();
+    const video = await registerVideo(fixture);
+
+    await setClock(nowSecs() + 30 * 24 * 60 * 60);
+    await expectError(
+      program.methods
+        .activateStandby()
+        .accountsPartial({ config: configPda(), official: fixture.official, video })
+        .rpc(),
+      "StandbyNotDue"
+    );
+  });
+});
@@ -0,0 +1,67 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  airdrop,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  program,
+  randomHash,
+  receiptPda,
+  registerVideo,
+} from "./helpers";
+
+const { Keypair } = anchor.web3;
+
+// A receipt with rent paid by its endorser, ready to be closed.
+async function openReceipt() {
+  const fixture = await createOfficial(3);
+  const hash = randomHash();
+  const video = await registerVideo(fixture, hash);
+  const voter = fixture.endorsers[0];
+  await airdrop(voter.publicKey);
+  const receipt = receiptPda(hash, voter.publicKey);
+  await endorse(fixture, video, voter, true, receipt);
+  return { voter, receipt };
+}
+
+describe("delegated close authority", () => {
+  const closeAuthority = Keypair.generate();
+
+  before(async () => {
+    await ensureConfig();
+    await program.methods
+      .setCloseAuthority(closeAuthority.publicKey)
+      .accountsPartial({ config: configPda(), admin: admin.publicKey })
+      .rpc();
+  });
+
+  it("lets the admin close a receipt, refunding the endorser", async () => {
+    const { voter, receipt } = await openReceipt();
+    const before = await program.provider.connection.getBalance(voter.publicKey);
+
+    await program.methods
+      .closeVoteReceipt()
+      .accountsPartial({ config: configPda(), receipt, endorser: voter.publicKey, closer: admin.publicKey })
+      .rpc();
+
+    expect(await program.account.voteReceipt.fetchNullable(receipt)).to.equal(null);
+    expect(await program.provider.connection.getBalance(voter.publicKey)).to.be.greaterThan(before);
+  });
+
+  it("lets the close authority close a receipt, refunding the endorser", async () => {
+    const { voter, receipt } = await openReceipt();
+    const before = await program.provider.connection.getBalance(voter.publicKey);
+
+    await program.methods
+      .closeVoteReceipt()
+      .accountsPartial({ config: configPda(), receipt, endorser: voter.publicKey, closer: closeAuthority.publicKey })
+      .signers([closeAuthority])
+      .rpc();
+
+    expect(await program.account.voteReceipt.fetchNullable(receipt)).to.equal(null);
+    expect(await program.provider.connection.getBalance(voter.publicKey)).to.be.greaterThan(before);
+  });
+});
@@ -0,0 +1,114 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  createOfficial,
+  endorse,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+  registerVideo,
+  takeDown,
+} from "./helpers";
+
+const { PublicKey } = anchor.web3;
+
+function coAttestationPda(video: anchor.web3.PublicKey, official: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("co_attest"), video.toBuffer(), official.toBuffer()],
+    program.programId
+  )[0];
+}
+
+function coAttest(attester: OfficialFixture, video: anchor.web3.PublicKey) {
+  return program.methods
+    .coAttest()
+    .accountsPartial({
+      attestingOfficial: attester.official,
+      video,
+      coAttestation: coAttestationPda(video, attester.official),
+      authority: attester.authority.publicKey,
+    })
+    .signers([attester.authority])
+    .rpc();
+}
+
+describe("co-attestations", () => {
+  let national: OfficialFixture;
+  let regional: OfficialFixture;
+
+  before(async () => {
+    await ensureConfig();
+    national = await createOfficial(3, "National Office");
+    regional = await createOfficial(3, "Regional Office");
+  });
+
+  it("counts attestations from other officials and decrements on revoke", async () => {
+    const video = await registerVideo(national);
+    await endorse(national, video, national.endorsers[0], true);
+    await endorse(national, video, national.endorsers[1], true);
+
+    await coAttest(regional, video);
+    expect((await program.account.video.fetch(video)).coAttestationCount).to.equal(1);
+
+    await program.methods
+      .revokeCoAttestation()
+      .accountsPartial({
+        attestingOfficial: regional.official,
+        video,
+        coAttestation: coAttestationPda(video, regional.official),
+        rentPayer: regional.authority.publicKey,
+        authority: regional.authority.publicKey,
+      })
+      .signers([regional.authority])
+      .rpc();
+
+    const account = await program.account.video.fetch(video);
+    expect(account.coAttestationCount).to.equal(0);
+    expect(account.status).to.deep.equal({ authentic: {} });
+  });
+
+  it("only refunds the recorded rent payer", async () => {
+    const video = await registerVideo(national);
+    await endorse(national, video, national.endorsers[0], true);
+    await endorse(national, video, national.endorsers[1], true);
+    await coAttest(regional, video);
+
+    await expectError(
+      program.methods
+        .revokeCoAttestation()
+        .accountsPartial({
+          attestingOfficial: regional.official,
+          video,
+          coAttestation: coAttestationPda(video, regional.official),
+          rentPayer: national.authority.publicKey,
+          authority: regional.authority.publicKey,
+        })
+        .signers([regional.authority])
+        .rpc(),
+      "RentPayerMismatch"
+    );
+  });
+
+  it("rejects self-attestation", async () => {
+    const video = await registerVideo(national);
+    await endorse(national, video, national.endorsers[0], true);
+    await endorse(national, video, national.endorsers[1], true);
+
+    await expectError(coAttest(national, video), "SelfAttestation");
+  });
+
+  it("rejects videos that are not Authentic", async () => {
+    const video = await registerVideo(national);
+    await expectError(coAttest(regional, video), "VideoNotAuthentic");
+  });
+
+  it("rejects videos under an active takedown", async () => {
+    const video = await registerVideo(national);
+    await endorse(national, video, national.endorsers[0], true);
+    await endorse(national, video, national.endorsers[1], true);
+    await takeDown(video);
+
+    await expectError(coAttest(regional, video), "VideoTakenDown");
+  });
+});
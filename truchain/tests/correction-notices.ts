@@ -0,0 +1,76 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  createOfficial,
+  endorse,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+  randomCid,
+  randomHash,
+  registerVideo,
+  takeDown,
+} from "./helpers";
+
+const { PublicKey } = anchor.web3;
+
+function noticesPda(video: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("notices"), video.toBuffer()], program.programId)[0];
+}
+
+function appendNotice(fixture: OfficialFixture, video: anchor.web3.PublicKey) {
+  return program.methods
+    .appendCorrectionNotice(randomHash(), randomCid())
+    .accountsPartial({
+      official: fixture.official,
+      video,
+      notices: noticesPda(video),
+      authority: fixture.authority.publicKey,
+    })
+    .signers([fixture.authority])
+    .rpc();
+}
+
+describe("correction notices", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("appends up to two notices to an Authentic video", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    await endorse(fixture, video, fixture.endorsers[0], true);
+    await endorse(fixture, video, fixture.endorsers[1], true);
+
+    await appendNotice(fixture, video);
+    await appendNotice(fixture, video);
+    await expectError(appendNotice(fixture, video), "NoticesFull");
+
+    const notices = await program.account.correctionNotices.fetch(noticesPda(video));
+    expect(notices.notices.length).to.equal(2);
+    const account = await program.account.video.fetch(video);
+    expect(account.hasNotice).to.equal(true);
+    expect(account.status).to.deep.equal({ authentic: {} });
+  });
+
+  it("rejects notices on unverified and Disputed videos", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    await expectError(appendNotice(fixture, video), "VideoNotAuthentic");
+
+    await endorse(fixture, video, fixture.endorsers[0], false);
+    await endorse(fixture, video, fixture.endorsers[1], false);
+    await expectError(appendNotice(fixture, video), "VideoNotAuthentic");
+  });
+
+  it("rejects notices on taken-down videos", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    await endorse(fixture, video, fixture.endorsers[0], true);
+    await endorse(fixture, video, fixture.endorsers[1], true);
+    await takeDown(video);
+
+    await expectError(appendNotice(fixture, video), "VideoTakenDown");
+  });
+});
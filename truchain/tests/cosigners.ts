@@ -0,0 +1,81 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+  registerVideo,
+} from "./helpers";
+
+const { Keypair } = anchor.web3;
+
+function setCosigner(fixture: OfficialFixture, slot: number, cosigner: anchor.web3.PublicKey | null) {
+  return program.methods
+    .setEndorserCosigner(slot, cosigner)
+    .accountsPartial({ config: configPda(), official: fixture.official, admin: admin.publicKey })
+    .rpc();
+}
+
+describe("endorser cosigners", () => {
+  let fixture: OfficialFixture;
+  const cosigner = Keypair.generate();
+
+  before(async () => {
+    await ensureConfig();
+    fixture = await createOfficial(3);
+    await setCosigner(fixture, 0, cosigner.publicKey);
+  });
+
+  it("requires the cosigner's signature for a paired slot", async () => {
+    const video = await registerVideo(fixture);
+    const primary = fixture.endorsers[0];
+
+    await expectError(
+      program.methods
+        .endorseVideo(true, false, null)
+        .accountsPartial({ config: configPda(), official: fixture.official, video, receipt: null, endorser: primary.publicKey, cosigner: null, statusIndex: null })
+        .signers([primary])
+        .rpc(),
+      "MissingCosigner"
+    );
+
+    await program.methods
+      .endorseVideo(true, false, null)
+      .accountsPartial({
+        config: configPda(),
+        official: fixture.official,
+        video,
+        receipt: null,
+        endorser: primary.publicKey,
+        cosigner: cosigner.publicKey,
+        statusIndex: null,
+      })
+      .signers([primary, cosigner])
+      .rpc();
+
+    // threshold math still sees the primary key
+    const account = await program.account.video.fetch(video);
+    expect(account.votes[0].endorser.toBase58()).to.equal(primary.publicKey.toBase58());
+  });
+
+  it("leaves unpaired slots single-signer", async () => {
+    const video = await registerVideo(fixture);
+    const unpaired = fixture.endorsers[1];
+
+    await program.methods
+      .endorseVideo(false, false, null)
+      .accountsPartial({ config: configPda(), official: fixture.official, video, receipt: null, endorser: unpaired.publicKey, cosigner: null, statusIndex: null })
+      .signers([unpaired])
+      .rpc();
+  });
+
+  it("rejects cosigners that are the primary, the authority, or another endorser", async () => {
+    await expectError(setCosigner(fixture, 1, fixture.endorsers[1].publicKey), "InvalidCosigner");
+    await expectError(setCosigner(fixture, 1, fixture.authority.publicKey), "InvalidCosigner");
+    await expectError(setCosigner(fixture, 1, fixture.endorsers[2].publicKey), "InvalidCosigner");
+  });
+});
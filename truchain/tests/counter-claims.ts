@@ -0,0 +1,158 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  airdrop,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+  provider,
+  randomHash,
+  registerVideo,
+  revokeVideo,
+  withdrawTreasury,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+const BOND = 10_000_000;
+
+function counterClaimPda(video: anchor.web3.PublicKey, claimant: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("counter_claim"), video.toBuffer(), claimant.toBuffer()],
+    program.programId
+  )[0];
+}
+
+async function fileClaim(video: anchor.web3.PublicKey, claimant: anchor.web3.Keypair) {
+  return program.methods
+    .fileCounterClaim(randomHash(), "bafy-evidence")
+    .accountsPartial({
+      video,
+      counterClaim: counterClaimPda(video, claimant.publicKey),
+      claimant: claimant.publicKey,
+    })
+    .signers([claimant])
+    .rpc();
+}
+
+async function resolveClaim(video: anchor.web3.PublicKey, claimant: anchor.web3.PublicKey, valid: boolean) {
+  return program.methods
+    .resolveCounterClaim(valid)
+    .accountsPartial({
+      config: configPda(),
+      video,
+      counterClaim: counterClaimPda(video, claimant),
+      claimant,
+      admin: admin.publicKey,
+    })
+    .rpc();
+}
+
+describe("counter-claims", () => {
+  let fixture: OfficialFixture;
+
+  before(async () => {
+    await ensureConfig();
+    fixture = await createOfficial(3);
+  });
+
+  it("returns the bond and freezes the video when upheld", async () => {
+    const video = await registerVideo(fixture);
+    const claimant = Keypair.generate();
+    await airdrop(claimant.publicKey);
+
+    await fileClaim(video, claimant);
+    await expectError(fileClaim(video, claimant), "already in use");
+
+    const before = await provider.connection.getBalance(claimant.publicKey);
+    const claimRent = await provider.connection.getBalance(counterClaimPda(video, claimant.publicKey));
+    await resolveClaim(video, claimant.publicKey, true);
+
+    const after = await provider.connection.getBalance(claimant.publicKey);
+    expect(after - before).to.equal(claimRent);
+    expect(claimRent).to.be.greaterThan(BOND);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.frozen).to.equal(true);
+    await expectError(endorse(fixture, video, fixture.endorsers[0], true), "VideoFrozen");
+
+    await program.methods
+      .unfreezeVideo()
+      .accountsPartial({ config: configPda(), video, admin: admin.publicKey })
+      .rpc();
+    await endorse(fixture, video, fixture.endorsers[0], true);
+  });
+
+  it("keeps a frozen video from being revoked", async () => {
+    const video = await registerVideo(fixture);
+    const claimant = Keypair.generate();
+    await airdrop(claimant.publicKey);
+    await fileClaim(video, claimant);
+    await resolveClaim(video, claimant.publicKey, true);
+
+    await expectError(
+      program.methods
+        .revokeVideo()
+        .accountsPartial({
+          config: configPda(),
+          official: fixture.official,
+          video,
+          rentPayer: null,
+          statusIndex: null,
+          authority: fixture.authority.publicKey,
+        })
+        .signers([fixture.authority])
+        .rpc(),
+      "VideoFrozen"
+    );
+  });
+
+  it("forfeits the bond to the treasury when rejected", async () => {
+    const video = await registerVideo(fixture);
+    const claimant = Keypair.generate();
+    await airdrop(claimant.publicKey);
+
+    await fileClaim(video, claimant);
+
+    const treasuryBefore = await provider.connection.getBalance(configPda());
+    const claimantBefore = await provider.connection.getBalance(claimant.publicKey);
+    const claimRent = await provider.connection.getBalance(counterClaimPda(video, claimant.publicKey));
+    await resolveClaim(video, claimant.publicKey, false);
+
+    expect((await provider.connection.getBalance(configPda())) - treasuryBefore).to.equal(BOND);
+    expect((await provider.connection.getBalance(claimant.publicKey)) - claimantBefore).to.equal(claimRent - BOND);
+    expect((await program.account.video.fetch(video)).frozen).to.equal(false);
+
+    // the claim is closed, so the claimant may file again
+    await fileClaim(video, claimant);
+  });
+
+  it("lets the admin withdraw forfeited bonds", async () => {
+    const video = await registerVideo(fixture);
+    const claimant = Keypair.generate();
+    await airdrop(claimant.publicKey);
+
+    await fileClaim(video, claimant);
+    await resolveClaim(video, claimant.publicKey, false);
+
+    const recipient = Keypair.generate().publicKey;
+    const treasuryBefore = await provider.connection.getBalance(configPda());
+    await withdrawTreasury(BOND, recipient);
+    expect(await provider.connection.getBalance(recipient)).to.equal(BOND);
+    expect(treasuryBefore - (await provider.connection.getBalance(configPda()))).to.equal(BOND);
+  });
+
+  it("refuses claims against a revoked video", async () => {
+    const video = await registerVideo(fixture);
+    const claimant = Keypair.generate();
+    await airdrop(claimant.publicKey);
+    await revokeVideo(fixture, video);
+
+    await expectError(fileClaim(video, claimant), "VideoRevoked");
+  });
+});
@@ -0,0 +1,81 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+function domainPda(domain: string): anchor.web3.PublicKey {
+  const hash = createHash("sha256").update(domain).digest();
+  return PublicKey.findProgramAddressSync([Buffer.from("domain"), hash], program.programId)[0];
+}
+
+function attest(fixture: OfficialFixture, domain: string) {
+  return program.methods
+    .attestDomain(domain)
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      domainIndex: domainPda(domain),
+      admin: admin.publicKey,
+    })
+    .rpc();
+}
+
+function freshDomain(): string {
+  return `health-${Keypair.generate().publicKey.toBase58().slice(0, 8).toLowerCase()}.gov.xx`;
+}
+
+describe("domain attestations", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("attests and revokes a domain", async () => {
+    const fixture = await createOfficial(3);
+    const domain = freshDomain();
+    await attest(fixture, domain);
+
+    let account = await program.account.official.fetch(fixture.official);
+    expect(Buffer.from(account.domain).toString("utf8").replace(/\0+$/, "")).to.equal(domain);
+    expect(account.domainAttestedBy.toBase58()).to.equal(admin.publicKey.toBase58());
+
+    await program.methods
+      .revokeDomainAttestation()
+      .accountsPartial({
+        config: configPda(),
+        official: fixture.official,
+        domainIndex: domainPda(domain),
+        admin: admin.publicKey,
+      })
+      .rpc();
+
+    account = await program.account.official.fetch(fixture.official);
+    expect(account.domainAttestedAt.toNumber()).to.equal(0);
+    expect(await program.account.domainIndex.fetchNullable(domainPda(domain))).to.equal(null);
+  });
+
+  it("refuses a domain already bound to another official", async () => {
+    const first = await createOfficial(3);
+    const second = await createOfficial(3);
+    const domain = freshDomain();
+    await attest(first, domain);
+
+    await expectError(attest(second, domain), "DomainAlreadyAttested");
+  });
+
+  it("rejects malformed domains", async () => {
+    const fixture = await createOfficial(3);
+    for (const bad of ["https://health.gov.xx", "Health.gov.xx", "localhost", "a..b", "-a.xx"]) {
+      await expectError(attest(fixture, bad), "InvalidDomain");
+    }
+  });
+});
@@ -0,0 +1,95 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  airdrop,
+  clockOverrideMeta,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  OfficialFixture,
+  nowSecs,
+  program,
+  requireClockOverride,
+  setClock,
+} from "./helpers";
+
+const { PublicKey } = anchor.web3;
+
+function profilePda(endorser: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("endorser"), endorser.toBuffer()], program.programId)[0];
+}
+
+function setWindow(secs: number) {
+  return program.methods
+    .setLivenessWindow(new BN(secs))
+    .accountsPartial({ config: configPda(), admin: admin.publicKey })
+    .rpc();
+}
+
+function heartbeat(endorser: anchor.web3.Keypair) {
+  return program.methods
+    .heartbeat()
+    .accountsPartial({ profile: profilePda(endorser.publicKey), endorser: endorser.publicKey })
+    .remainingAccounts([clockOverrideMeta()])
+    .signers([endorser])
+    .rpc();
+}
+
+function mark(fixture: OfficialFixture, slot: number, active: boolean) {
+  const accounts = {
+    config: configPda(),
+    official: fixture.official,
+    profile: profilePda(fixture.endorsers[slot].publicKey),
+  };
+  const method = active ? program.methods.markEndorserActive(slot) : program.methods.markEndorserInactive(slot);
+  return method.accountsPartial(accounts).remainingAccounts([clockOverrideMeta()]).rpc();
+}
+
+describe("endorser liveness", () => {
+  before(async function () {
+    await ensureConfig();
+    await requireClockOverride(this);
+    await setWindow(3600);
+  });
+
+  afterEach(async () => {
+    await setClock(0);
+  });
+
+  after(async () => {
+    await setWindow(7 * 24 * 60 * 60);
+  });
+
+  it("goes stale, gets marked inactive, and recovers on heartbeat", async () => {
+    const fixture = await createOfficial(3);
+    const endorser = fixture.endorsers[1];
+    await airdrop(endorser.publicKey);
+
+    const base = nowSecs();
+    await setClock(base);
+    await heartbeat(endorser);
+    await setClock(base + 3600);
+    await expectError(mark(fixture, 1, false), "EndorserStillLive");
+
+    await setClock(base + 3601);
+    const sig = await mark(fixture, 1, false);
+    expect((await program.account.official.fetch(fixture.official)).inactiveSlots).to.equal(0b10);
+    const alert = (await eventsFor(sig)).find((e) => e.name === "endorserMarkedInactive");
+    expect(alert!.data.endorser.toBase58()).to.equal(endorser.publicKey.toBase58());
+
+    await expectError(mark(fixture, 1, true), "EndorserStillStale");
+    await heartbeat(endorser);
+    await mark(fixture, 1, true);
+    expect((await program.account.official.fetch(fixture.official)).inactiveSlots).to.equal(0);
+  });
+
+  it("treats an endorser who never sent a heartbeat as stale", async () => {
+    const fixture = await createOfficial(3);
+    await mark(fixture, 0, false);
+    expect((await program.account.official.fetch(fixture.official)).inactiveSlots).to.equal(0b1);
+  });
+});
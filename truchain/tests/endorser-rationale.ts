@@ -0,0 +1,84 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  NO_RATIONALE,
+  OfficialFixture,
+  program,
+  randomRationale,
+  Rationale,
+} from "./helpers";
+
+const { Keypair } = anchor.web3;
+
+function setRequired(required: boolean) {
+  return program.methods
+    .setRequireEndorserRationale(required)
+    .accountsPartial({ config: configPda(), admin: admin.publicKey })
+    .rpc();
+}
+
+function schedule(fixture: OfficialFixture, rationale: Rationale) {
+  const panel = fixture.endorsers.map(() => Keypair.generate().publicKey);
+  return program.methods
+    .scheduleRotation(new BN(Math.floor(Date.now() / 1000) + 3600), panel, rationale.hash, rationale.cid)
+    .accountsPartial({ config: configPda(), official: fixture.official, admin: admin.publicKey })
+    .rpc();
+}
+
+describe("endorser rationale", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  after(async () => {
+    await setRequired(false);
+  });
+
+  it("is optional while the config flag is off", async () => {
+    await setRequired(false);
+    const fixture = await createOfficial(3);
+
+    const official = await program.account.official.fetch(fixture.official);
+    expect(official.endorserRationaleHash).to.deep.equal(NO_RATIONALE.hash);
+  });
+
+  it("is required at registration once the flag is on", async () => {
+    await setRequired(true);
+    await expectError(createOfficial(3), "MissingEndorserRationale");
+
+    const rationale = randomRationale();
+    const fixture = await createOfficial(3, "Test Official", undefined, rationale);
+
+    const official = await program.account.official.fetch(fixture.official);
+    expect(official.endorserRationaleHash).to.deep.equal(rationale.hash);
+    expect(Buffer.from(official.endorserRationaleCid).toString("utf8").replace(/\0+$/, "")).to.equal(rationale.cid);
+  });
+
+  it("rejects a hash without its CID", async () => {
+    await setRequired(false);
+    await expectError(
+      createOfficial(3, "Test Official", undefined, { hash: randomRationale().hash, cid: "" }),
+      "InvalidEndorserRationale"
+    );
+  });
+
+  it("requires a fresh rationale for a panel change", async () => {
+    await setRequired(true);
+    const current = randomRationale();
+    const fixture = await createOfficial(3, "Test Official", undefined, current);
+
+    await expectError(schedule(fixture, NO_RATIONALE), "MissingEndorserRationale");
+    await expectError(schedule(fixture, current), "StaleEndorserRationale");
+
+    const next = randomRationale();
+    await schedule(fixture, next);
+    const official = await program.account.official.fetch(fixture.official);
+    expect(official.scheduledRotation!.rationaleHash).to.deep.equal(next.hash);
+  });
+});
@@ -0,0 +1,140 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  clockOverrideMeta,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  expectError,
+  NO_RATIONALE,
+  nowSecs,
+  OfficialFixture,
+  program,
+  registerVideo,
+  requireClockOverride,
+  setClock,
+} from "./helpers";
+
+const { Keypair } = anchor.web3;
+
+function schedule(fixture: OfficialFixture, effectiveAt: number, panel: anchor.web3.PublicKey[]) {
+  return program.methods
+    .scheduleRotation(new BN(effectiveAt), panel, NO_RATIONALE.hash, NO_RATIONALE.cid)
+    .accountsPartial({ config: configPda(), official: fixture.official, admin: admin.publicKey })
+    .remainingAccounts([clockOverrideMeta()])
+    .rpc();
+}
+
+function apply(fixture: OfficialFixture, videos: anchor.web3.PublicKey[] = []) {
+  return program.methods
+    .applyScheduledRotation()
+    .accountsPartial({ official: fixture.official })
+    .remainingAccounts([
+      ...videos.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+      clockOverrideMeta(),
+    ])
+    .rpc();
+}
+
+// Schedules `panel` an hour out, then warps past it and cranks.
+async function rotate(fixture: OfficialFixture, panel: anchor.web3.PublicKey[], videos: anchor.web3.PublicKey[] = []) {
+  const base = nowSecs();
+  await setClock(base);
+  await schedule(fixture, base + 3600, panel);
+  await setClock(base + 3600);
+  await apply(fixture, videos);
+}
+
+describe("scheduled endorser rotation", () => {
+  before(async function () {
+    await ensureConfig();
+    await requireClockOverride(this);
+  });
+
+  afterEach(async () => {
+    await setClock(0);
+  });
+
+  it("swaps the panel at effective_at and purges stale votes", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const [stays, leaves] = fixture.endorsers;
+    await endorse(fixture, video, stays, true);
+    await endorse(fixture, video, leaves, false);
+
+    const incoming = [Keypair.generate(), Keypair.generate()];
+    const panel = [stays.publicKey, incoming[0].publicKey, incoming[1].publicKey];
+    const base = nowSecs();
+    await setClock(base);
+    await schedule(fixture, base + 3600, panel);
+
+    await setClock(base + 3599);
+    await expectError(apply(fixture, [video]), "RotationNotDue");
+
+    await setClock(base + 3600);
+    await apply(fixture, [video]);
+
+    const official = await program.account.official.fetch(fixture.official);
+    expect(official.endorsers.map((e) => e.toBase58())).to.deep.equal(panel.map((e) => e.toBase58()));
+    expect(official.scheduledRotation).to.equal(null);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.votes.map((v) => v.endorser.toBase58())).to.deep.equal([stays.publicKey.toBase58()]);
+  });
+
+  it("purges videos the crank wasn't given on their next vote", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const [first, second, stays] = fixture.endorsers;
+    await endorse(fixture, video, first, true);
+    await endorse(fixture, video, second, false);
+
+    const incoming = [Keypair.generate(), Keypair.generate()];
+    await rotate(fixture, [incoming[0].publicKey, incoming[1].publicKey, stays.publicKey]);
+
+    // without the purge the two stale votes would leave room for only one more
+    await endorse(fixture, video, incoming[0], true);
+    await endorse(fixture, video, incoming[1], true);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.status).to.deep.equal({ authentic: {} });
+    expect(account.votes.map((v) => v.endorser.toBase58())).to.deep.equal(
+      incoming.map((e) => e.publicKey.toBase58())
+    );
+  });
+
+  it("drops outgoing endorsers' threshold approvals", async () => {
+    const fixture = await createOfficial(3);
+    const [leaves, second, third] = fixture.endorsers;
+    await program.methods
+      .proposeThresholdChange(3)
+      .accountsPartial({ config: configPda(), official: fixture.official, proposer: fixture.authority.publicKey })
+      .signers([fixture.authority])
+      .rpc();
+    await program.methods
+      .approveThresholdChange()
+      .accountsPartial({ official: fixture.official, endorser: leaves.publicKey })
+      .signers([leaves])
+      .rpc();
+
+    await rotate(fixture, [Keypair.generate().publicKey, second.publicKey, third.publicKey]);
+
+    const official = await program.account.official.fetch(fixture.official);
+    expect(official.pendingThreshold!.approvals).to.deep.equal([]);
+    expect(official.threshold).to.equal(2);
+  });
+
+  it("validates the scheduled panel", async () => {
+    const fixture = await createOfficial(3);
+    const base = nowSecs();
+    await setClock(base);
+    const key = Keypair.generate().publicKey;
+
+    await expectError(schedule(fixture, base + 3600, [key, key, Keypair.generate().publicKey]), "DuplicateEndorsers");
+    await expectError(schedule(fixture, base + 3600, [key]), "InvalidEndorserCount");
+    await expectError(schedule(fixture, base, fixture.endorsers.map((e) => e.publicKey)), "InvalidRotationTime");
+  });
+});
@@ -0,0 +1,105 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { createOfficial, ensureConfig, eventsFor, expectError, program, randomHash } from "./helpers";
+
+const { Ed25519Program, Keypair, SYSVAR_INSTRUCTIONS_PUBKEY } = anchor.web3;
+
+const PGP = 1;
+const MINISIGN = 3;
+
+describe("external key binding", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("binds and rebinds a fingerprint, recording the old one", async () => {
+    const fixture = await createOfficial(3);
+    const first = randomHash();
+    const second = randomHash();
+
+    await program.methods
+      .bindExternalKey(PGP, first, false)
+      .accountsPartial({ official: fixture.official, authority: fixture.authority.publicKey, instructions: null })
+      .signers([fixture.authority])
+      .rpc();
+
+    let account = await program.account.official.fetch(fixture.official);
+    expect(account.externalKeyKind).to.equal(PGP);
+    expect(account.externalKeyFingerprint).to.deep.equal(first);
+
+    const sig = await program.methods
+      .bindExternalKey(PGP, second, false)
+      .accountsPartial({ official: fixture.official, authority: fixture.authority.publicKey, instructions: null })
+      .signers([fixture.authority])
+      .rpc();
+
+    account = await program.account.official.fetch(fixture.official);
+    expect(account.externalKeyFingerprint).to.deep.equal(second);
+
+    const bound = (await eventsFor(sig)).find((e) => e.name === "externalKeyBound");
+    expect(bound!.data.oldFingerprint).to.deep.equal(first);
+    expect(bound!.data.newFingerprint).to.deep.equal(second);
+  });
+
+  it("verifies a minisign key's signature over the official pubkey", async () => {
+    const fixture = await createOfficial(3);
+    const minisign = Keypair.generate();
+    const fingerprint = Array.from(minisign.publicKey.toBytes());
+
+    const proof = Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: minisign.secretKey,
+      message: fixture.official.toBytes(),
+    });
+
+    const sig = await program.methods
+      .bindExternalKey(MINISIGN, fingerprint, true)
+      .accountsPartial({
+        official: fixture.official,
+        authority: fixture.authority.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([proof])
+      .signers([fixture.authority])
+      .rpc();
+
+    const bound = (await eventsFor(sig)).find((e) => e.name === "externalKeyBound");
+    expect(bound!.data.proofVerified).to.equal(true);
+
+    // a signature over some other message doesn't count
+    const other = await createOfficial(3);
+    const wrongProof = Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: minisign.secretKey,
+      message: fixture.official.toBytes(),
+    });
+    await expectError(
+      program.methods
+        .bindExternalKey(MINISIGN, fingerprint, true)
+        .accountsPartial({
+          official: other.official,
+          authority: other.authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .preInstructions([wrongProof])
+        .signers([other.authority])
+        .rpc(),
+      "InvalidExternalKeyProof"
+    );
+  });
+
+  it("refuses a proof for key kinds that can't be checked on-chain", async () => {
+    const fixture = await createOfficial(3);
+
+    await expectError(
+      program.methods
+        .bindExternalKey(PGP, randomHash(), true)
+        .accountsPartial({
+          official: fixture.official,
+          authority: fixture.authority.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .signers([fixture.authority])
+        .rpc(),
+      "ExternalKeyProofUnsupported"
+    );
+  });
+});
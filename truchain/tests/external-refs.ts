@@ -0,0 +1,74 @@
+import { expect } from "chai";
+import {
+  cidPda,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  program,
+  randomCid,
+  randomHash,
+  refPda,
+  registerVideo,
+  videoPda,
+  VISIBILITY_PUBLIC,
+} from "./helpers";
+
+describe("external reference ids", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("resolves a ref to its video with one derived-address fetch", async () => {
+    const fixture = await createOfficial(3);
+    const externalRef = `PRESS-2024-${Math.floor(Math.random() * 10_000)}`;
+    const video = await registerVideo(fixture, randomHash(), randomCid(), VISIBILITY_PUBLIC, externalRef);
+
+    const index = await program.account.refIndex.fetch(refPda(fixture.official, externalRef));
+    expect(index.video.toBase58()).to.equal(video.toBase58());
+    expect(index.official.toBase58()).to.equal(fixture.official.toBase58());
+
+    const account = await program.account.video.fetch(video);
+    expect(Buffer.from(account.externalRef).toString("utf8").replace(/\0+$/, "")).to.equal(externalRef);
+  });
+
+  it("rejects a ref the official already used", async () => {
+    const fixture = await createOfficial(3);
+    await registerVideo(fixture, randomHash(), randomCid(), VISIBILITY_PUBLIC, "ARCHIVE-0001");
+
+    await expectError(
+      registerVideo(fixture, randomHash(), randomCid(), VISIBILITY_PUBLIC, "ARCHIVE-0001"),
+      "ExternalRefTaken"
+    );
+
+    // refs are scoped per official
+    const other = await createOfficial(3);
+    await registerVideo(other, randomHash(), randomCid(), VISIBILITY_PUBLIC, "ARCHIVE-0001");
+  });
+
+  it("rejects non-printable refs and a missing index account", async () => {
+    const fixture = await createOfficial(3);
+    await expectError(
+      registerVideo(fixture, randomHash(), randomCid(), VISIBILITY_PUBLIC, "bad\nref"),
+      "InvalidExternalRef"
+    );
+
+    const hash = randomHash();
+    const cid = randomCid();
+    await expectError(
+      program.methods
+        .registerVideo(hash, cid, VISIBILITY_PUBLIC, "ARCHIVE-0002")
+        .accountsPartial({
+          config: configPda(),
+          official: fixture.official,
+          video: videoPda(fixture.official, hash),
+          cidIndex: cidPda(fixture.official, cid),
+          refIndex: null,
+          authority: fixture.authority.publicKey,
+        })
+        .signers([fixture.authority])
+        .rpc(),
+      "RefIndexMismatch"
+    );
+  });
+});
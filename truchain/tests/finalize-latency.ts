@@ -0,0 +1,102 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  cidPda,
+  clockOverrideMeta,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  eventsFor,
+  headIndexPda,
+  OfficialFixture,
+  program,
+  randomCid,
+  randomHash,
+  requireClockOverride,
+  setClock,
+  videoPda,
+  VISIBILITY_PUBLIC,
+} from "./helpers";
+
+// Scripted timestamps need a `test-clock` build (`yarn test`); skipped
+// against any other.
+async function registerAt(fixture: OfficialFixture, timestamp: number): Promise<anchor.web3.PublicKey> {
+  await setClock(timestamp);
+  const hash = randomHash();
+  const cid = randomCid();
+  const video = videoPda(fixture.official, hash);
+  await program.methods
+    .registerVideo(hash, cid, VISIBILITY_PUBLIC, null)
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      video,
+      cidIndex: cidPda(fixture.official, cid),
+      refIndex: null,
+      authority: fixture.authority.publicKey,
+    })
+    .remainingAccounts([clockOverrideMeta()])
+    .signers([fixture.authority])
+    .rpc();
+  return video;
+}
+
+function endorseAt(fixture: OfficialFixture, video: anchor.web3.PublicKey, endorser: anchor.web3.Keypair) {
+  return program.methods
+    .endorseVideo(true, false, null)
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      video,
+      receipt: null,
+      endorser: endorser.publicKey,
+      cosigner: null,
+      statusIndex: headIndexPda(fixture.official, true),
+    })
+    .remainingAccounts([clockOverrideMeta()])
+    .signers([endorser])
+    .rpc();
+}
+
+async function finalizeAt(fixture: OfficialFixture, video: anchor.web3.PublicKey, timestamp: number) {
+  await setClock(timestamp);
+  await endorseAt(fixture, video, fixture.endorsers[0]);
+  return eventsFor(await endorseAt(fixture, video, fixture.endorsers[1]));
+}
+
+describe("finalization latency", () => {
+  before(async function () {
+    await ensureConfig();
+    await requireClockOverride(this);
+  });
+
+  after(async () => {
+    await setClock(0).catch(() => undefined);
+  });
+
+  it("tracks min, max and the fixed-point mean", async () => {
+    const fixture = await createOfficial(3);
+    const base = 1_700_000_000;
+
+    const first = await registerAt(fixture, base);
+    await finalizeAt(fixture, first, base + 100);
+
+    let official = await program.account.official.fetch(fixture.official);
+    expect(official.minFinalizeSecs.toNumber()).to.equal(100);
+    expect(official.maxFinalizeSecs.toNumber()).to.equal(100);
+    expect(official.meanFinalizeMillis.toNumber()).to.equal(100_000);
+
+    const second = await registerAt(fixture, base + 1_000);
+    const events = await finalizeAt(fixture, second, base + 1_000 + 251);
+
+    official = await program.account.official.fetch(fixture.official);
+    expect(official.minFinalizeSecs.toNumber()).to.equal(100);
+    expect(official.maxFinalizeSecs.toNumber()).to.equal(251);
+    expect(official.meanFinalizeMillis.toNumber()).to.equal(175_500);
+
+    const changed = events.find((e) => e.name === "statusChanged")!;
+    expect(changed.data.minFinalizeSecs.toNumber()).to.equal(100);
+    expect(changed.data.maxFinalizeSecs.toNumber()).to.equal(251);
+    expect(changed.data.meanFinalizeMillis.toNumber()).to.equal(175_500);
+  });
+});
@@ -0,0 +1,85 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+function handlePda(handle: string): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("handle"), Buffer.from(handle)], program.programId)[0];
+}
+
+function claim(fixture: OfficialFixture, handle: string) {
+  return program.methods
+    .claimHandle(handle)
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      handleAccount: handlePda(handle),
+      admin: admin.publicKey,
+    })
+    .rpc();
+}
+
+// unique per run so repeated test runs against one validator don't collide
+function freshHandle(prefix: string): string {
+  return `${prefix}-${Keypair.generate().publicKey.toBase58().slice(0, 8).toLowerCase()}`;
+}
+
+describe("handles", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("claims a unique handle and back-references it on the official", async () => {
+    const fixture = await createOfficial(3);
+    const other = await createOfficial(3);
+    const handle = freshHandle("health-ministry");
+
+    await claim(fixture, handle);
+
+    const account = await program.account.handle.fetch(handlePda(handle));
+    expect(account.official.toBase58()).to.equal(fixture.official.toBase58());
+    const official = await program.account.official.fetch(fixture.official);
+    expect(Buffer.from(official.handle).toString("utf8").replace(/\0+$/, "")).to.equal(handle);
+
+    await expectError(claim(other, handle), "already in use");
+  });
+
+  it("rejects malformed and reserved handles", async () => {
+    const fixture = await createOfficial(3);
+    for (const bad of ["ab", "-leading", "trailing-", "Upper-case", "admin", "spaces here"]) {
+      await expectError(claim(fixture, bad), "InvalidHandle");
+    }
+  });
+
+  it("moves a handle only through admin reassignment", async () => {
+    const from = await createOfficial(3);
+    const to = await createOfficial(3);
+    const handle = freshHandle("press-office");
+    await claim(from, handle);
+
+    await program.methods
+      .reassignHandle()
+      .accountsPartial({
+        config: configPda(),
+        handleAccount: handlePda(handle),
+        fromOfficial: from.official,
+        toOfficial: to.official,
+        admin: admin.publicKey,
+      })
+      .rpc();
+
+    const account = await program.account.handle.fetch(handlePda(handle));
+    expect(account.official.toBase58()).to.equal(to.official.toBase58());
+    const cleared = await program.account.official.fetch(from.official);
+    expect(cleared.handle.every((b: number) => b === 0)).to.equal(true);
+  });
+});
@@ -0,0 +1,94 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  airdrop,
+  cidPda,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  program,
+  randomHash,
+  videoPda,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+function hashAuthPda(official: anchor.web3.PublicKey, videoHash: number[]): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("hash_auth"), official.toBuffer(), Buffer.from(videoHash)],
+    program.programId
+  )[0];
+}
+
+describe("hash authorizations", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("lets a third party register an authorized hash exactly once", async () => {
+    const fixture = await createOfficial(3);
+    const fieldTeam = Keypair.generate();
+    await airdrop(fieldTeam.publicKey);
+
+    const hash = randomHash();
+    const authorization = hashAuthPda(fixture.official, hash);
+
+    await program.methods
+      .authorizeHashes([hash])
+      .accountsPartial({ official: fixture.official, authority: fixture.authority.publicKey })
+      .remainingAccounts([{ pubkey: authorization, isWritable: true, isSigner: false }])
+      .signers([fixture.authority])
+      .rpc();
+
+    const register = () =>
+      program.methods
+        .registerAuthorizedVideo(hash, "bafy-field-capture")
+        .accountsPartial({
+          config: configPda(),
+          official: fixture.official,
+          authorization,
+          authority: fixture.authority.publicKey,
+          video: videoPda(fixture.official, hash),
+          cidIndex: cidPda(fixture.official, "bafy-field-capture"),
+          payer: fieldTeam.publicKey,
+        })
+        .signers([fieldTeam])
+        .rpc();
+
+    await register();
+
+    const video = await program.account.video.fetch(videoPda(fixture.official, hash));
+    expect(video.official.toBase58()).to.equal(fixture.official.toBase58());
+    // the field team sponsored the account, so refunds go to them
+    expect(video.rentPayer.toBase58()).to.equal(fieldTeam.publicKey.toBase58());
+    expect(await program.account.hashAuthorization.fetchNullable(authorization)).to.equal(null);
+
+    // authorization was consumed by the first registration
+    await expectError(register(), "AccountNotInitialized");
+  });
+
+  it("rejects hashes that were never authorized", async () => {
+    const fixture = await createOfficial(3);
+    const stranger = Keypair.generate();
+    await airdrop(stranger.publicKey);
+    const hash = randomHash();
+
+    await expectError(
+      program.methods
+        .registerAuthorizedVideo(hash, "bafy-unapproved")
+        .accountsPartial({
+          config: configPda(),
+          official: fixture.official,
+          authorization: hashAuthPda(fixture.official, hash),
+          authority: fixture.authority.publicKey,
+          video: videoPda(fixture.official, hash),
+          cidIndex: cidPda(fixture.official, "bafy-unapproved"),
+          payer: stranger.publicKey,
+        })
+        .signers([stranger])
+        .rpc(),
+      "AccountNotInitialized"
+    );
+  });
+});
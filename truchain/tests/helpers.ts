@@ -0,0 +1,369 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { Truchain } from "../target/types/truchain";
+
+const { Keypair, PublicKey, LAMPORTS_PER_SOL } = anchor.web3;
+
+// Shared setup for every test file. The config PDA is a singleton, so all
+// files run against the same deployment and must tolerate it already existing.
+anchor.setProvider(anchor.AnchorProvider.env());
+
+export const provider = anchor.getProvider() as anchor.AnchorProvider;
+export const program = anchor.workspace.truchain as Program<Truchain>;
+export const admin = provider.wallet as anchor.Wallet;
+
+// ---------- PDAs ----------
+
+export function configPda(): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], program.programId)[0];
+}
+
+// The program's ProgramData account, which records its upgrade authority.
+export function programDataPda(): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    anchor.web3.BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  )[0];
+}
+
+export function officialPda(officialId: BN): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("official"), officialId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  )[0];
+}
+
+export function receiptPda(videoHash: number[], endorser: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("receipt"), Buffer.from(videoHash), endorser.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function videoPda(official: anchor.web3.PublicKey, videoHash: number[]): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("video"), official.toBuffer(), Buffer.from(videoHash)],
+    program.programId
+  )[0];
+}
+
+export function cidPda(official: anchor.web3.PublicKey, ipfsCid: string): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("cid"), official.toBuffer(), createHash("sha256").update(ipfsCid).digest()],
+    program.programId
+  )[0];
+}
+
+export function refPda(official: anchor.web3.PublicKey, externalRef: string): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("ref"), official.toBuffer(), createHash("sha256").update(externalRef).digest()],
+    program.programId
+  )[0];
+}
+
+export function statusIndexPda(official: anchor.web3.PublicKey, status: number, page: number): anchor.web3.PublicKey {
+  const pageBytes = Buffer.alloc(4);
+  pageBytes.writeUInt32LE(page);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("status_index"), official.toBuffer(), Buffer.from([status]), pageBytes],
+    program.programId
+  )[0];
+}
+
+export const STATUS_AUTHENTIC = 1;
+export const STATUS_DISPUTED = 2;
+
+// Head page of the bucket a vote in this direction can finalize a video into.
+export function headIndexPda(official: anchor.web3.PublicKey, isAuthentic: boolean): anchor.web3.PublicKey {
+  return statusIndexPda(official, isAuthentic ? STATUS_AUTHENTIC : STATUS_DISPUTED, 0);
+}
+
+// ---------- fixtures ----------
+
+// Official ids only need to be unique per validator run.
+let nextOfficialId = Math.floor(Math.random() * 1_000_000_000);
+export function uniqueOfficialId(): BN {
+  nextOfficialId += 1;
+  return new BN(nextOfficialId);
+}
+
+export function randomHash(): number[] {
+  return Array.from(Keypair.generate().publicKey.toBytes());
+}
+
+// A CID can only ever point at one hash per official, so fixtures need fresh ones.
+export function randomCid(): string {
+  return `bafy${Keypair.generate().publicKey.toBase58().toLowerCase()}`;
+}
+
+export async function airdrop(pubkey: anchor.web3.PublicKey, sol = 2): Promise<void> {
+  const sig = await provider.connection.requestAirdrop(pubkey, sol * LAMPORTS_PER_SOL);
+  const latest = await provider.connection.getLatestBlockhash();
+  await provider.connection.confirmTransaction({ signature: sig, ...latest });
+}
+
+// Creates the config on first use; afterwards just makes sure the cap is
+// large enough for the calling test.
+export async function ensureConfig(maxVotes = 10): Promise<void> {
+  const existing = await program.account.config.fetchNullable(configPda());
+  if (!existing) {
+    await program.methods
+      .initializeConfig(maxVotes)
+      .accountsPartial({ config: configPda(), admin: admin.publicKey, programData: programDataPda() })
+      .rpc();
+  } else if (existing.maxVotes < maxVotes) {
+    await program.methods
+      .updateMaxVotes(maxVotes)
+      .accountsPartial({ config: configPda(), admin: admin.publicKey })
+      .rpc();
+  }
+}
+
+export interface OfficialFixture {
+  officialId: BN;
+  official: anchor.web3.PublicKey;
+  authority: anchor.web3.Keypair;
+  endorsers: anchor.web3.Keypair[];
+}
+
+// Hash + CID of the document explaining why a panel was chosen.
+export interface Rationale {
+  hash: number[];
+  cid: string;
+}
+
+export const NO_RATIONALE: Rationale = { hash: Array(32).fill(0), cid: "" };
+
+export function randomRationale(): Rationale {
+  return { hash: randomHash(), cid: randomCid() };
+}
+
+export async function createOfficial(
+  endorserCount = 3,
+  name = "Test Official",
+  authority: anchor.web3.Keypair = Keypair.generate(),
+  rationale: Rationale = NO_RATIONALE,
+  threshold = Math.min(2, endorserCount)
+): Promise<OfficialFixture> {
+  const officialId = uniqueOfficialId();
+  const official = officialPda(officialId);
+  const endorsers = Array.from({ length: endorserCount }, () => Keypair.generate());
+
+  await airdrop(authority.publicKey);
+  await program.methods
+    .registerOfficial(
+      officialId,
+      name,
+      authority.publicKey,
+      endorsers.map((e) => e.publicKey),
+      threshold,
+      rationale.hash,
+      rationale.cid
+    )
+    .accountsPartial({ config: configPda(), official, admin: admin.publicKey })
+    .rpc();
+
+  // finalizing votes must land in a bucket, so open both head pages up front
+  for (const status of [STATUS_AUTHENTIC, STATUS_DISPUTED]) {
+    await program.methods
+      .openStatusIndex(status, 0)
+      .accountsPartial({ official, statusIndex: statusIndexPda(official, status, 0), previous: null })
+      .rpc();
+  }
+
+  return { officialId, official, authority, endorsers };
+}
+
+export const VISIBILITY_PUBLIC = 0;
+export const VISIBILITY_UNLISTED_UNTIL_FINAL = 1;
+
+export async function registerVideo(
+  fixture: OfficialFixture,
+  videoHash: number[] = randomHash(),
+  ipfsCid: string = randomCid(),
+  visibility = VISIBILITY_PUBLIC,
+  externalRef: string | null = null
+): Promise<anchor.web3.PublicKey> {
+  const video = videoPda(fixture.official, videoHash);
+  await program.methods
+    .registerVideo(videoHash, ipfsCid, visibility, externalRef)
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      video,
+      cidIndex: cidPda(fixture.official, ipfsCid),
+      refIndex: externalRef === null ? null : refPda(fixture.official, externalRef),
+      authority: fixture.authority.publicKey,
+    })
+    .signers([fixture.authority])
+    .rpc();
+  return video;
+}
+
+export async function endorse(
+  fixture: OfficialFixture,
+  video: anchor.web3.PublicKey,
+  endorser: anchor.web3.Keypair,
+  isAuthentic: boolean,
+  receipt: anchor.web3.PublicKey | null = null,
+  classification: number | null = null,
+  statusIndex: anchor.web3.PublicKey | null = headIndexPda(fixture.official, isAuthentic)
+): Promise<string> {
+  return program.methods
+    .endorseVideo(isAuthentic, receipt !== null, classification)
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      video,
+      receipt,
+      endorser: endorser.publicKey,
+      cosigner: null,
+      statusIndex,
+    })
+    .signers([endorser])
+    .rpc();
+}
+
+// ---------- program version ----------
+
+export function programVersionPda(): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("version")], program.programId)[0];
+}
+
+export interface Semver {
+  major: number;
+  minor: number;
+  patch: number;
+}
+
+// Null until the admin has published a version.
+export async function fetchProgramVersion() {
+  return program.account.programVersion.fetchNullable(programVersionPda());
+}
+
+// Throws if the deployed program is older than `required`. Callers that
+// don't care about the version simply skip this check.
+export async function requireProgramVersion(required: Semver): Promise<void> {
+  const deployed = await fetchProgramVersion();
+  const have = deployed ? [deployed.major, deployed.minor, deployed.patch] : [0, 0, 0];
+  const want = [required.major, required.minor, required.patch];
+  const cmp = have.map((v, i) => v - want[i]).find((d) => d !== 0) ?? 0;
+  if (cmp < 0) {
+    throw new Error(`program version ${have.join(".")} is older than required ${want.join(".")}`);
+  }
+}
+
+// Files an admin takedown on a video (reason code 451, court order).
+export function takeDown(video: anchor.web3.PublicKey): Promise<string> {
+  return program.methods
+    .fileTakedown(randomHash(), 451)
+    .accountsPartial({ config: configPda(), video, admin: admin.publicKey })
+    .rpc();
+}
+
+// Revokes a video that has no bond escrowed and no status index page.
+export function revokeVideo(fixture: OfficialFixture, video: anchor.web3.PublicKey): Promise<string> {
+  return program.methods
+    .revokeVideo()
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      video,
+      rentPayer: null,
+      statusIndex: null,
+      authority: fixture.authority.publicKey,
+    })
+    .signers([fixture.authority])
+    .rpc();
+}
+
+// Sweeps forfeited bonds out of the config treasury.
+export function withdrawTreasury(lamports: number, recipient: anchor.web3.PublicKey): Promise<string> {
+  return program.methods
+    .withdrawTreasury(new BN(lamports))
+    .accountsPartial({ config: configPda(), recipient, admin: admin.publicKey })
+    .rpc();
+}
+
+export const DEFAULT_BOND_SETTLE_DELAY_SECS = 3 * 24 * 60 * 60;
+
+// Shortens the wait between finalization and reclaim/slash; suites that
+// settle bonds drop it to 0 and restore the default afterwards.
+export function setBondSettleDelay(secs: number): Promise<string> {
+  return program.methods
+    .setBondSettleDelay(new BN(secs))
+    .accountsPartial({ config: configPda(), admin: admin.publicKey })
+    .rpc();
+}
+
+// ---------- clock override ----------
+
+// Only usable against a `test-clock` build, which `yarn test` produces.
+// Time-reading instructions honour it when clockOverrideMeta() is passed
+// as a remaining account; reset it to 0 when done.
+export function clockOverridePda(): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("clock_override")], program.programId)[0];
+}
+
+export function clockOverrideMeta(): anchor.web3.AccountMeta {
+  return { pubkey: clockOverridePda(), isSigner: false, isWritable: false };
+}
+
+export function setClock(timestamp: number): Promise<string> {
+  return program.methods
+    .setClockOverride(new BN(timestamp))
+    .accountsPartial({ config: configPda(), clockOverride: clockOverridePda(), admin: admin.publicKey })
+    .rpc();
+}
+
+export function nowSecs(): number {
+  return Math.floor(Date.now() / 1000);
+}
+
+// Call from a suite's before hook: skips it against builds without the override.
+export async function requireClockOverride(suite: Mocha.Context): Promise<void> {
+  try {
+    await setClock(0);
+  } catch (err: any) {
+    if (err?.error?.errorCode?.code === "ClockOverrideDisabled") {
+      suite.skip();
+    }
+    throw err;
+  }
+}
+
+// ---------- return data ----------
+
+// Expands a verify_bundle bitmap (bit i = hash i, LSB first) into booleans.
+export function decodeBundleBitmap(bitmap: number[] | Buffer, count: number): boolean[] {
+  return Array.from({ length: count }, (_, i) => ((bitmap[i >> 3] >> (i & 7)) & 1) === 1);
+}
+
+// ---------- events ----------
+
+// Decodes every Anchor event emitted by a confirmed transaction.
+export async function eventsFor(signature: string): Promise<anchor.Event[]> {
+  const latest = await provider.connection.getLatestBlockhash();
+  await provider.connection.confirmTransaction({ signature, ...latest }, "confirmed");
+  const tx = await provider.connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(program.programId, program.coder);
+  return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+}
+
+// ---------- assertions ----------
+
+export async function expectError(promise: Promise<unknown>, code: string): Promise<void> {
+  try {
+    await promise;
+  } catch (err: any) {
+    const actual = err?.error?.errorCode?.code ?? err?.message ?? String(err);
+    expect(actual).to.contain(code);
+    return;
+  }
+  expect.fail(`expected ${code} but the transaction succeeded`);
+}
@@ -0,0 +1,66 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { airdrop, expectError, program } from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+function labelHash(label: string): number[] {
+  return Array.from(createHash("sha256").update(label).digest());
+}
+
+function cursorPda(hash: number[]): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("cursor"), Buffer.from(hash)], program.programId)[0];
+}
+
+async function createCursor(label: string, owner: anchor.web3.Keypair): Promise<anchor.web3.PublicKey> {
+  const hash = labelHash(label);
+  const cursor = cursorPda(hash);
+  await program.methods
+    .createCursor(hash)
+    .accountsPartial({ cursor, owner: owner.publicKey })
+    .signers([owner])
+    .rpc();
+  return cursor;
+}
+
+describe("indexer cursors", () => {
+  it("only moves forward and only for its owner", async () => {
+    const owner = Keypair.generate();
+    const other = Keypair.generate();
+    await airdrop(owner.publicKey);
+    const cursor = await createCursor(`backfill-${owner.publicKey.toBase58()}`, owner);
+
+    await program.methods.bumpCursor(new BN(10)).accountsPartial({ cursor, owner: owner.publicKey }).signers([owner]).rpc();
+    expect((await program.account.indexerCursor.fetch(cursor)).lastSequence.toNumber()).to.equal(10);
+
+    await expectError(
+      program.methods.bumpCursor(new BN(10)).accountsPartial({ cursor, owner: owner.publicKey }).signers([owner]).rpc(),
+      "NonMonotonicCursor"
+    );
+    await expectError(
+      program.methods.bumpCursor(new BN(5)).accountsPartial({ cursor, owner: owner.publicKey }).signers([owner]).rpc(),
+      "NonMonotonicCursor"
+    );
+    await expectError(
+      program.methods.bumpCursor(new BN(11)).accountsPartial({ cursor, owner: other.publicKey }).signers([other]).rpc(),
+      "UnauthorizedCursorOwner"
+    );
+  });
+
+  it("keeps independent cursors apart", async () => {
+    const a = Keypair.generate();
+    const b = Keypair.generate();
+    await airdrop(a.publicKey);
+    await airdrop(b.publicKey);
+    const cursorA = await createCursor(`indexer-a-${a.publicKey.toBase58()}`, a);
+    const cursorB = await createCursor(`indexer-b-${b.publicKey.toBase58()}`, b);
+
+    await program.methods.bumpCursor(new BN(100)).accountsPartial({ cursor: cursorA, owner: a.publicKey }).signers([a]).rpc();
+    await program.methods.bumpCursor(new BN(3)).accountsPartial({ cursor: cursorB, owner: b.publicKey }).signers([b]).rpc();
+
+    expect((await program.account.indexerCursor.fetch(cursorA)).lastSequence.toNumber()).to.equal(100);
+    expect((await program.account.indexerCursor.fetch(cursorB)).lastSequence.toNumber()).to.equal(3);
+  });
+});
@@ -0,0 +1,59 @@
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  eventsFor,
+  OfficialFixture,
+  program,
+  registerVideo,
+} from "./helpers";
+
+async function finalize(fixture: OfficialFixture, authentic: boolean): Promise<string> {
+  const video = await registerVideo(fixture);
+  await endorse(fixture, video, fixture.endorsers[0], authentic);
+  return endorse(fixture, video, fixture.endorsers[1], authentic);
+}
+
+describe("integrity score", () => {
+  before(async () => {
+    await ensureConfig();
+    await program.methods
+      .setIntegrityParams(10_000, 10)
+      .accountsPartial({ config: configPda(), admin: admin.publicKey })
+      .rpc();
+  });
+
+  it("pins exact scores for a scripted history", async () => {
+    const fixture = await createOfficial(3);
+    const score = async () => (await program.account.official.fetch(fixture.official)).integrityScore;
+
+    expect(await score()).to.equal(100);
+
+    // 0 of 1 disputed
+    await finalize(fixture, true);
+    expect(await score()).to.equal(100);
+
+    // 1 of 2 disputed: 5000 bps -> 50 point penalty, alert fires
+    const sig = await finalize(fixture, false);
+    expect(await score()).to.equal(50);
+    const alert = (await eventsFor(sig)).find((e) => e.name === "integrityScoreChanged");
+    expect(alert!.data.oldScore).to.equal(100);
+    expect(alert!.data.newScore).to.equal(50);
+
+    // 1 of 3 disputed: 3333 bps -> 33 point penalty
+    await finalize(fixture, true);
+    expect(await score()).to.equal(67);
+
+    // 1 of 4 disputed: 2500 bps -> 25 point penalty, 8 point move stays quiet
+    const quiet = await finalize(fixture, true);
+    expect(await score()).to.equal(75);
+    expect((await eventsFor(quiet)).some((e) => e.name === "integrityScoreChanged")).to.equal(false);
+
+    const official = await program.account.official.fetch(fixture.official);
+    expect(official.finalizedCount).to.equal(4);
+    expect(official.disputedCount).to.equal(1);
+  });
+});
@@ -0,0 +1,90 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  createOfficial,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  OfficialFixture,
+  program,
+  registerVideo,
+  revokeVideo,
+} from "./helpers";
+
+const { PublicKey } = anchor.web3;
+
+const IPFS = 0;
+const ARWEAVE = 1;
+const HTTPS = 2;
+
+function mirrorsPda(video: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("mirrors"), video.toBuffer()], program.programId)[0];
+}
+
+function addMirror(fixture: OfficialFixture, video: anchor.web3.PublicKey, kind: number, uri: string) {
+  return program.methods
+    .addMirror(kind, uri)
+    .accountsPartial({
+      official: fixture.official,
+      video,
+      mirrors: mirrorsPda(video),
+      authority: fixture.authority.publicKey,
+    })
+    .signers([fixture.authority])
+    .rpc();
+}
+
+describe("mirrors", () => {
+  let fixture: OfficialFixture;
+  let video: anchor.web3.PublicKey;
+
+  before(async () => {
+    await ensureConfig();
+    fixture = await createOfficial(3);
+    video = await registerVideo(fixture);
+  });
+
+  it("adds up to three mirrors and emits an event for each", async () => {
+    const sig = await addMirror(fixture, video, IPFS, "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku");
+    await addMirror(fixture, video, ARWEAVE, "bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U");
+    await addMirror(fixture, video, HTTPS, "https://mirror.example.org/videos/speech.mp4");
+
+    const events = await eventsFor(sig);
+    expect(events.map((e) => e.name)).to.include("mirrorAdded");
+
+    const account = await program.account.mirrors.fetch(mirrorsPda(video));
+    expect(account.mirrors.length).to.equal(3);
+
+    await expectError(
+      addMirror(fixture, video, HTTPS, "https://another.example.org/speech.mp4"),
+      "TooManyMirrors"
+    );
+  });
+
+  it("rejects duplicate URIs and malformed pointers", async () => {
+    await program.methods
+      .removeMirror(2)
+      .accountsPartial({
+        official: fixture.official,
+        video,
+        mirrors: mirrorsPda(video),
+        authority: fixture.authority.publicKey,
+      })
+      .signers([fixture.authority])
+      .rpc();
+
+    await expectError(
+      addMirror(fixture, video, ARWEAVE, "bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U"),
+      "DuplicateMirror"
+    );
+    await expectError(addMirror(fixture, video, HTTPS, "http://insecure.example.org"), "InvalidMirrorUri");
+    await expectError(addMirror(fixture, video, 9, "whatever"), "InvalidMirrorKind");
+  });
+
+  it("refuses mirrors for a revoked video", async () => {
+    const revoked = await registerVideo(fixture);
+    await revokeVideo(fixture, revoked);
+
+    await expectError(addMirror(fixture, revoked, IPFS, "bafy-revoked"), "VideoRevoked");
+  });
+});
@@ -0,0 +1,151 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  airdrop,
+  clockOverrideMeta,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  nowSecs,
+  program,
+  randomHash,
+  registerVideo,
+  requireClockOverride,
+  revokeVideo,
+  setClock,
+  takeDown,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+function providerPda(providerKey: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync([Buffer.from("provider"), providerKey.toBuffer()], program.programId)[0];
+}
+
+function pinPda(video: anchor.web3.PublicKey, provider: anchor.web3.PublicKey): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pin"), video.toBuffer(), provider.toBuffer()],
+    program.programId
+  )[0];
+}
+
+async function registerProvider(name: string): Promise<anchor.web3.Keypair> {
+  const key = Keypair.generate();
+  await airdrop(key.publicKey);
+  await program.methods
+    .registerProvider(name, key.publicKey, randomHash())
+    .accountsPartial({ config: configPda(), provider: providerPda(key.publicKey), admin: admin.publicKey })
+    .rpc();
+  return key;
+}
+
+async function now(): Promise<number> {
+  const slot = await program.provider.connection.getSlot();
+  return (await program.provider.connection.getBlockTime(slot))!;
+}
+
+function attest(key: anchor.web3.Keypair, video: anchor.web3.PublicKey, expiresAt: number) {
+  const provider = providerPda(key.publicKey);
+  return program.methods
+    .attestPin(new BN(expiresAt))
+    .accountsPartial({ provider, video, pinReceipt: pinPda(video, provider), providerKey: key.publicKey })
+    .remainingAccounts([clockOverrideMeta()])
+    .signers([key])
+    .rpc();
+}
+
+describe("pinning providers", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("counts receipts per provider and stops revoked providers", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const other = await registerVideo(fixture);
+    const alpha = await registerProvider("Alpha Pinning");
+    const beta = await registerProvider("Beta Pinning");
+
+    const later = (await now()) + 3600;
+    await attest(alpha, video, later);
+    await attest(alpha, other, later);
+    await attest(beta, video, later);
+
+    expect((await program.account.provider.fetch(providerPda(alpha.publicKey))).activeReceipts).to.equal(2);
+    expect((await program.account.provider.fetch(providerPda(beta.publicKey))).activeReceipts).to.equal(1);
+
+    await program.methods
+      .revokeProvider()
+      .accountsPartial({ config: configPda(), provider: providerPda(beta.publicKey), admin: admin.publicKey })
+      .rpc();
+
+    expect((await program.account.provider.fetch(providerPda(beta.publicKey))).revoked).to.equal(true);
+    await expectError(attest(beta, other, later), "ProviderRevoked");
+
+    // its unexpired receipt is void, so the crank can close it right away
+    const betaProvider = providerPda(beta.publicKey);
+    await program.methods
+      .collectPinReceipt()
+      .accountsPartial({ provider: betaProvider, pinReceipt: pinPda(video, betaProvider), providerKey: beta.publicKey })
+      .rpc();
+    expect(await program.account.pinReceipt.fetchNullable(pinPda(video, betaProvider))).to.equal(null);
+    expect((await program.account.provider.fetch(betaProvider)).activeReceipts).to.equal(0);
+  });
+
+  it("refuses receipts for taken-down videos", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const key = await registerProvider("Delta Pinning");
+    await takeDown(video);
+
+    await expectError(attest(key, video, (await now()) + 3600), "VideoTakenDown");
+  });
+
+  it("refuses receipts for revoked videos", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const key = await registerProvider("Epsilon Pinning");
+    await revokeVideo(fixture, video);
+
+    await expectError(attest(key, video, (await now()) + 3600), "VideoRevoked");
+  });
+
+  describe("with the clock override", () => {
+    before(async function () {
+      await requireClockOverride(this);
+    });
+
+    afterEach(async () => {
+      await setClock(0);
+    });
+
+    it("collects expired receipts and refunds the provider", async () => {
+      const fixture = await createOfficial(3);
+      const video = await registerVideo(fixture);
+      const key = await registerProvider("Gamma Pinning");
+      const provider = providerPda(key.publicKey);
+
+      const base = nowSecs();
+      await setClock(base);
+      await attest(key, video, base + 60);
+
+      const collect = () =>
+        program.methods
+          .collectPinReceipt()
+          .accountsPartial({ provider, pinReceipt: pinPda(video, provider), providerKey: key.publicKey })
+          .remainingAccounts([clockOverrideMeta()])
+          .rpc();
+
+      await setClock(base + 59);
+      await expectError(collect(), "PinReceiptNotExpired");
+      await setClock(base + 60);
+      await collect();
+
+      expect((await program.account.provider.fetch(provider)).activeReceipts).to.equal(0);
+      expect(await program.account.pinReceipt.fetchNullable(pinPda(video, provider))).to.equal(null);
+    });
+  });
+});
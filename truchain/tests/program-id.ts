@@ -0,0 +1,60 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { existsSync, readFileSync } from "fs";
+import { admin, configPda, ensureConfig, program, provider } from "./helpers";
+
+// Which Anchor.toml [programs.<cluster>] section the current provider targets.
+function clusterName(endpoint: string): string {
+  if (endpoint.includes("devnet")) return "devnet";
+  if (endpoint.includes("mainnet")) return "mainnet";
+  return "localnet";
+}
+
+function configuredId(cluster: string): string | undefined {
+  const toml = readFileSync(`${__dirname}/../Anchor.toml`, "utf8");
+  const section = toml.split(`[programs.${cluster}]`)[1]?.split("[")[0] ?? "";
+  return section.match(/truchain\s*=\s*"(\w+)"/)?.[1];
+}
+
+function keypairAddress(path: string): string {
+  const secret = Uint8Array.from(JSON.parse(readFileSync(path, "utf8")));
+  return anchor.web3.Keypair.fromSecretKey(secret).publicKey.toBase58();
+}
+
+describe("program id", () => {
+  const cluster = clusterName(provider.connection.rpcEndpoint);
+
+  // program.programId comes from the IDL, i.e. the crate::ID the binary was built with
+  it("was built for the target cluster", () => {
+    expect(program.programId.toBase58()).to.equal(configuredId(cluster));
+  });
+
+  it("was deployed from the keypair matching its ID", function () {
+    if (cluster !== "localnet") this.skip();
+
+    const fixture = `${__dirname}/fixtures/truchain-localnet-keypair.json`;
+    expect(keypairAddress(fixture)).to.equal(program.programId.toBase58());
+
+    const deployed = `${__dirname}/../target/deploy/truchain-keypair.json`;
+    if (existsSync(deployed)) {
+      expect(keypairAddress(deployed)).to.equal(program.programId.toBase58());
+    }
+  });
+
+  it("runs at that address and derives the same PDAs as the client", async () => {
+    const info = await provider.connection.getAccountInfo(program.programId);
+    expect(info?.executable).to.equal(true);
+
+    // a binary built with another cluster's ID fails every instruction with
+    // DeclaredProgramIdMismatch, and would have created config elsewhere
+    await ensureConfig();
+    const config = await program.account.config.fetch(configPda());
+    expect((await provider.connection.getAccountInfo(configPda()))!.owner.toBase58()).to.equal(
+      program.programId.toBase58()
+    );
+    await program.methods
+      .updateMaxVotes(config.maxVotes)
+      .accountsPartial({ config: configPda(), admin: admin.publicKey })
+      .simulate();
+  });
+});
@@ -0,0 +1,60 @@
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  ensureConfig,
+  expectError,
+  fetchProgramVersion,
+  program,
+  programVersionPda,
+  randomHash,
+  requireProgramVersion,
+} from "./helpers";
+
+function setVersion(major: number, minor: number, patch: number, minAccountVersion = 1, changelog = randomHash()) {
+  return program.methods
+    .setProgramVersion(major, minor, patch, minAccountVersion, changelog)
+    .accountsPartial({ config: configPda(), programVersion: programVersionPda(), admin: admin.publicKey })
+    .rpc();
+}
+
+describe("program version", () => {
+  // Start above whatever an earlier run on the same validator published.
+  let major: number;
+
+  before(async () => {
+    await ensureConfig();
+    major = ((await fetchProgramVersion())?.major ?? 0) + 1;
+  });
+
+  it("publishes and bumps the version", async () => {
+    const changelog = randomHash();
+    await setVersion(major, 0, 0, 1, changelog);
+
+    let version = (await fetchProgramVersion())!;
+    expect([version.major, version.minor, version.patch]).to.deep.equal([major, 0, 0]);
+    expect(version.minSupportedAccountVersion).to.equal(1);
+    expect(version.changelogHash).to.deep.equal(changelog);
+    expect(version.upgradeSlot.toNumber()).to.be.greaterThan(0);
+
+    await setVersion(major, 1, 0, 2);
+    version = (await fetchProgramVersion())!;
+    expect([version.major, version.minor, version.patch]).to.deep.equal([major, 1, 0]);
+    expect(version.minSupportedAccountVersion).to.equal(2);
+
+    await requireProgramVersion({ major, minor: 1, patch: 0 });
+    try {
+      await requireProgramVersion({ major, minor: 2, patch: 0 });
+      expect.fail("expected the version check to fail");
+    } catch (err: any) {
+      expect(err.message).to.contain("older than required");
+    }
+  });
+
+  it("rejects regressions", async () => {
+    await expectError(setVersion(major, 0, 9), "VersionRegression");
+    await expectError(setVersion(major, 1, 0), "VersionRegression");
+    await expectError(setVersion(major - 1, 9, 9), "VersionRegression");
+    await setVersion(major, 1, 1);
+  });
+});
@@ -0,0 +1,64 @@
+import { expect } from "chai";
+import { createOfficial, endorse, ensureConfig, expectError, NO_RATIONALE, program, registerVideo } from "./helpers";
+
+async function status(video: Parameters<typeof program.account.video.fetch>[0]) {
+  return (await program.account.video.fetch(video)).status;
+}
+
+describe("quorum threshold", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("finalizes a 3-of-5 panel on the third matching vote", async () => {
+    const fixture = await createOfficial(5, "Five Panel", undefined, NO_RATIONALE, 3);
+    expect((await program.account.official.fetch(fixture.official)).threshold).to.equal(3);
+    const [a, b, c, d, e] = fixture.endorsers;
+
+    const authentic = await registerVideo(fixture);
+    await endorse(fixture, authentic, a, true);
+    await endorse(fixture, authentic, b, false);
+    await endorse(fixture, authentic, c, true);
+    expect(await status(authentic)).to.deep.equal({ unverified: {} });
+    await endorse(fixture, authentic, d, true);
+    expect(await status(authentic)).to.deep.equal({ authentic: {} });
+
+    // 3 fake votes leave only 2 endorsers, so 3 authentic is out of reach
+    const disputed = await registerVideo(fixture);
+    await endorse(fixture, disputed, a, false);
+    await endorse(fixture, disputed, b, false);
+    expect(await status(disputed)).to.deep.equal({ unverified: {} });
+    await endorse(fixture, disputed, e, false);
+    expect(await status(disputed)).to.deep.equal({ disputed: {} });
+  });
+
+  it("finalizes a 1-of-1 panel on its only vote", async () => {
+    const fixture = await createOfficial(1, "Solo Panel", undefined, NO_RATIONALE, 1);
+    const [solo] = fixture.endorsers;
+
+    const authentic = await registerVideo(fixture);
+    await endorse(fixture, authentic, solo, true);
+    expect(await status(authentic)).to.deep.equal({ authentic: {} });
+
+    const disputed = await registerVideo(fixture);
+    await endorse(fixture, disputed, solo, false);
+    expect(await status(disputed)).to.deep.equal({ disputed: {} });
+  });
+
+  it("keeps 2-of-3 behaviour when every endorser votes fake", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+
+    await endorse(fixture, video, fixture.endorsers[0], false);
+    expect(await status(video)).to.deep.equal({ unverified: {} });
+    await endorse(fixture, video, fixture.endorsers[1], false);
+    expect(await status(video)).to.deep.equal({ disputed: {} });
+    await endorse(fixture, video, fixture.endorsers[2], false);
+    expect(await status(video)).to.deep.equal({ disputed: {} });
+  });
+
+  it("rejects thresholds outside 1..=panel size", async () => {
+    await expectError(createOfficial(3, "Zero", undefined, NO_RATIONALE, 0), "InvalidThreshold");
+    await expectError(createOfficial(3, "Four", undefined, NO_RATIONALE, 4), "InvalidThreshold");
+  });
+});
@@ -0,0 +1,167 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  DEFAULT_BOND_SETTLE_DELAY_SECS,
+  endorse,
+  ensureConfig,
+  expectError,
+  headIndexPda,
+  OfficialFixture,
+  program,
+  provider,
+  registerVideo,
+  setBondSettleDelay,
+  withdrawTreasury,
+} from "./helpers";
+
+const BOND = 50_000_000;
+
+function setBond(lamports: number) {
+  return program.methods
+    .setRegistrationBond(new BN(lamports))
+    .accountsPartial({ config: configPda(), admin: admin.publicKey })
+    .rpc();
+}
+
+function reclaim(video: anchor.web3.PublicKey, bondPayer: anchor.web3.PublicKey) {
+  return program.methods.reclaimBond().accountsPartial({ video, rentPayer: bondPayer }).rpc();
+}
+
+function slash(video: anchor.web3.PublicKey) {
+  return program.methods.slashBond().accountsPartial({ config: configPda(), video }).rpc();
+}
+
+describe("registration bonds", () => {
+  let fixture: OfficialFixture;
+
+  before(async () => {
+    await ensureConfig();
+    fixture = await createOfficial(3);
+    await setBond(BOND);
+    await setBondSettleDelay(0);
+  });
+
+  after(async () => {
+    // other suites share the config
+    await setBond(0);
+    await setBondSettleDelay(DEFAULT_BOND_SETTLE_DELAY_SECS);
+  });
+
+  it("escrows the bond and returns it after Authentic", async () => {
+    const video = await registerVideo(fixture);
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(
+      (await provider.connection.getAccountInfo(video))!.data.length
+    );
+    expect(await provider.connection.getBalance(video)).to.equal(rent + BOND);
+
+    await expectError(reclaim(video, fixture.authority.publicKey), "VideoNotAuthentic");
+
+    await endorse(fixture, video, fixture.endorsers[0], true);
+    await endorse(fixture, video, fixture.endorsers[1], true);
+
+    const before = await provider.connection.getBalance(fixture.authority.publicKey);
+    await reclaim(video, fixture.authority.publicKey);
+    expect((await provider.connection.getBalance(fixture.authority.publicKey)) - before).to.equal(BOND);
+    expect(await provider.connection.getBalance(video)).to.equal(rent);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.bondState).to.deep.equal({ reclaimed: {} });
+    await expectError(reclaim(video, fixture.authority.publicKey), "BondNotEscrowed");
+  });
+
+  it("slashes the bond to the treasury after Disputed", async () => {
+    const video = await registerVideo(fixture);
+    await endorse(fixture, video, fixture.endorsers[0], false);
+    await endorse(fixture, video, fixture.endorsers[1], false);
+
+    await expectError(reclaim(video, fixture.authority.publicKey), "VideoNotAuthentic");
+
+    const treasuryBefore = await provider.connection.getBalance(configPda());
+    await slash(video);
+    expect((await provider.connection.getBalance(configPda())) - treasuryBefore).to.equal(BOND);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.bondState).to.deep.equal({ slashed: {} });
+    await expectError(slash(video), "BondNotEscrowed");
+  });
+
+  it("lets the admin withdraw slashed bonds from the treasury", async () => {
+    const video = await registerVideo(fixture);
+    await endorse(fixture, video, fixture.endorsers[0], false);
+    await endorse(fixture, video, fixture.endorsers[1], false);
+    await slash(video);
+
+    const recipient = anchor.web3.Keypair.generate().publicKey;
+    await withdrawTreasury(BOND, recipient);
+    expect(await provider.connection.getBalance(recipient)).to.equal(BOND);
+
+    // the config account itself must stay rent-exempt
+    const balance = await provider.connection.getBalance(configPda());
+    await expectError(withdrawTreasury(balance, recipient), "InsufficientTreasury");
+
+    const outsider = anchor.web3.Keypair.generate();
+    await expectError(
+      program.methods
+        .withdrawTreasury(new BN(1))
+        .accountsPartial({ config: configPda(), recipient, admin: outsider.publicKey })
+        .signers([outsider])
+        .rpc(),
+      "UnauthorizedAdmin"
+    );
+  });
+
+  it("leaves Unverified bonds in escrow", async () => {
+    const video = await registerVideo(fixture);
+    await expectError(slash(video), "VideoNotDisputed");
+    expect((await program.account.video.fetch(video)).bondState).to.deep.equal({ escrowed: {} });
+  });
+
+  it("holds the bond through the settle delay so the verdict can still change", async () => {
+    const video = await registerVideo(fixture);
+    await endorse(fixture, video, fixture.endorsers[0], true);
+    await endorse(fixture, video, fixture.endorsers[1], true);
+
+    await setBondSettleDelay(60 * 60);
+    try {
+      await expectError(reclaim(video, fixture.authority.publicKey), "BondSettleDelayActive");
+      await program.methods
+        .changeVote(false)
+        .accountsPartial({
+          config: configPda(),
+          official: fixture.official,
+          video,
+          receipt: null,
+          endorser: fixture.endorsers[1].publicKey,
+          cosigner: null,
+          statusIndex: null,
+          oldStatusIndex: headIndexPda(fixture.official, true),
+        })
+        .signers([fixture.endorsers[1]])
+        .rpc();
+    } finally {
+      await setBondSettleDelay(0);
+    }
+
+    const account = await program.account.video.fetch(video);
+    expect(account.status).to.deep.equal({ unverified: {} });
+    expect(account.bondState).to.deep.equal({ escrowed: {} });
+    await expectError(reclaim(video, fixture.authority.publicKey), "VideoNotAuthentic");
+  });
+
+  it("only lets the admin change the settle delay", async () => {
+    await expectError(setBondSettleDelay(-1), "InvalidBondSettleDelay");
+    const outsider = anchor.web3.Keypair.generate();
+    await expectError(
+      program.methods
+        .setBondSettleDelay(new BN(0))
+        .accountsPartial({ config: configPda(), admin: outsider.publicKey })
+        .signers([outsider])
+        .rpc(),
+      "UnauthorizedAdmin"
+    );
+  });
+});
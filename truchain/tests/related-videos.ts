@@ -0,0 +1,105 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  createOfficial,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  OfficialFixture,
+  program,
+  registerVideo,
+  revokeVideo,
+  takeDown,
+} from "./helpers";
+
+function link(
+  a: OfficialFixture,
+  videoA: anchor.web3.PublicKey,
+  b: OfficialFixture,
+  videoB: anchor.web3.PublicKey
+) {
+  return program.methods
+    .linkSameEvent(videoB)
+    .accountsPartial({
+      official: a.official,
+      video: videoA,
+      otherOfficial: b.official,
+      linkedVideo: videoB,
+      authority: a.authority.publicKey,
+      otherAuthority: b.authority.publicKey,
+    })
+    .signers(a === b ? [a.authority] : [a.authority, b.authority])
+    .rpc();
+}
+
+describe("same-event links", () => {
+  let ministry: OfficialFixture;
+  let press: OfficialFixture;
+
+  before(async () => {
+    await ensureConfig();
+    ministry = await createOfficial(3, "Ministry");
+    press = await createOfficial(3, "Press Office");
+  });
+
+  it("writes reciprocal links signed by both authorities", async () => {
+    const angleA = await registerVideo(ministry);
+    const angleB = await registerVideo(press);
+
+    const sig = await link(ministry, angleA, press, angleB);
+    const event = (await eventsFor(sig)).find((e) => e.name === "videosLinked");
+    expect(event!.data.video.toBase58()).to.equal(angleA.toBase58());
+    expect(event!.data.otherVideo.toBase58()).to.equal(angleB.toBase58());
+
+    const a = await program.account.video.fetch(angleA);
+    const b = await program.account.video.fetch(angleB);
+    expect(a.relatedVideos.map((k) => k.toBase58())).to.deep.equal([angleB.toBase58()]);
+    expect(b.relatedVideos.map((k) => k.toBase58())).to.deep.equal([angleA.toBase58()]);
+
+    await expectError(link(press, angleB, ministry, angleA), "AlreadyLinked");
+  });
+
+  it("rejects self-links", async () => {
+    const video = await registerVideo(ministry);
+    await expectError(link(ministry, video, ministry, video), "CannotLinkSelf");
+  });
+
+  it("requires the other official's authority", async () => {
+    const angleA = await registerVideo(ministry);
+    const angleB = await registerVideo(press);
+
+    await expectError(
+      program.methods
+        .linkSameEvent(angleB)
+        .accountsPartial({
+          official: ministry.official,
+          video: angleA,
+          otherOfficial: press.official,
+          linkedVideo: angleB,
+          authority: ministry.authority.publicKey,
+          otherAuthority: ministry.authority.publicKey,
+        })
+        .signers([ministry.authority])
+        .rpc(),
+      "UnauthorizedOfficial"
+    );
+  });
+
+  it("refuses to link a taken-down video", async () => {
+    const angleA = await registerVideo(ministry);
+    const angleB = await registerVideo(press);
+    await takeDown(angleB);
+
+    await expectError(link(ministry, angleA, press, angleB), "VideoTakenDown");
+    await expectError(link(press, angleB, ministry, angleA), "VideoTakenDown");
+  });
+
+  it("refuses to link a revoked video", async () => {
+    const angleA = await registerVideo(ministry);
+    const angleB = await registerVideo(press);
+    await revokeVideo(press, angleB);
+
+    await expectError(link(ministry, angleA, press, angleB), "VideoRevoked");
+    await expectError(link(press, angleB, ministry, angleA), "VideoRevoked");
+  });
+});
@@ -0,0 +1,133 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { VerifiedConsumer } from "../target/types/verified_consumer";
+import {
+  clockOverrideMeta,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+  registerVideo,
+  requireClockOverride,
+  setClock,
+} from "./helpers";
+
+// Test-only program (programs/verified-consumer) that calls require_verified
+// over CPI before doing anything.
+const consumer = anchor.workspace.verifiedConsumer as Program<VerifiedConsumer>;
+
+const AUTHENTIC = 1;
+const DISPUTED = 2;
+
+async function authenticVideo(fixture: OfficialFixture): Promise<anchor.web3.PublicKey> {
+  const video = await registerVideo(fixture);
+  await endorse(fixture, video, fixture.endorsers[0], true);
+  await endorse(fixture, video, fixture.endorsers[1], true);
+  return video;
+}
+
+function gatedAction(video: anchor.web3.PublicKey, maxAgeSecs: number, status: number) {
+  return consumer.methods
+    .gatedAction(new anchor.BN(maxAgeSecs), status)
+    .accountsPartial({ video, truchainProgram: program.programId })
+    .remainingAccounts([clockOverrideMeta()])
+    .rpc();
+}
+
+describe("require_verified", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("passes for a freshly finalized video with the required status", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    await endorse(fixture, video, fixture.endorsers[0], true);
+    await endorse(fixture, video, fixture.endorsers[1], true);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.finalizedAt.toNumber()).to.be.greaterThan(0);
+
+    await program.methods.requireVerified(new anchor.BN(3600), AUTHENTIC).accountsPartial({ video }).rpc();
+    await program.methods.requireVerified(new anchor.BN(0), AUTHENTIC).accountsPartial({ video }).rpc();
+  });
+
+  it("fails when the status does not match", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+
+    await expectError(
+      program.methods.requireVerified(new anchor.BN(3600), AUTHENTIC).accountsPartial({ video }).rpc(),
+      "VerificationRequirementNotMet"
+    );
+
+    await endorse(fixture, video, fixture.endorsers[0], true);
+    await endorse(fixture, video, fixture.endorsers[1], true);
+    await expectError(
+      program.methods.requireVerified(new anchor.BN(3600), DISPUTED).accountsPartial({ video }).rpc(),
+      "VerificationRequirementNotMet"
+    );
+  });
+
+  it("rejects unknown status values", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+
+    await expectError(
+      program.methods.requireVerified(new anchor.BN(0), 7).accountsPartial({ video }).rpc(),
+      "InvalidStatusValue"
+    );
+    // Revoked (3) is a status but not a verdict to gate on
+    await expectError(
+      program.methods.requireVerified(new anchor.BN(0), 3).accountsPartial({ video }).rpc(),
+      "InvalidStatusValue"
+    );
+  });
+
+  describe("with the clock override", () => {
+    before(async function () {
+      await requireClockOverride(this);
+    });
+
+    afterEach(async () => {
+      await setClock(0);
+    });
+
+    it("fails when the verification is older than the allowed age", async () => {
+      const fixture = await createOfficial(3);
+      const video = await authenticVideo(fixture);
+      const finalizedAt = (await program.account.video.fetch(video)).finalizedAt.toNumber();
+      const check = () =>
+        program.methods
+          .requireVerified(new anchor.BN(3600), AUTHENTIC)
+          .accountsPartial({ video })
+          .remainingAccounts([clockOverrideMeta()])
+          .rpc();
+
+      await setClock(finalizedAt + 3600);
+      await check();
+      await setClock(finalizedAt + 3601);
+      await expectError(check(), "VerificationTooOld");
+    });
+
+    it("gates a consumer program through CPI", async () => {
+      const fixture = await createOfficial(3);
+      const video = await authenticVideo(fixture);
+      const finalizedAt = (await program.account.video.fetch(video)).finalizedAt.toNumber();
+
+      await setClock(finalizedAt + 3600);
+      await gatedAction(video, 3600, AUTHENTIC);
+
+      // truchain's error surfaces as the consumer's failure
+      await expectError(gatedAction(video, 3600, DISPUTED), "VerificationRequirementNotMet");
+      await setClock(finalizedAt + 3601);
+      await expectError(gatedAction(video, 3600, AUTHENTIC), "VerificationTooOld");
+
+      const pending = await registerVideo(fixture);
+      await expectError(gatedAction(pending, 0, AUTHENTIC), "VerificationRequirementNotMet");
+    });
+  });
+});
@@ -0,0 +1,77 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  airdrop,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+  randomHash,
+  registerVideo,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+function snapshotPda(official: anchor.web3.PublicKey, epoch: number): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("snapshot"), official.toBuffer(), Buffer.from([epoch % 4])],
+    program.programId
+  )[0];
+}
+
+describe("official snapshots", () => {
+  const snapshotter = Keypair.generate();
+
+  function publish(fixture: OfficialFixture, epoch: number, root: number[], count: number) {
+    return program.methods
+      .publishSnapshot(root, new BN(count))
+      .accountsPartial({
+        config: configPda(),
+        official: fixture.official,
+        snapshot: snapshotPda(fixture.official, epoch),
+        snapshotter: snapshotter.publicKey,
+      })
+      .signers([snapshotter])
+      .rpc();
+  }
+
+  before(async () => {
+    await ensureConfig();
+    await airdrop(snapshotter.publicKey);
+    await program.methods
+      .setSnapshotter(snapshotter.publicKey)
+      .accountsPartial({ config: configPda(), admin: admin.publicKey })
+      .rpc();
+  });
+
+  it("publishes successive snapshots into the ring", async () => {
+    const fixture = await createOfficial(3);
+    await registerVideo(fixture);
+    await registerVideo(fixture);
+
+    const first = randomHash();
+    await publish(fixture, 0, first, 2);
+
+    await registerVideo(fixture);
+    const second = randomHash();
+    await publish(fixture, 1, second, 3);
+
+    const s0 = await program.account.snapshot.fetch(snapshotPda(fixture.official, 0));
+    const s1 = await program.account.snapshot.fetch(snapshotPda(fixture.official, 1));
+    expect(s0.merkleRoot).to.deep.equal(first);
+    expect(s0.videoCount.toNumber()).to.equal(2);
+    expect(s1.epoch.toNumber()).to.equal(1);
+    expect(s1.videoCount.toNumber()).to.equal(3);
+  });
+
+  it("rejects a video count that doesn't match the chain", async () => {
+    const fixture = await createOfficial(3);
+    await registerVideo(fixture);
+
+    await expectError(publish(fixture, 0, randomHash(), 5), "SnapshotCountMismatch");
+  });
+});
@@ -0,0 +1,66 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import * as fs from "fs";
+import { createOfficial, ensureConfig, expectError, OfficialFixture, program } from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+// Mocked name registry loaded into the test validator from Anchor.toml;
+// its owner field is the sns-owner keypair.
+const NAME_ACCOUNT = new PublicKey("55z7wZ7RTQNGHxrnmQ3AiTnf7ay5WXydQzvyn6MapnuW");
+const snsOwner = Keypair.fromSecretKey(
+  Uint8Array.from(JSON.parse(fs.readFileSync("tests/fixtures/sns-owner.json", "utf8")))
+);
+
+function link(fixture: OfficialFixture, nameAccount: anchor.web3.PublicKey) {
+  return program.methods
+    .linkSnsDomain(nameAccount)
+    .accountsPartial({
+      official: fixture.official,
+      authority: fixture.authority.publicKey,
+      nameAccountInfo: nameAccount,
+    })
+    .signers([fixture.authority])
+    .rpc();
+}
+
+describe("sns domain links", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("links a domain owned by the authority and keeps a valid link", async () => {
+    const fixture = await createOfficial(3, "Ministry", snsOwner);
+    await link(fixture, NAME_ACCOUNT);
+
+    const account = await program.account.official.fetch(fixture.official);
+    expect(account.snsNameAccount.toBase58()).to.equal(NAME_ACCOUNT.toBase58());
+
+    await expectError(
+      program.methods
+        .invalidateSnsLink()
+        .accountsPartial({ official: fixture.official, nameAccountInfo: NAME_ACCOUNT })
+        .rpc(),
+      "SnsLinkStillValid"
+    );
+
+    await program.methods
+      .unlinkSnsDomain()
+      .accountsPartial({ official: fixture.official, authority: fixture.authority.publicKey })
+      .signers([fixture.authority])
+      .rpc();
+    expect((await program.account.official.fetch(fixture.official)).snsNameAccount.toBase58()).to.equal(
+      PublicKey.default.toBase58()
+    );
+  });
+
+  it("refuses a domain owned by someone else", async () => {
+    const fixture = await createOfficial(3);
+    await expectError(link(fixture, NAME_ACCOUNT), "SnsOwnerMismatch");
+  });
+
+  it("refuses accounts that aren't name registries", async () => {
+    const fixture = await createOfficial(3);
+    await expectError(link(fixture, fixture.official), "InvalidSnsNameAccount");
+  });
+});
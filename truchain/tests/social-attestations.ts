@@ -0,0 +1,93 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  OfficialFixture,
+  program,
+} from "./helpers";
+
+const { Keypair, PublicKey } = anchor.web3;
+
+const X = 0;
+const TELEGRAM = 2;
+
+function socialPda(platform: number, handle: string): anchor.web3.PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("social"), Buffer.from([platform]), Buffer.from(handle)],
+    program.programId
+  )[0];
+}
+
+function attest(
+  fixture: OfficialFixture,
+  platform: number,
+  handle: string,
+  previous: anchor.web3.PublicKey | null = null
+) {
+  return program.methods
+    .attestSocial(platform, handle)
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      socialIndex: socialPda(platform, handle),
+      previousSocialIndex: previous,
+      admin: admin.publicKey,
+    })
+    .rpc();
+}
+
+function freshHandle(length = 12): string {
+  return `h${Keypair.generate().publicKey.toBase58().toLowerCase().replace(/[^a-z0-9]/g, "")}`.slice(0, length);
+}
+
+describe("social attestations", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("attests, replaces and revokes a platform handle", async () => {
+    const fixture = await createOfficial(3);
+    const first = freshHandle();
+    const second = freshHandle();
+
+    await attest(fixture, X, first);
+    const sig = await attest(fixture, X, second, socialPda(X, first));
+
+    const account = await program.account.official.fetch(fixture.official);
+    expect(account.socialAttestations.length).to.equal(1);
+    expect(await program.account.socialIndex.fetchNullable(socialPda(X, first))).to.equal(null);
+
+    const attested = (await eventsFor(sig)).find((e) => e.name === "socialAttested");
+    expect(attested!.data.replacedHandle).to.equal(first);
+
+    await program.methods
+      .revokeSocial()
+      .accountsPartial({
+        config: configPda(),
+        official: fixture.official,
+        socialIndex: socialPda(X, second),
+        admin: admin.publicKey,
+      })
+      .rpc();
+    expect((await program.account.official.fetch(fixture.official)).socialAttestations.length).to.equal(0);
+  });
+
+  it("prevents two officials claiming the same account", async () => {
+    const first = await createOfficial(3);
+    const second = await createOfficial(3);
+    const handle = freshHandle(20);
+
+    await attest(first, TELEGRAM, handle);
+    await expectError(attest(second, TELEGRAM, handle), "SocialHandleTaken");
+  });
+
+  it("rejects handles that aren't normalized", async () => {
+    const fixture = await createOfficial(3);
+    await expectError(attest(fixture, X, "@HealthMinistry"), "InvalidSocialHandle");
+  });
+});
@@ -0,0 +1,96 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  airdrop,
+  clockOverrideMeta,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+  registerVideo,
+  requireClockOverride,
+  setClock,
+} from "./helpers";
+
+const { Keypair } = anchor.web3;
+
+function setActivationSecs(secs: number) {
+  return program.methods
+    .setStandbyActivationSecs(new BN(secs))
+    .accountsPartial({ config: configPda(), admin: admin.publicKey })
+    .rpc();
+}
+
+function setStandby(fixture: OfficialFixture, standby: anchor.web3.PublicKey) {
+  return program.methods
+    .setStandbyEndorser(standby)
+    .accountsPartial({ config: configPda(), official: fixture.official, admin: admin.publicKey })
+    .rpc();
+}
+
+function activate(fixture: OfficialFixture, video: anchor.web3.PublicKey) {
+  return program.methods
+    .activateStandby()
+    .accountsPartial({ config: configPda(), official: fixture.official, video })
+    .remainingAccounts([clockOverrideMeta()])
+    .rpc();
+}
+
+describe("standby endorser", () => {
+  before(async function () {
+    await ensureConfig();
+    await requireClockOverride(this);
+    await setActivationSecs(3600);
+  });
+
+  afterEach(async () => {
+    await setClock(0);
+  });
+
+  it("rejects activation before the waiting period", async () => {
+    const fixture = await createOfficial(3);
+    await setStandby(fixture, Keypair.generate().publicKey);
+    const video = await registerVideo(fixture);
+    const registeredAt = (await program.account.video.fetch(video)).timestamp.toNumber();
+
+    await setClock(registeredAt + 3599);
+    await expectError(activate(fixture, video), "StandbyNotDue");
+  });
+
+  it("lets the standby complete a split vote", async () => {
+    const fixture = await createOfficial(3);
+    const standby = Keypair.generate();
+    await airdrop(standby.publicKey);
+    await setStandby(fixture, standby.publicKey);
+
+    const video = await registerVideo(fixture);
+    const [yes, no] = fixture.endorsers;
+    await endorse(fixture, video, yes, true);
+    await endorse(fixture, video, no, false);
+
+    // not active yet: the standby isn't a panelist
+    await expectError(endorse(fixture, video, standby, true), "UnauthorizedEndorser");
+
+    const registeredAt = (await program.account.video.fetch(video)).timestamp.toNumber();
+    await setClock(registeredAt + 3600);
+    await activate(fixture, video);
+    await endorse(fixture, video, standby, true);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.status).to.deep.equal({ authentic: {} });
+    expect(account.votes[2].endorser.toBase58()).to.equal(standby.publicKey.toBase58());
+
+    // the silent panelist's slot has been filled
+    await expectError(endorse(fixture, video, fixture.endorsers[2], true), "TooManyVotes");
+  });
+
+  it("refuses a standby that duplicates the panel", async () => {
+    const fixture = await createOfficial(3);
+    await expectError(setStandby(fixture, fixture.endorsers[0].publicKey), "DuplicateEndorsers");
+  });
+});
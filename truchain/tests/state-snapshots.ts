@@ -0,0 +1,67 @@
+import { expect } from "chai";
+import {
+  createOfficial,
+  endorse,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  program,
+  registerVideo,
+} from "./helpers";
+
+// BN / PublicKey / byte arrays all compare cleanly once serialized
+function normalize(value: unknown): unknown {
+  return JSON.parse(JSON.stringify(value));
+}
+
+describe("emit_current_state", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("round-trips every field of a populated video and its official", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const other = await registerVideo(fixture);
+    await endorse(fixture, video, fixture.endorsers[0], true, null, 1);
+    await endorse(fixture, video, fixture.endorsers[1], true, null, 1);
+    await program.methods
+      .linkSameEvent(other)
+      .accountsPartial({
+        official: fixture.official,
+        video,
+        otherOfficial: fixture.official,
+        linkedVideo: other,
+        authority: fixture.authority.publicKey,
+        otherAuthority: fixture.authority.publicKey,
+      })
+      .signers([fixture.authority])
+      .rpc();
+
+    const sig = await program.methods
+      .emitCurrentState()
+      .accountsPartial({ official: fixture.official, video })
+      .rpc();
+    const events = await eventsFor(sig);
+
+    const videoSnapshot = events.find((e) => e.name === "videoSnapshot")!;
+    const officialSnapshot = events.find((e) => e.name === "officialSnapshot")!;
+    expect(videoSnapshot.data.video.toBase58()).to.equal(video.toBase58());
+    expect(normalize(videoSnapshot.data.state)).to.deep.equal(
+      normalize(await program.account.video.fetch(video))
+    );
+    expect(normalize(officialSnapshot.data.state)).to.deep.equal(
+      normalize(await program.account.official.fetch(fixture.official))
+    );
+
+    // synthetic: no organic event rides along
+    expect(events.map((e) => e.name).sort()).to.deep.equal(["officialSnapshot", "videoSnapshot"]);
+  });
+
+  it("requires at least one account", async () => {
+    await expectError(
+      program.methods.emitCurrentState().accountsPartial({ official: null, video: null }).rpc(),
+      "NothingToEmit"
+    );
+  });
+});
@@ -0,0 +1,96 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  createOfficial,
+  endorse,
+  ensureConfig,
+  expectError,
+  OfficialFixture,
+  program,
+  registerVideo,
+  statusIndexPda,
+} from "./helpers";
+
+const AUTHENTIC = 1;
+const DISPUTED = 2;
+
+function openPage(fixture: OfficialFixture, status: number, page: number) {
+  return program.methods
+    .openStatusIndex(status, page)
+    .accountsPartial({
+      official: fixture.official,
+      statusIndex: statusIndexPda(fixture.official, status, page),
+      previous: page === 0 ? null : statusIndexPda(fixture.official, status, page - 1),
+    })
+    .rpc();
+}
+
+// Registers a video and finalizes it, appending to `index`.
+async function finalize(
+  fixture: OfficialFixture,
+  isAuthentic: boolean,
+  index: anchor.web3.PublicKey
+): Promise<anchor.web3.PublicKey> {
+  const video = await registerVideo(fixture);
+  await endorse(fixture, video, fixture.endorsers[0], isAuthentic, null, null, index);
+  await endorse(fixture, video, fixture.endorsers[1], isAuthentic, null, null, index);
+  return video;
+}
+
+describe("status indexes", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("buckets finalized videos by outcome", async () => {
+    // createOfficial opens the head page of both buckets
+    const fixture = await createOfficial(3);
+    const authenticIndex = statusIndexPda(fixture.official, AUTHENTIC, 0);
+    const disputedIndex = statusIndexPda(fixture.official, DISPUTED, 0);
+
+    const a1 = await finalize(fixture, true, authenticIndex);
+    const d1 = await finalize(fixture, false, disputedIndex);
+    const a2 = await finalize(fixture, true, authenticIndex);
+
+    // wrong bucket for the outcome
+    const video = await registerVideo(fixture);
+    await endorse(fixture, video, fixture.endorsers[0], true);
+    await expectError(
+      endorse(fixture, video, fixture.endorsers[1], true, null, null, disputedIndex),
+      "StatusIndexMismatch"
+    );
+    // and no bucket at all
+    await expectError(
+      endorse(fixture, video, fixture.endorsers[1], true, null, null, null),
+      "StatusIndexRequired"
+    );
+
+    const authentic = await program.account.statusIndex.fetch(authenticIndex);
+    const disputed = await program.account.statusIndex.fetch(disputedIndex);
+    expect(authentic.videos.map((v) => v.toBase58())).to.deep.equal([a1.toBase58(), a2.toBase58()]);
+    expect(disputed.videos.map((v) => v.toBase58())).to.deep.equal([d1.toBase58()]);
+  });
+
+  it("continues a full page on a linked continuation", async () => {
+    const fixture = await createOfficial(3);
+    const head = statusIndexPda(fixture.official, AUTHENTIC, 0);
+
+    await expectError(openPage(fixture, AUTHENTIC, 1), "StatusIndexNotFull");
+
+    for (let i = 0; i < 64; i++) {
+      await finalize(fixture, true, head);
+    }
+    await expectError(finalize(fixture, true, head), "StatusIndexFull");
+
+    await openPage(fixture, AUTHENTIC, 1);
+    const continuation = statusIndexPda(fixture.official, AUTHENTIC, 1);
+    const overflow = await finalize(fixture, true, continuation);
+
+    const first = await program.account.statusIndex.fetch(head);
+    const second = await program.account.statusIndex.fetch(continuation);
+    expect(first.videos.length).to.equal(64);
+    expect(first.next.toBase58()).to.equal(continuation.toBase58());
+    expect(second.page).to.equal(1);
+    expect(second.videos.map((v) => v.toBase58())).to.deep.equal([overflow.toBase58()]);
+  });
+});
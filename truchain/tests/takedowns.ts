@@ -0,0 +1,84 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  program,
+  randomHash,
+  registerVideo,
+} from "./helpers";
+
+const { PublicKey } = anchor.web3;
+
+const COURT_ORDER = 451;
+
+describe("takedowns", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("hides a video without touching its record, then lifts", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    await endorse(fixture, video, fixture.endorsers[0], true);
+
+    const requester = randomHash();
+    const sig = await program.methods
+      .fileTakedown(requester, COURT_ORDER)
+      .accountsPartial({ config: configPda(), video, admin: admin.publicKey })
+      .rpc();
+
+    const events = await eventsFor(sig);
+    const filed = events.find((e) => e.name === "takedownFiled");
+    expect(filed!.data.reasonCode).to.equal(COURT_ORDER);
+
+    const account = await program.account.video.fetch(video);
+    expect(account.takedown).to.equal(true);
+    expect(account.votes.length).to.equal(1);
+    expect(account.status).to.deep.equal({ unverified: {} });
+
+    await expectError(endorse(fixture, video, fixture.endorsers[1], true), "VideoTakenDown");
+
+    const mirrors = PublicKey.findProgramAddressSync(
+      [Buffer.from("mirrors"), video.toBuffer()],
+      program.programId
+    )[0];
+    await expectError(
+      program.methods
+        .addMirror(2, "https://mirror.example.org/speech.mp4")
+        .accountsPartial({ official: fixture.official, video, mirrors, authority: fixture.authority.publicKey })
+        .signers([fixture.authority])
+        .rpc(),
+      "VideoTakenDown"
+    );
+
+    await program.methods
+      .liftTakedown()
+      .accountsPartial({ config: configPda(), video, admin: admin.publicKey })
+      .rpc();
+    await endorse(fixture, video, fixture.endorsers[1], true);
+
+    const lifted = await program.account.video.fetch(video);
+    expect(lifted.takedown).to.equal(false);
+    expect(lifted.status).to.deep.equal({ authentic: {} });
+  });
+
+  it("is admin-gated", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+
+    await expectError(
+      program.methods
+        .fileTakedown(randomHash(), COURT_ORDER)
+        .accountsPartial({ config: configPda(), video, admin: fixture.authority.publicKey })
+        .signers([fixture.authority])
+        .rpc(),
+      "UnauthorizedAdmin"
+    );
+  });
+});
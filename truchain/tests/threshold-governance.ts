@@ -0,0 +1,116 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  clockOverrideMeta,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  nowSecs,
+  OfficialFixture,
+  program,
+  registerVideo,
+  requireClockOverride,
+  setClock,
+} from "./helpers";
+
+function setWindow(secs: number) {
+  return program.methods
+    .setThresholdProposalWindow(new BN(secs))
+    .accountsPartial({ config: configPda(), admin: admin.publicKey })
+    .rpc();
+}
+
+function propose(fixture: OfficialFixture, newThreshold: number) {
+  return program.methods
+    .proposeThresholdChange(newThreshold)
+    .accountsPartial({ config: configPda(), official: fixture.official, proposer: fixture.authority.publicKey })
+    .remainingAccounts([clockOverrideMeta()])
+    .signers([fixture.authority])
+    .rpc();
+}
+
+function approve(fixture: OfficialFixture, endorser: anchor.web3.Keypair) {
+  return program.methods
+    .approveThresholdChange()
+    .accountsPartial({ official: fixture.official, endorser: endorser.publicKey })
+    .remainingAccounts([clockOverrideMeta()])
+    .signers([endorser])
+    .rpc();
+}
+
+describe("threshold governance", () => {
+  before(async function () {
+    await ensureConfig();
+    await requireClockOverride(this);
+    await setWindow(3600);
+  });
+
+  afterEach(async () => {
+    await setClock(0);
+  });
+
+  it("applies a change once a majority of the panel approves", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second, third] = fixture.endorsers;
+    expect((await program.account.official.fetch(fixture.official)).threshold).to.equal(2);
+
+    const earlier = await registerVideo(fixture);
+
+    await propose(fixture, 3);
+    await expectError(propose(fixture, 1), "ThresholdChangePending");
+    await approve(fixture, first);
+    await expectError(approve(fixture, first), "ThresholdAlreadyApproved");
+    expect((await program.account.official.fetch(fixture.official)).threshold).to.equal(2);
+
+    const events = await eventsFor(await approve(fixture, second));
+    const official = await program.account.official.fetch(fixture.official);
+    expect(official.threshold).to.equal(3);
+    expect(official.pendingThreshold).to.equal(null);
+
+    const changed = events.find((e) => e.name === "thresholdChanged")!;
+    expect(changed.data.oldThreshold).to.equal(2);
+    expect(changed.data.newThreshold).to.equal(3);
+    expect(changed.data.approvers.length).to.equal(2);
+
+    // the earlier video still finalizes at 2-of-3
+    await endorse(fixture, earlier, first, true);
+    await endorse(fixture, earlier, second, true);
+    const video = await program.account.video.fetch(earlier);
+    expect(video.thresholdAtRegistration).to.equal(2);
+    expect(video.status).to.deep.equal({ authentic: {} });
+
+    // a later one needs all three
+    const later = await registerVideo(fixture);
+    await endorse(fixture, later, first, true);
+    await endorse(fixture, later, second, true);
+    expect((await program.account.video.fetch(later)).status).to.deep.equal({ unverified: {} });
+    await endorse(fixture, later, third, true);
+    expect((await program.account.video.fetch(later)).status).to.deep.equal({ authentic: {} });
+
+    await expectError(approve(fixture, third), "NoThresholdChange");
+  });
+
+  it("rejects thresholds outside 1..=panel size", async () => {
+    const fixture = await createOfficial(3);
+    await expectError(propose(fixture, 0), "InvalidThreshold");
+    await expectError(propose(fixture, 4), "InvalidThreshold");
+  });
+
+  it("rejects approvals after the window", async () => {
+    const fixture = await createOfficial(3);
+    const base = nowSecs();
+    await setClock(base);
+    await propose(fixture, 1);
+
+    await setClock(base + 3601);
+    await expectError(approve(fixture, fixture.endorsers[0]), "ThresholdChangeExpired");
+
+    // an expired proposal doesn't block a fresh one
+    await propose(fixture, 1);
+  });
+});
@@ -1,16 +1,101 @@
-import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
-import { Truchain } from "../target/types/truchain";
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  expectError,
+  NO_RATIONALE,
+  officialPda,
+  program,
+  randomHash,
+  registerVideo,
+} from "./helpers";
 
 describe("truchain", () => {
-  // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+  before(async () => {
+    await ensureConfig(10);
+  });
+
+  it("sizes videos from each official's endorser count", async () => {
+    const five = await createOfficial(5, "Five Panel");
+    const two = await createOfficial(2, "Two Panel");
+
+    const fiveVideo = await registerVideo(five);
+    const twoVideo = await registerVideo(two);
+
+    const fiveAccount = await program.account.video.fetch(fiveVideo);
+    const twoAccount = await program.account.video.fetch(twoVideo);
+    expect(fiveAccount.maxVotesAtRegistration).to.equal(5);
+    expect(twoAccount.maxVotesAtRegistration).to.equal(2);
+
+    const fiveInfo = await program.provider.connection.getAccountInfo(fiveVideo);
+    const twoInfo = await program.provider.connection.getAccountInfo(twoVideo);
+    expect(fiveInfo!.data.length - twoInfo!.data.length).to.equal(3 * 35);
+  });
+
+  it("accepts votes up to the per-video cap", async () => {
+    const five = await createOfficial(5);
+    const video = await registerVideo(five);
+
+    for (const endorser of five.endorsers) {
+      await endorse(five, video, endorser, true);
+    }
+
+    const account = await program.account.video.fetch(video);
+    expect(account.votes.length).to.equal(5);
+  });
+
+  it("rejects panels larger than config.max_votes", async () => {
+    const config = await program.account.config.fetch(configPda());
+    await expectError(createOfficial(config.maxVotes + 1), "InvalidEndorserCount");
+  });
+
+  it("rejects videos whose threshold exceeds a lowered vote cap", async () => {
+    const five = await createOfficial(5, "Strict Panel", undefined, undefined, 4);
+    const config = await program.account.config.fetch(configPda());
+    const setCap = (maxVotes: number) =>
+      program.methods.updateMaxVotes(maxVotes).accountsPartial({ config: configPda(), admin: admin.publicKey }).rpc();
+
+    await setCap(3);
+    try {
+      await expectError(registerVideo(five), "ThresholdExceedsVoteCap");
+    } finally {
+      await setCap(config.maxVotes);
+    }
+    await registerVideo(five);
+  });
+
+  it("rejects re-registering the same hash with VideoAlreadyExists", async () => {
+    const fixture = await createOfficial(3);
+    const hash = randomHash();
+    await registerVideo(fixture, hash);
+
+    await expectError(registerVideo(fixture, hash), "VideoAlreadyExists");
+  });
 
-  const program = anchor.workspace.truchain as Program<Truchain>;
+  it("rejects reusing an official id with OfficialAlreadyExists", async () => {
+    const fixture = await createOfficial(3);
 
-  it("Is initialized!", async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+    await expectError(
+      program.methods
+        .registerOfficial(
+          fixture.officialId,
+          "Impostor",
+          admin.publicKey,
+          fixture.endorsers.map((e) => e.publicKey),
+          2,
+          NO_RATIONALE.hash,
+          NO_RATIONALE.cid
+        )
+        .accountsPartial({
+          config: configPda(),
+          official: officialPda(fixture.officialId),
+          admin: admin.publicKey,
+        })
+        .rpc(),
+      "OfficialAlreadyExists"
+    );
   });
 });
@@ -0,0 +1,109 @@
+import { expect } from "chai";
+import {
+  cidPda,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  program,
+  randomCid,
+  randomHash,
+  registerVideo,
+  revokeVideo,
+  videoPda,
+  VISIBILITY_PUBLIC,
+  VISIBILITY_UNLISTED_UNTIL_FINAL,
+} from "./helpers";
+
+describe("unlisted videos", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  async function registerWithEvents(visibility: number) {
+    const fixture = await createOfficial(3);
+    const hash = randomHash();
+    const cid = randomCid();
+    const video = videoPda(fixture.official, hash);
+    const sig = await program.methods
+      .registerVideo(hash, cid, visibility, null)
+      .accountsPartial({
+        config: configPda(),
+        official: fixture.official,
+        video,
+        cidIndex: cidPda(fixture.official, cid),
+        refIndex: null,
+        authority: fixture.authority.publicKey,
+      })
+      .signers([fixture.authority])
+      .rpc();
+    const registered = (await eventsFor(sig)).find((e) => e.name === "videoRegistered")!;
+    return { fixture, hash, cid, video, registered };
+  }
+
+  it("announces public videos with their CID", async () => {
+    const { cid, registered, video } = await registerWithEvents(VISIBILITY_PUBLIC);
+
+    expect(registered.data.ipfsCid).to.equal(cid);
+    expect((await program.account.video.fetch(video)).listed).to.equal(true);
+  });
+
+  it("withholds the CID until the video finalizes", async () => {
+    const { fixture, hash, cid, video, registered } = await registerWithEvents(VISIBILITY_UNLISTED_UNTIL_FINAL);
+
+    expect(registered.data.ipfsCid).to.equal(null);
+    expect(registered.data.videoHash).to.deep.equal(hash);
+    expect((await program.account.video.fetch(video)).listed).to.equal(false);
+
+    const [first, second] = fixture.endorsers;
+    const early = await eventsFor(await endorse(fixture, video, first, true));
+    expect(early.some((e) => e.name === "videoListed")).to.equal(false);
+
+    const deciding = await eventsFor(await endorse(fixture, video, second, true));
+    const listed = deciding.find((e) => e.name === "videoListed")!;
+    expect(listed.data.ipfsCid).to.equal(cid);
+    expect((await program.account.video.fetch(video)).listed).to.equal(true);
+  });
+
+  it("lets the authority list early, once", async () => {
+    const { fixture, cid, video } = await registerWithEvents(VISIBILITY_UNLISTED_UNTIL_FINAL);
+    const list = () =>
+      program.methods
+        .listVideo()
+        .accountsPartial({ official: fixture.official, video, authority: fixture.authority.publicKey })
+        .signers([fixture.authority])
+        .rpc();
+
+    const listed = (await eventsFor(await list())).find((e) => e.name === "videoListed")!;
+    expect(listed.data.ipfsCid).to.equal(cid);
+    await expectError(list(), "VideoAlreadyListed");
+
+    // finalizing later doesn't announce it a second time
+    const [first, second] = fixture.endorsers;
+    await endorse(fixture, video, first, true);
+    const deciding = await eventsFor(await endorse(fixture, video, second, true));
+    expect(deciding.some((e) => e.name === "videoListed")).to.equal(false);
+  });
+
+  it("refuses to list a revoked video", async () => {
+    const { fixture, video } = await registerWithEvents(VISIBILITY_UNLISTED_UNTIL_FINAL);
+    await revokeVideo(fixture, video);
+
+    await expectError(
+      program.methods
+        .listVideo()
+        .accountsPartial({ official: fixture.official, video, authority: fixture.authority.publicKey })
+        .signers([fixture.authority])
+        .rpc(),
+      "VideoRevoked"
+    );
+    expect((await program.account.video.fetch(video)).listed).to.equal(false);
+  });
+
+  it("rejects unknown visibility values", async () => {
+    const fixture = await createOfficial(3);
+    await expectError(registerVideo(fixture, randomHash(), randomCid(), 2), "InvalidVisibility");
+  });
+});
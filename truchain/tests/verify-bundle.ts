@@ -0,0 +1,83 @@
+import { expect } from "chai";
+import {
+  createOfficial,
+  decodeBundleBitmap,
+  endorse,
+  ensureConfig,
+  expectError,
+  program,
+  randomHash,
+  registerVideo,
+  revokeVideo,
+  videoPda,
+} from "./helpers";
+
+describe("verify_bundle", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("reports authentic and non-authentic registrations as bitmaps", async () => {
+    const fixture = await createOfficial(3);
+    const [e1, e2] = fixture.endorsers;
+
+    const authenticHash = randomHash();
+    const authentic = await registerVideo(fixture, authenticHash);
+    await endorse(fixture, authentic, e1, true);
+    await endorse(fixture, authentic, e2, true);
+
+    const disputedHash = randomHash();
+    const disputed = await registerVideo(fixture, disputedHash);
+    await endorse(fixture, disputed, e1, false);
+    await endorse(fixture, disputed, e2, false);
+
+    const pendingHash = randomHash();
+    await registerVideo(fixture, pendingHash);
+
+    const revokedHash = randomHash();
+    await revokeVideo(fixture, await registerVideo(fixture, revokedHash));
+
+    const missingHash = randomHash();
+
+    const hashes = [authenticHash, disputedHash, pendingHash, revokedHash, missingHash];
+    const result = await program.methods
+      .verifyBundle(hashes)
+      .accountsPartial({ official: fixture.official })
+      .remainingAccounts(
+        hashes.map((h) => ({ pubkey: videoPda(fixture.official, h), isSigner: false, isWritable: false }))
+      )
+      .view();
+
+    expect(decodeBundleBitmap(result.authentic, 5)).to.deep.equal([true, false, false, false, false]);
+    expect(decodeBundleBitmap(result.notAuthentic, 5)).to.deep.equal([false, true, true, true, false]);
+  });
+
+  it("detects accounts passed out of order", async () => {
+    const fixture = await createOfficial(3);
+    const first = randomHash();
+    const second = randomHash();
+    await registerVideo(fixture, first);
+    await registerVideo(fixture, second);
+
+    await expectError(
+      program.methods
+        .verifyBundle([first, second])
+        .accountsPartial({ official: fixture.official })
+        .remainingAccounts(
+          [second, first].map((h) => ({ pubkey: videoPda(fixture.official, h), isSigner: false, isWritable: false }))
+        )
+        .view(),
+      "BundleOrderMismatch"
+    );
+  });
+
+  it("caps the bundle size", async () => {
+    const fixture = await createOfficial(3);
+    const hashes = Array.from({ length: 51 }, () => randomHash());
+
+    await expectError(
+      program.methods.verifyBundle(hashes).accountsPartial({ official: fixture.official }).view(),
+      "InvalidBundle"
+    );
+  });
+});
@@ -0,0 +1,263 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  admin,
+  configPda,
+  createOfficial,
+  DEFAULT_BOND_SETTLE_DELAY_SECS,
+  endorse,
+  ensureConfig,
+  eventsFor,
+  expectError,
+  OfficialFixture,
+  program,
+  provider,
+  registerVideo,
+  setBondSettleDelay,
+  statusIndexPda,
+} from "./helpers";
+
+const AUTHENTIC = 1;
+const DISPUTED = 2;
+const BOND = 20_000_000;
+
+function changeVote(
+  fixture: OfficialFixture,
+  video: anchor.web3.PublicKey,
+  endorser: anchor.web3.Keypair,
+  isAuthentic: boolean,
+  statusIndex: anchor.web3.PublicKey | null = null,
+  oldStatusIndex: anchor.web3.PublicKey | null = null
+) {
+  return program.methods
+    .changeVote(isAuthentic)
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      video,
+      receipt: null,
+      endorser: endorser.publicKey,
+      cosigner: null,
+      statusIndex,
+      oldStatusIndex,
+    })
+    .signers([endorser])
+    .rpc();
+}
+
+function revoke(
+  fixture: OfficialFixture,
+  video: anchor.web3.PublicKey,
+  signer = fixture.authority,
+  rentPayer: anchor.web3.PublicKey | null = null,
+  statusIndex: anchor.web3.PublicKey | null = null
+) {
+  return program.methods
+    .revokeVideo()
+    .accountsPartial({
+      config: configPda(),
+      official: fixture.official,
+      video,
+      rentPayer,
+      statusIndex,
+      authority: signer.publicKey,
+    })
+    .signers([signer])
+    .rpc();
+}
+
+function setBond(lamports: number) {
+  return program.methods
+    .setRegistrationBond(new BN(lamports))
+    .accountsPartial({ config: configPda(), admin: admin.publicKey })
+    .rpc();
+}
+
+// Registers under a temporary bond; other suites share the config.
+async function withBond<T>(register: () => Promise<T>): Promise<T> {
+  await setBond(BOND);
+  try {
+    return await register();
+  } finally {
+    await setBond(0);
+  }
+}
+
+async function bucket(index: anchor.web3.PublicKey): Promise<string[]> {
+  return (await program.account.statusIndex.fetch(index)).videos.map((v) => v.toBase58());
+}
+
+describe("vote changes and revocation", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("flips a video from Authentic to Disputed when votes change", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second, third] = fixture.endorsers;
+    const video = await registerVideo(fixture);
+
+    const cast = await eventsFor(await endorse(fixture, video, first, true));
+    const voteCast = cast.find((e) => e.name === "voteCast")!;
+    expect(voteCast.data.endorser.toBase58()).to.equal(first.publicKey.toBase58());
+    expect(voteCast.data.isAuthentic).to.equal(true);
+
+    await endorse(fixture, video, second, true);
+    await endorse(fixture, video, third, false);
+    expect((await program.account.video.fetch(video)).status).to.deep.equal({ authentic: {} });
+    const finalizedAt = (await program.account.video.fetch(video)).finalizedAt.toNumber();
+
+    await expectError(changeVote(fixture, video, first, true), "VoteUnchanged");
+    const events = await eventsFor(
+      await changeVote(
+        fixture,
+        video,
+        first,
+        false,
+        statusIndexPda(fixture.official, DISPUTED, 0),
+        statusIndexPda(fixture.official, AUTHENTIC, 0)
+      )
+    );
+
+    const account = await program.account.video.fetch(video);
+    expect(account.status).to.deep.equal({ disputed: {} });
+    expect(account.finalizedAt.toNumber()).to.be.at.least(finalizedAt);
+    const official = await program.account.official.fetch(fixture.official);
+    expect(official.finalizedCount).to.equal(1);
+    expect(official.disputedCount).to.equal(1);
+
+    const changed = events.find((e) => e.name === "voteChanged")!;
+    expect(changed.data.isAuthentic).to.equal(false);
+    const status = events.find((e) => e.name === "statusChanged")!;
+    expect(status.data.old).to.deep.equal({ authentic: {} });
+    expect(status.data.new).to.deep.equal({ disputed: {} });
+  });
+
+  it("unwinds the old verdict when a change reopens or flips it", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second, third] = fixture.endorsers;
+    const authenticIndex = statusIndexPda(fixture.official, AUTHENTIC, 0);
+    const disputedIndex = statusIndexPda(fixture.official, DISPUTED, 0);
+    const video = await registerVideo(fixture);
+
+    await endorse(fixture, video, first, true);
+    await endorse(fixture, video, second, true, null, null, authenticIndex);
+    expect(await bucket(authenticIndex)).to.deep.equal([video.toBase58()]);
+
+    // Authentic -> Unverified: the old page must come along
+    await expectError(changeVote(fixture, video, second, false), "StatusIndexRequired");
+    await changeVote(fixture, video, second, false, null, authenticIndex);
+    let account = await program.account.video.fetch(video);
+    expect(account.status).to.deep.equal({ unverified: {} });
+    expect(account.finalizedAt.toNumber()).to.equal(0);
+    expect(account.statusIndexPage).to.equal(null);
+    expect(await bucket(authenticIndex)).to.deep.equal([]);
+    let official = await program.account.official.fetch(fixture.official);
+    expect(official.finalizedCount).to.equal(0);
+    expect(official.integrityScore).to.equal(100);
+
+    await endorse(fixture, video, third, false, null, null, disputedIndex);
+    expect((await program.account.video.fetch(video)).status).to.deep.equal({ disputed: {} });
+    expect((await program.account.official.fetch(fixture.official)).disputedCount).to.equal(1);
+
+    // Disputed -> Authentic in one change: out of one bucket, into the other
+    await changeVote(fixture, video, second, true, authenticIndex, disputedIndex);
+    account = await program.account.video.fetch(video);
+    expect(account.status).to.deep.equal({ authentic: {} });
+    expect(account.statusIndexPage).to.equal(0);
+    expect(await bucket(disputedIndex)).to.deep.equal([]);
+    expect(await bucket(authenticIndex)).to.deep.equal([video.toBase58()]);
+    official = await program.account.official.fetch(fixture.official);
+    expect(official.finalizedCount).to.equal(1);
+    expect(official.disputedCount).to.equal(0);
+    expect(official.integrityScore).to.equal(100);
+  });
+
+  it("refuses to flip a verdict whose bond was already settled", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second] = fixture.endorsers;
+    const video = await withBond(() => registerVideo(fixture));
+    await endorse(fixture, video, first, true);
+    await endorse(fixture, video, second, true);
+    await setBondSettleDelay(0);
+    try {
+      await program.methods.reclaimBond().accountsPartial({ video, rentPayer: fixture.authority.publicKey }).rpc();
+    } finally {
+      await setBondSettleDelay(DEFAULT_BOND_SETTLE_DELAY_SECS);
+    }
+
+    await expectError(changeVote(fixture, video, first, false), "BondAlreadySettled");
+  });
+
+  it("rejects changing a vote that was never cast", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+
+    await expectError(changeVote(fixture, video, fixture.endorsers[0], true), "VoteNotFound");
+  });
+
+  it("locks a revoked video", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second] = fixture.endorsers;
+    const video = await registerVideo(fixture);
+    await endorse(fixture, video, first, true);
+
+    const events = await eventsFor(await revoke(fixture, video));
+    expect((await program.account.video.fetch(video)).status).to.deep.equal({ revoked: {} });
+    const revoked = events.find((e) => e.name === "videoRevoked")!;
+    expect(revoked.data.oldStatus).to.deep.equal({ unverified: {} });
+
+    await expectError(changeVote(fixture, video, first, false), "VideoRevoked");
+    await expectError(endorse(fixture, video, second, true), "VideoRevoked");
+    await expectError(revoke(fixture, video), "VideoRevoked");
+  });
+
+  it("only lets the official's authority revoke", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+
+    await expectError(revoke(fixture, video, fixture.endorsers[0]), "UnauthorizedOfficial");
+  });
+
+  it("settles an escrowed bond and leaves the status bucket on revoke", async () => {
+    const fixture = await createOfficial(3);
+    const [first, second] = fixture.endorsers;
+    const authenticIndex = statusIndexPda(fixture.official, AUTHENTIC, 0);
+    const disputedIndex = statusIndexPda(fixture.official, DISPUTED, 0);
+
+    const pending = await withBond(() => registerVideo(fixture));
+    const authentic = await withBond(() => registerVideo(fixture));
+    const disputed = await withBond(() => registerVideo(fixture));
+
+    // still Unverified with a fake vote in: revoking can't dodge the slash
+    await endorse(fixture, pending, first, false);
+    let treasuryBefore = await provider.connection.getBalance(configPda());
+    await revoke(fixture, pending);
+    expect((await provider.connection.getBalance(configPda())) - treasuryBefore).to.equal(BOND);
+    expect((await program.account.video.fetch(pending)).bondState).to.deep.equal({ slashed: {} });
+
+    // Authentic: the bond goes back to whoever posted it
+    await endorse(fixture, authentic, first, true);
+    await endorse(fixture, authentic, second, true, null, null, authenticIndex);
+    await expectError(revoke(fixture, authentic, fixture.authority, null, authenticIndex), "BondPayerMismatch");
+    const payerBefore = await provider.connection.getBalance(fixture.authority.publicKey);
+    await revoke(fixture, authentic, fixture.authority, fixture.authority.publicKey, authenticIndex);
+    expect((await provider.connection.getBalance(fixture.authority.publicKey)) - payerBefore).to.equal(BOND);
+    expect((await program.account.video.fetch(authentic)).bondState).to.deep.equal({ reclaimed: {} });
+
+    // Disputed: forfeited to the treasury, and the video leaves its bucket
+    await endorse(fixture, disputed, first, false);
+    await endorse(fixture, disputed, second, false, null, null, disputedIndex);
+    treasuryBefore = await provider.connection.getBalance(configPda());
+    await expectError(revoke(fixture, disputed), "StatusIndexRequired");
+    await revoke(fixture, disputed, fixture.authority, null, disputedIndex);
+    expect((await provider.connection.getBalance(configPda())) - treasuryBefore).to.equal(BOND);
+    const account = await program.account.video.fetch(disputed);
+    expect(account.bondState).to.deep.equal({ slashed: {} });
+    expect(account.status).to.deep.equal({ revoked: {} });
+    expect(await bucket(disputedIndex)).to.deep.equal([]);
+    // the verdict was reached, so it stays in the official's record
+    expect((await program.account.official.fetch(fixture.official)).disputedCount).to.equal(1);
+  });
+});
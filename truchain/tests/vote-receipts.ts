@@ -0,0 +1,136 @@
+import { expect } from "chai";
+import {
+  airdrop,
+  configPda,
+  createOfficial,
+  endorse,
+  ensureConfig,
+  expectError,
+  program,
+  randomHash,
+  receiptPda,
+  registerVideo,
+} from "./helpers";
+
+// The request also asks to close a video and check the receipt survives.
+// There is no instruction that closes a Video account yet (revoke_video
+// marks it Revoked instead), so that test waits for the first close path.
+// Receipts are seeded by video hash, not the video key, for that reason.
+describe("vote receipts", () => {
+  before(async () => {
+    await ensureConfig();
+  });
+
+  it("issues a receipt and settles it once the video finalizes", async () => {
+    const fixture = await createOfficial(3);
+    const hash = randomHash();
+    const video = await registerVideo(fixture, hash);
+    const [first, second] = fixture.endorsers;
+    await airdrop(first.publicKey);
+
+    const receipt = receiptPda(hash, first.publicKey);
+    await endorse(fixture, video, first, true, receipt);
+
+    let decoded = await program.account.voteReceipt.fetch(receipt);
+    expect(decoded.endorser.toBase58()).to.equal(first.publicKey.toBase58());
+    expect(decoded.videoHash).to.deep.equal(hash);
+    expect(decoded.isAuthentic).to.equal(true);
+    expect(decoded.finalStatus).to.equal(null);
+
+    await expectError(
+      program.methods.finalizeVoteReceipt().accountsPartial({ video, receipt }).rpc(),
+      "VideoNotFinalized"
+    );
+
+    await endorse(fixture, video, second, true);
+    await program.methods.finalizeVoteReceipt().accountsPartial({ video, receipt }).rpc();
+
+    decoded = await program.account.voteReceipt.fetch(receipt);
+    expect(decoded.finalStatus).to.deep.equal({ authentic: {} });
+  });
+
+  it("rejects a receipt flag without the receipt account", async () => {
+    const fixture = await createOfficial(3);
+    const video = await registerVideo(fixture);
+    const endorser = fixture.endorsers[0];
+
+    await expectError(
+      program.methods
+        .endorseVideo(true, true, null)
+        .accountsPartial({ config: configPda(), official: fixture.official, video, receipt: null, endorser: endorser.publicKey, cosigner: null, statusIndex: null })
+        .signers([endorser])
+        .rpc(),
+      "VoteReceiptMismatch"
+    );
+  });
+
+  it("refunds the endorser and rejects unrelated closers", async () => {
+    const fixture = await createOfficial(3);
+    const hash = randomHash();
+    const video = await registerVideo(fixture, hash);
+    const [voter, other] = fixture.endorsers;
+    await airdrop(voter.publicKey);
+    await airdrop(other.publicKey);
+
+    const receipt = receiptPda(hash, voter.publicKey);
+    await endorse(fixture, video, voter, false, receipt);
+
+    await expectError(
+      program.methods
+        .closeVoteReceipt()
+        .accountsPartial({ config: configPda(), receipt, endorser: voter.publicKey, closer: other.publicKey })
+        .signers([other])
+        .rpc(),
+      "UnauthorizedCloser"
+    );
+
+    // rent can't be redirected away from the endorser
+    await expectError(
+      program.methods
+        .closeVoteReceipt()
+        .accountsPartial({ config: configPda(), receipt, endorser: other.publicKey, closer: voter.publicKey })
+        .signers([voter])
+        .rpc(),
+      "UnauthorizedEndorser"
+    );
+
+    await program.methods
+      .closeVoteReceipt()
+      .accountsPartial({ config: configPda(), receipt, endorser: voter.publicKey, closer: voter.publicKey })
+      .signers([voter])
+      .rpc();
+    expect(await program.account.voteReceipt.fetchNullable(receipt)).to.equal(null);
+  });
+
+  it("won't rewrite a receipt issued under another official's video", async () => {
+    const ministry = await createOfficial(3);
+    const press = await createOfficial(3);
+    const hash = randomHash();
+    const ministryVideo = await registerVideo(ministry, hash);
+    const pressVideo = await registerVideo(press, hash);
+    const endorser = ministry.endorsers[0];
+    await airdrop(endorser.publicKey);
+
+    const receipt = receiptPda(hash, endorser.publicKey);
+    await endorse(ministry, ministryVideo, endorser, true, receipt);
+
+    await expectError(
+      program.methods
+        .changeVote(false)
+        .accountsPartial({
+          config: configPda(),
+          official: press.official,
+          video: pressVideo,
+          receipt,
+          endorser: endorser.publicKey,
+          cosigner: null,
+          statusIndex: null,
+          oldStatusIndex: null,
+        })
+        .signers([endorser])
+        .rpc(),
+      "ReceiptVideoMismatch"
+    );
+    expect((await program.account.voteReceipt.fetch(receipt)).isAuthentic).to.equal(true);
+  });
+});